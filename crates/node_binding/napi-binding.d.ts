@@ -553,6 +553,7 @@ export declare enum BuiltinPluginName {
   NamedChunkIdsPlugin = 'NamedChunkIdsPlugin',
   DeterministicChunkIdsPlugin = 'DeterministicChunkIdsPlugin',
   OccurrenceChunkIdsPlugin = 'OccurrenceChunkIdsPlugin',
+  RecordIdsPlugin = 'RecordIdsPlugin',
   RealContentHashPlugin = 'RealContentHashPlugin',
   RemoveEmptyChunksPlugin = 'RemoveEmptyChunksPlugin',
   EnsureChunkConditionsPlugin = 'EnsureChunkConditionsPlugin',
@@ -1335,6 +1336,7 @@ export interface JsStatsCompilation {
   entrypoints?: Array<JsStatsChunkGroup>
   errors: Array<JsStatsError>
   hash?: string
+  incremental?: Array<JsStatsIncrementalPass>
   modules?: Array<JsStatsModule>
   namedChunkGroups?: Array<JsStatsChunkGroup>
   warnings: Array<JsStatsError>
@@ -1361,6 +1363,14 @@ export interface JsStatsGetAssets {
   assetsByChunkName: Array<JsStatsAssetsByChunkName>
 }
 
+export interface JsStatsIncrementalPass {
+  pass: string
+  incremental: boolean
+  recomputed: number
+  reused: number
+  total: number
+}
+
 export interface JsStatsLogging {
   name: string
   type: string
@@ -1459,6 +1469,7 @@ export interface JsStatsOptions {
   errors: boolean
   hash: boolean
   ids: boolean
+  incremental: boolean
   modules: boolean
   moduleAssets: boolean
   nestedModules: boolean
@@ -1704,6 +1715,20 @@ export interface NativeWatcherOptions {
   followSymlinks?: boolean
   pollInterval?: number
   aggregateTimeout?: number
+  /**
+   * Wait until no event arrives for this long in milliseconds before rebuilding,
+   * `aggregateTimeout` is used as a fixed delay when it's not set.
+   */
+  quietPeriod?: number
+  /** The longest time in milliseconds a rebuild can be delayed by `quietPeriod`. */
+  maxBatchLatency?: number
+  /** Replay the events received during a rebuild when the watcher resumes. */
+  replayPausedEvents?: boolean
+  /**
+   * Rescan all the watched paths instead of tracking the events when more
+   * files than this are pending.
+   */
+  overflowThreshold?: number
   /**
    * The ignored paths for the watcher.
    * It can be a single path, an array of paths, or a regular expression.
@@ -1994,6 +2019,8 @@ export interface RawCssAutoGeneratorOptions {
   exportsOnly?: boolean
   localIdentName?: string
   esModule?: boolean
+  typeDeclaration?: "false" | "write" | "asset" | "check"
+  removeUnusedRules?: boolean
 }
 
 export interface RawCssAutoParserOptions {
@@ -2030,6 +2057,8 @@ export interface RawCssModuleGeneratorOptions {
   exportsOnly?: boolean
   localIdentName?: string
   esModule?: boolean
+  typeDeclaration?: "false" | "write" | "asset" | "check"
+  removeUnusedRules?: boolean
 }
 
 export interface RawCssModuleParserOptions {
@@ -2119,6 +2148,7 @@ export interface RawExperimentCacheOptionsPersistent {
   version?: string
   snapshot?: RawExperimentSnapshotOptions
   storage?: RawStorageOptions
+  invalidationReport?: boolean
 }
 
 export interface RawExperiments {
@@ -2136,6 +2166,7 @@ export interface RawExperimentSnapshotOptions {
   immutablePaths: Array<string|RegExp>
   unmanagedPaths: Array<string|RegExp>
   managedPaths: Array<string|RegExp>
+  contentHash?: boolean
 }
 
 export interface RawExposeOptions {
@@ -2221,6 +2252,8 @@ export interface RawHtmlRspackPluginOptions {
   template?: string
   templateFn?: (data: string) => Promise<string>
   templateContent?: string
+  /** glob or directory of page templates */
+  pages?: string
   templateParameters?: boolean | Record<string, any> | ((params: string) => Promise<string>)
   /** "head", "body" or "false" */
   inject: "head" | "body" | "false"
@@ -2238,6 +2271,7 @@ export interface RawHtmlRspackPluginOptions {
   favicon?: string
   meta?: Record<string, Record<string, string>>
   hash?: boolean
+  criticalCss?: boolean
   base?: RawHtmlRspackPluginBaseOptions
   uid?: number
 }
@@ -2252,7 +2286,20 @@ export interface RawHttpUriPluginOptions {
   lockfileLocation?: string
   cacheLocation?: string
   upgrade: boolean
-  httpClient: (url: string, headers: Record<string, string>) => Promise<JsHttpResponseRaw>
+  httpProxy?: string
+  httpsProxy?: string
+  noProxy?: string
+  frozen: boolean
+  offline: boolean
+  headers?: Record<string, Record<string, string>>
+  retry?: RawHttpUriRetryOptions
+  httpClient: (url: string, headers: Record<string, string>, proxy?: string) => Promise<JsHttpResponseRaw>
+}
+
+export interface RawHttpUriRetryOptions {
+  retries?: number
+  delay?: number
+  maxDelay?: number
 }
 
 export interface RawIgnorePluginOptions {
@@ -2414,11 +2461,16 @@ export interface RawLightningCssMinimizerOptions {
   unusedSymbols: Array<string>
 }
 
+export interface RawLightningCssMinimizerReport {
+  filename?: string
+}
+
 export interface RawLightningCssMinimizerRspackPluginOptions {
   test?: string | RegExp | (string | RegExp)[]
   include?: string | RegExp | (string | RegExp)[]
   exclude?: string | RegExp | (string | RegExp)[]
   removeUnusedLocalIdents: boolean
+  report?: RawLightningCssMinimizerReport
   minimizerOptions: RawLightningCssMinimizerOptions
 }
 
@@ -2436,6 +2488,12 @@ export interface RawLimitChunkCountPluginOptions {
   maxChunks: number
 }
 
+export interface RawMangleProperties {
+  regex: string
+  reserved?: Array<string>
+  nameCache?: string
+}
+
 export interface RawManifestExposeOption {
   path: string
   name: string
@@ -2681,6 +2739,11 @@ export interface RawProvideOptions {
   strictVersion?: boolean
 }
 
+export interface RawRecordIdsPluginOptions {
+  inputPath?: string
+  outputPath?: string
+}
+
 export interface RawRelated {
   sourceMap?: string
 }
@@ -2694,6 +2757,7 @@ export interface RawRemoteOptions {
   key: string
   external: Array<string>
   shareScope: string
+  manifest?: string
 }
 
 export interface RawResolveOptions {
@@ -2859,8 +2923,17 @@ export interface RawStatsOptions {
 }
 
 export interface RawStorageOptions {
-  type: "filesystem"
+  type: "filesystem" | "remote"
   directory: string
+  url?: string
+  headers?: Record<string, string>
+  readOnly?: boolean
+  bucketSize?: number
+  packSize?: number
+  expire?: number
+  freshGeneration?: number
+  releaseGeneration?: number
+  adaptive?: boolean
 }
 
 export interface RawSubresourceIntegrityPluginOptions {
@@ -2883,6 +2956,7 @@ export interface RawSwcJsMinimizerRspackPluginOptions {
   include?: string | RegExp | (string | RegExp)[]
   exclude?: string | RegExp | (string | RegExp)[]
   extractComments?: RawExtractComments
+  mangleProperties?: RawMangleProperties
   minimizerOptions: RawSwcJsMinimizerOptions
 }
 
@@ -3062,6 +3136,8 @@ export interface SourceMapDevToolPluginOptions {
   include?: string | RegExp | (string | RegExp)[]
   exclude?: string | RegExp | (string | RegExp)[]
   debugIds?: boolean
+  validate?: boolean
+  sections?: "module" | "concatenated-module"
 }
 
 export interface SourcePosition {
@@ -41,7 +41,7 @@ use rspack_core::{
   CacheOptions, ChunkLoading, ChunkLoadingType, CleanOptions, Compiler, CompilerOptions, Context,
   CrossOriginLoading, CssAutoGeneratorOptions, CssAutoParserOptions, CssExportsConvention,
   CssGeneratorOptions, CssModuleGeneratorOptions, CssModuleParserOptions, CssParserOptions,
-  DynamicImportMode, EntryDescription, EntryOptions, EntryRuntime, Environment,
+  CssTypeDeclaration, DynamicImportMode, EntryDescription, EntryOptions, EntryRuntime, Environment,
   ExperimentCacheOptions, Experiments, ExternalItem, ExternalType, Filename, GeneratorOptions,
   GeneratorOptionsMap, JavascriptParserCommonjsExportsOption, JavascriptParserCommonjsOptions,
   JavascriptParserOptions, JavascriptParserOrder, JavascriptParserUrl, JsonGeneratorOptions,
@@ -1026,6 +1026,8 @@ impl CompilerOptionsBuilder {
         include: None,
         exclude: None,
         debug_ids: false,
+        validate: false,
+        sections: None,
       };
 
       if eval_wrapped {
@@ -1794,6 +1796,8 @@ impl ModuleOptionsBuilder {
           local_ident_name: Some("[uniqueName]-[id]-[local]".into()),
 
           es_module: Some(true),
+          type_declaration: Some(CssTypeDeclaration::None),
+          remove_unused_rules: Some(false),
         }),
       );
 
@@ -1804,6 +1808,8 @@ impl ModuleOptionsBuilder {
           exports_convention: Some(CssExportsConvention::default()),
           local_ident_name: Some("[uniqueName]-[id]-[local]".into()),
           es_module: Some(true),
+          type_declaration: Some(CssTypeDeclaration::None),
+          remove_unused_rules: Some(false),
         }),
       );
     }
@@ -3629,6 +3635,7 @@ impl OptimizationOptionsBuilder {
                 include: None,
                 exclude: None,
                 extract_comments: None,
+                mangle_properties: None,
                 minimizer_options: Default::default(),
               },
             ),
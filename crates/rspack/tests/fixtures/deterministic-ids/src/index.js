@@ -0,0 +1,2 @@
+import(/* webpackChunkName: "a" */ "./a");
+import(/* webpackChunkName: "b" */ "./b");
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+  use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+  };
+
+  use rspack::builder::Builder as _;
+  use rspack_core::{
+    ApplyContext, Compilation, CompilationChunkIds, Compiler, Experiments, Mode, Optimization,
+    Plugin,
+    incremental::{IncrementalOptions, IncrementalPasses},
+  };
+  use rspack_error::Result;
+  use rspack_fs::MemoryFileSystem;
+  use rspack_hook::{plugin, plugin_hook};
+  use rspack_paths::Utf8Path;
+  use rspack_tasks::within_compiler_context_for_testing;
+  use rustc_hash::FxHashMap;
+
+  /// Set the id of a named chunk before the deterministic ids are assigned.
+  #[plugin]
+  #[derive(Debug)]
+  struct PresetChunkIdPlugin {
+    preset: Arc<Mutex<Option<(String, String)>>>,
+  }
+
+  #[plugin_hook(CompilationChunkIds for PresetChunkIdPlugin, stage = -1)]
+  async fn chunk_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    let Some((name, id)) = self.preset.lock().expect("should lock").clone() else {
+      return Ok(());
+    };
+    for chunk in compilation.chunk_by_ukey.values_mut() {
+      if chunk.name() == Some(name.as_str()) {
+        chunk.set_id(id.clone());
+      }
+    }
+    Ok(())
+  }
+
+  impl Plugin for PresetChunkIdPlugin {
+    fn apply(&self, ctx: &mut ApplyContext<'_>) -> Result<()> {
+      ctx.compilation_hooks.chunk_ids.tap(chunk_ids::new(self));
+      Ok(())
+    }
+  }
+
+  fn collect_chunk_ids(compiler: &Compiler) -> FxHashMap<String, String> {
+    compiler
+      .compilation
+      .chunk_by_ukey
+      .values()
+      .map(|chunk| {
+        (
+          chunk.name().expect("should have chunk name").to_string(),
+          chunk.expect_id().to_string(),
+        )
+      })
+      .collect()
+  }
+
+  fn chunk_ids_metrics(compiler: &Compiler) -> (bool, usize, usize) {
+    let metrics = compiler
+      .compilation
+      .incremental
+      .metrics()
+      .into_iter()
+      .find(|metrics| metrics.pass == IncrementalPasses::CHUNK_IDS)
+      .expect("should record chunk ids pass");
+    (metrics.incremental, metrics.recomputed, metrics.total)
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn deterministic_chunk_ids() {
+    within_compiler_context_for_testing(async {
+      let preset = Arc::new(Mutex::new(None));
+      let mut compiler = Compiler::builder()
+        .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/deterministic-ids"))
+        .entry("main", "./src/index.js")
+        .mode(Mode::Development)
+        .optimization(Optimization::builder().chunk_ids("deterministic".to_string()))
+        .experiments(Experiments::builder().incremental(IncrementalOptions {
+          silent: true,
+          passes: IncrementalPasses::CHUNK_IDS,
+        }))
+        .output_filesystem(Arc::new(MemoryFileSystem::default()))
+        .plugin(Box::new(PresetChunkIdPlugin::new_inner(preset.clone())))
+        .build()
+        .unwrap();
+
+      compiler.build().await.unwrap();
+      assert!(compiler.compilation.get_errors().next().is_none());
+      let ids = collect_chunk_ids(&compiler);
+      assert_eq!(ids.len(), 3);
+      assert_eq!(chunk_ids_metrics(&compiler), (false, 3, 3));
+
+      // reuse the ids of the previous compilation
+      compiler
+        .rebuild(HashSet::default(), HashSet::default())
+        .await
+        .unwrap();
+      assert_eq!(collect_chunk_ids(&compiler), ids);
+      assert_eq!(chunk_ids_metrics(&compiler), (true, 0, 3));
+
+      // the previous id of chunk `a` is taken by chunk `b`
+      *preset.lock().unwrap() = Some(("b".to_string(), ids["a"].clone()));
+      compiler
+        .rebuild(HashSet::default(), HashSet::default())
+        .await
+        .unwrap();
+      let new_ids = collect_chunk_ids(&compiler);
+      assert_eq!(new_ids["main"], ids["main"]);
+      assert_eq!(new_ids["b"], ids["a"]);
+      assert_ne!(new_ids["a"], ids["a"]);
+      // the preset chunk is not assigned by the plugin but still counted
+      assert_eq!(chunk_ids_metrics(&compiler), (true, 1, 3));
+    })
+    .await;
+  }
+}
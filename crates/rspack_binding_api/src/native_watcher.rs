@@ -32,6 +32,20 @@ pub struct NativeWatcherOptions {
 
   pub aggregate_timeout: Option<u32>,
 
+  /// Wait until no event arrives for this long in milliseconds before rebuilding,
+  /// `aggregateTimeout` is used as a fixed delay when it's not set.
+  pub quiet_period: Option<u32>,
+
+  /// The longest time in milliseconds a rebuild can be delayed by `quietPeriod`.
+  pub max_batch_latency: Option<u32>,
+
+  /// Replay the events received during a rebuild when the watcher resumes.
+  pub replay_paused_events: Option<bool>,
+
+  /// Rescan all the watched paths instead of tracking the events when more
+  /// files than this are pending.
+  pub overflow_threshold: Option<u32>,
+
   #[napi(ts_type = "string | string[] | RegExp")]
   /// The ignored paths for the watcher.
   /// It can be a single path, an array of paths, or a regular expression.
@@ -63,6 +77,13 @@ impl NativeWatcher {
         follow_symlinks: options.follow_symlinks.unwrap_or(false),
         poll_interval: options.poll_interval,
         aggregate_timeout: options.aggregate_timeout,
+        quiet_period: options.quiet_period,
+        max_batch_latency: options.max_batch_latency,
+        replay_paused_events: options.replay_paused_events.unwrap_or(false),
+        overflow_threshold: options
+          .overflow_threshold
+          .map(|threshold| threshold as usize),
+        ..Default::default()
       },
       to_fs_watcher_ignored(options.ignored.clone()),
     );
@@ -34,6 +34,8 @@ pub struct RawHtmlRspackPluginOptions {
   #[napi(ts_type = "(data: string) => Promise<string>")]
   pub template_fn: Option<RawTemplateRenderFn>,
   pub template_content: Option<String>,
+  /// glob or directory of page templates
+  pub pages: Option<String>,
   #[napi(ts_type = "boolean | Record<string, any> | ((params: string) => Promise<string>)")]
   pub template_parameters: Option<RawTemplateParameter>,
   /// "head", "body" or "false"
@@ -58,6 +60,7 @@ pub struct RawHtmlRspackPluginOptions {
   pub favicon: Option<String>,
   pub meta: Option<HashMap<String, HashMap<String, String>>>,
   pub hash: Option<bool>,
+  pub critical_css: Option<bool>,
   pub base: Option<RawHtmlRspackPluginBaseOptions>,
   pub uid: Option<u32>,
 }
@@ -88,6 +91,7 @@ impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
         }),
       }),
       template_content: value.template_content,
+      pages: value.pages,
       template_parameters: match value.template_parameters {
         Some(parameters) => match parameters {
           Either3::A(data) => TemplateParameters::Map(data),
@@ -119,6 +123,7 @@ impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
       favicon: value.favicon,
       meta: value.meta,
       hash: value.hash,
+      critical_css: value.critical_css,
       base: value.base.map(|v| v.into()),
       uid: value.uid,
     }
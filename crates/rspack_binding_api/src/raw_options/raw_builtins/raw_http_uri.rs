@@ -1,4 +1,4 @@
-use std::{fmt::Debug, sync::Arc};
+use std::{fmt::Debug, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use napi::bindgen_prelude::{Buffer, Either, FnArgs, Promise};
@@ -7,13 +7,16 @@ use rspack_fs::WritableFileSystem;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
 use rspack_plugin_schemes::{
   HttpClient, HttpResponse, HttpUriOptionsAllowedUris, HttpUriPlugin, HttpUriPluginOptions,
+  HttpUriProxyOptions, HttpUriRetryOptions,
 };
 use rspack_regex::RspackRegex;
 use rspack_util::asset_condition::{AssetCondition, AssetConditions};
 use rustc_hash::FxHashMap as HashMap;
 
-type HttpClientRequest =
-  ThreadsafeFunction<FnArgs<(String, HashMap<String, String>)>, Promise<JsHttpResponseRaw>>;
+type HttpClientRequest = ThreadsafeFunction<
+  FnArgs<(String, HashMap<String, String>, Option<String>)>,
+  Promise<JsHttpResponseRaw>,
+>;
 
 #[napi(object, object_to_js = false)]
 #[derive(Debug)]
@@ -23,12 +26,42 @@ pub struct RawHttpUriPluginOptions {
   pub lockfile_location: Option<String>,
   pub cache_location: Option<String>,
   pub upgrade: bool,
-  // pub proxy: Option<String>,
-  // pub frozen: Option<bool>,
-  #[napi(ts_type = "(url: string, headers: Record<string, string>) => Promise<JsHttpResponseRaw>")]
+  pub http_proxy: Option<String>,
+  pub https_proxy: Option<String>,
+  pub no_proxy: Option<String>,
+  pub frozen: bool,
+  pub offline: bool,
+  pub headers: Option<HashMap<String, HashMap<String, String>>>,
+  pub retry: Option<RawHttpUriRetryOptions>,
+  #[napi(
+    ts_type = "(url: string, headers: Record<string, string>, proxy?: string) => Promise<JsHttpResponseRaw>"
+  )]
   pub http_client: HttpClientRequest,
 }
 
+#[napi(object, object_to_js = false)]
+#[derive(Debug)]
+pub struct RawHttpUriRetryOptions {
+  pub retries: Option<u32>,
+  pub delay: Option<u32>,
+  pub max_delay: Option<u32>,
+}
+
+impl From<RawHttpUriRetryOptions> for HttpUriRetryOptions {
+  fn from(value: RawHttpUriRetryOptions) -> Self {
+    let default = HttpUriRetryOptions::default();
+    Self {
+      retries: value.retries.unwrap_or(default.retries),
+      delay: value
+        .delay
+        .map_or(default.delay, |delay| Duration::from_millis(delay as u64)),
+      max_delay: value.max_delay.map_or(default.max_delay, |max_delay| {
+        Duration::from_millis(max_delay as u64)
+      }),
+    }
+  }
+}
+
 #[napi(object)]
 pub struct JsHttpResponseRaw {
   pub status: u16,
@@ -36,8 +69,7 @@ pub struct JsHttpResponseRaw {
   pub body: Buffer,
 }
 
-type JsHttpClientFunction =
-  ThreadsafeFunction<FnArgs<(String, HashMap<String, String>)>, Promise<JsHttpResponseRaw>>;
+type JsHttpClientFunction = HttpClientRequest;
 
 #[derive(Debug, Clone)]
 pub struct JsHttpClient {
@@ -50,13 +82,15 @@ impl HttpClient for JsHttpClient {
     &self,
     url: &str,
     headers: &HashMap<String, String>,
+    proxy: Option<&str>,
   ) -> anyhow::Result<HttpResponse> {
     let url_owned = url.to_string();
     let headers_owned = headers.clone();
+    let proxy_owned = proxy.map(|proxy| proxy.to_string());
     let func = self.function.clone();
 
     let result = func
-      .call_with_promise((url_owned, headers_owned).into())
+      .call_with_promise((url_owned, headers_owned, proxy_owned).into())
       .await
       .map_err(|e| anyhow::anyhow!("Error calling JavaScript HTTP client: {}", e))?;
 
@@ -92,8 +126,17 @@ fn create_http_uri_plugin_options(
     lockfile_location: options.lockfile_location,
     cache_location: options.cache_location,
     upgrade: options.upgrade,
-    // proxy: options.proxy,
-    // frozen: options.frozen,
+    proxy: (options.http_proxy.is_some() || options.https_proxy.is_some()).then(|| {
+      HttpUriProxyOptions::new(
+        options.http_proxy,
+        options.https_proxy,
+        options.no_proxy.as_deref(),
+      )
+    }),
+    headers: options.headers.unwrap_or_default(),
+    retry: options.retry.map(Into::into).unwrap_or_default(),
+    frozen: options.frozen,
+    offline: options.offline,
     http_client,
     filesystem,
   }
@@ -1,5 +1,5 @@
 use napi_derive::napi;
-use rspack_ids::OccurrenceChunkIdsPluginOptions;
+use rspack_ids::{OccurrenceChunkIdsPluginOptions, RecordIdsPluginOptions};
 
 #[derive(Debug)]
 #[napi(object, object_to_js = false)]
@@ -14,3 +14,19 @@ impl From<RawOccurrenceChunkIdsPluginOptions> for OccurrenceChunkIdsPluginOption
     }
   }
 }
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawRecordIdsPluginOptions {
+  pub input_path: Option<String>,
+  pub output_path: Option<String>,
+}
+
+impl From<RawRecordIdsPluginOptions> for RecordIdsPluginOptions {
+  fn from(value: RawRecordIdsPluginOptions) -> Self {
+    Self {
+      input_path: value.input_path.map(Into::into),
+      output_path: value.output_path.map(Into::into),
+    }
+  }
+}
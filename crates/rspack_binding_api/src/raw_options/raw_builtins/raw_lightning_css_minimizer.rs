@@ -2,7 +2,7 @@ use napi_derive::napi;
 use rspack_browserslist::browserslist_to_lightningcss_targets;
 use rspack_error::{Result, ToStringResultToRspackResultExt};
 use rspack_plugin_lightning_css_minimizer::{
-  Draft, MinimizerOptions, NonStandard, PluginOptions, PseudoClasses,
+  Draft, MinimizerOptions, NonStandard, PluginOptions, PseudoClasses, ReportOptions,
 };
 
 use crate::asset_condition::{RawAssetConditions, into_asset_conditions};
@@ -17,9 +17,16 @@ pub struct RawLightningCssMinimizerRspackPluginOptions {
   #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
   pub exclude: Option<RawAssetConditions>,
   pub remove_unused_local_idents: bool,
+  pub report: Option<RawLightningCssMinimizerReport>,
   pub minimizer_options: RawLightningCssMinimizerOptions,
 }
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawLightningCssMinimizerReport {
+  pub filename: Option<String>,
+}
+
 #[derive(Debug)]
 #[napi(object)]
 pub struct RawLightningCssMinimizerOptions {
@@ -82,6 +89,9 @@ impl TryFrom<RawLightningCssMinimizerRspackPluginOptions> for PluginOptions {
       include: value.include.map(into_asset_conditions),
       exclude: value.exclude.map(into_asset_conditions),
       remove_unused_local_idents: value.remove_unused_local_idents,
+      report: value.report.map(|report| ReportOptions {
+        filename: report.filename,
+      }),
       minimizer_options: MinimizerOptions {
         error_recovery: value.minimizer_options.error_recovery,
         targets: value
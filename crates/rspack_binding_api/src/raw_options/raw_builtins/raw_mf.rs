@@ -87,6 +87,7 @@ pub struct RawRemoteOptions {
   pub key: String,
   pub external: Vec<String>,
   pub share_scope: String,
+  pub manifest: Option<String>,
 }
 
 impl From<RawRemoteOptions> for (String, RemoteOptions) {
@@ -96,6 +97,7 @@ impl From<RawRemoteOptions> for (String, RemoteOptions) {
       RemoteOptions {
         external: value.external,
         share_scope: value.share_scope,
+        manifest: value.manifest,
       },
     )
   }
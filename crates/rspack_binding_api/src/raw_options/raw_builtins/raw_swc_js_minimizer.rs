@@ -2,7 +2,7 @@ use napi::Either;
 use napi_derive::napi;
 use rspack_error::{Result, ToStringResultToRspackResultExt};
 use rspack_plugin_swc_js_minimizer::{
-  ExtractComments, MinimizerOptions, OptionWrapper, PluginOptions,
+  ExtractComments, MangleProperties, MinimizerOptions, OptionWrapper, PluginOptions,
 };
 use serde::de::DeserializeOwned;
 use swc_core::base::BoolOrDataConfig;
@@ -16,6 +16,14 @@ pub struct RawExtractComments {
   pub condition: Option<String>,
 }
 
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawMangleProperties {
+  pub regex: String,
+  pub reserved: Option<Vec<String>>,
+  pub name_cache: Option<String>,
+}
+
 #[derive(Debug)]
 #[napi(object, object_to_js = false)]
 pub struct RawSwcJsMinimizerRspackPluginOptions {
@@ -26,6 +34,7 @@ pub struct RawSwcJsMinimizerRspackPluginOptions {
   #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
   pub exclude: Option<RawAssetConditions>,
   pub extract_comments: Option<RawExtractComments>,
+  pub mangle_properties: Option<RawMangleProperties>,
   pub minimizer_options: RawSwcJsMinimizerOptions,
 }
 
@@ -89,6 +98,13 @@ impl TryFrom<RawSwcJsMinimizerRspackPluginOptions> for PluginOptions {
       test: value.test.map(into_asset_conditions),
       include: value.include.map(into_asset_conditions),
       exclude: value.exclude.map(into_asset_conditions),
+      mangle_properties: value
+        .mangle_properties
+        .map(|mangle_properties| MangleProperties {
+          regex: mangle_properties.regex,
+          reserved: mangle_properties.reserved.unwrap_or_default(),
+          name_cache: mangle_properties.name_cache,
+        }),
       minimizer_options: MinimizerOptions {
         compress,
         mangle,
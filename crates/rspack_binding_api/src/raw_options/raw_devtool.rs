@@ -6,9 +6,11 @@ use napi::{
 };
 use napi_derive::napi;
 use rspack_core::PathData;
+use rspack_error::error;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
 use rspack_plugin_devtool::{
   Append, EvalDevToolModulePluginOptions, ModuleFilenameTemplate, ModuleFilenameTemplateFnCtx,
+  SourceMapSections,
 };
 
 use crate::asset_condition::{RawAssetConditions, into_asset_conditions};
@@ -122,10 +124,17 @@ pub struct SourceMapDevToolPluginOptions {
   #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
   pub exclude: Option<RawAssetConditions>,
   pub debug_ids: Option<bool>,
+  pub validate: Option<bool>,
+  #[napi(ts_type = "\"module\" | \"concatenated-module\"")]
+  pub sections: Option<String>,
 }
 
-impl From<SourceMapDevToolPluginOptions> for rspack_plugin_devtool::SourceMapDevToolPluginOptions {
-  fn from(opts: SourceMapDevToolPluginOptions) -> Self {
+impl TryFrom<SourceMapDevToolPluginOptions>
+  for rspack_plugin_devtool::SourceMapDevToolPluginOptions
+{
+  type Error = rspack_error::Error;
+
+  fn try_from(opts: SourceMapDevToolPluginOptions) -> rspack_error::Result<Self> {
     let append = opts.append.map(normalize_raw_append);
 
     let filename = opts.filename.and_then(|raw| match raw {
@@ -143,7 +152,18 @@ impl From<SourceMapDevToolPluginOptions> for rspack_plugin_devtool::SourceMapDev
     let columns = opts.columns.unwrap_or(true);
     let no_sources = opts.no_sources.unwrap_or(false);
 
-    Self {
+    let sections = opts
+      .sections
+      .map(|sections| match sections.as_str() {
+        "module" => Ok(SourceMapSections::Module),
+        "concatenated-module" => Ok(SourceMapSections::ConcatenatedModule),
+        _ => Err(error!(
+          "invalid sections: {sections}, supported: 'module' | 'concatenated-module' | undefined"
+        )),
+      })
+      .transpose()?;
+
+    Ok(Self {
       append,
       columns,
       fallback_module_filename_template,
@@ -160,7 +180,9 @@ impl From<SourceMapDevToolPluginOptions> for rspack_plugin_devtool::SourceMapDev
       include: opts.include.map(into_asset_conditions),
       exclude: opts.exclude.map(into_asset_conditions),
       debug_ids: opts.debug_ids.unwrap_or(false),
-    }
+      validate: opts.validate.unwrap_or(false),
+      sections,
+    })
   }
 }
 
@@ -11,6 +11,7 @@ use napi_derive::napi;
 use raw_snapshot::RawExperimentSnapshotOptions;
 use raw_storage::RawStorageOptions;
 use rspack_core::{ExperimentCacheOptions, cache::persistent::PersistentCacheOptions};
+use rspack_error::Result;
 
 pub type RawExperimentCacheOptions = Either<bool, RawExperimentCache>;
 
@@ -21,11 +22,14 @@ pub struct RawExperimentCacheOptionsPersistent {
   pub version: Option<String>,
   pub snapshot: Option<RawExperimentSnapshotOptions>,
   pub storage: Option<RawStorageOptions>,
+  pub invalidation_report: Option<bool>,
 }
 
-impl From<RawExperimentCacheOptionsPersistent> for PersistentCacheOptions {
-  fn from(value: RawExperimentCacheOptionsPersistent) -> Self {
-    Self {
+impl TryFrom<RawExperimentCacheOptionsPersistent> for PersistentCacheOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawExperimentCacheOptionsPersistent) -> Result<Self> {
+    Ok(Self {
       build_dependencies: value
         .build_dependencies
         .unwrap_or_default()
@@ -34,8 +38,9 @@ impl From<RawExperimentCacheOptionsPersistent> for PersistentCacheOptions {
         .collect(),
       version: value.version.unwrap_or_default(),
       snapshot: value.snapshot.unwrap_or_default().into(),
-      storage: value.storage.unwrap_or_default().into(),
-    }
+      storage: value.storage.unwrap_or_default().try_into()?,
+      invalidation_report: value.invalidation_report.unwrap_or(false),
+    })
   }
 }
 
@@ -83,8 +88,8 @@ impl FromNapiValue for RawExperimentCache {
 
 pub fn normalize_raw_experiment_cache_options(
   options: RawExperimentCacheOptions,
-) -> ExperimentCacheOptions {
-  match options {
+) -> Result<ExperimentCacheOptions> {
+  Ok(match options {
     Either::A(options) => {
       if options {
         ExperimentCacheOptions::Memory
@@ -93,8 +98,10 @@ pub fn normalize_raw_experiment_cache_options(
       }
     }
     Either::B(options) => match options {
-      RawExperimentCache::Persistent(options) => ExperimentCacheOptions::Persistent(options.into()),
+      RawExperimentCache::Persistent(options) => {
+        ExperimentCacheOptions::Persistent(options.try_into()?)
+      }
       RawExperimentCache::Memory => ExperimentCacheOptions::Memory,
     },
-  }
+  })
 }
@@ -12,6 +12,7 @@ pub struct RawExperimentSnapshotOptions {
   pub unmanaged_paths: Vec<RawPathMatcher>,
   #[napi(ts_type = r#"Array<string|RegExp>"#)]
   pub managed_paths: Vec<RawPathMatcher>,
+  pub content_hash: Option<bool>,
 }
 
 type RawPathMatcher = Either<String, RspackRegex>;
@@ -35,6 +36,7 @@ impl From<RawExperimentSnapshotOptions> for SnapshotOptions {
         .map(normalize_raw_path_matcher)
         .collect(),
     )
+    .with_content_hash(value.content_hash.unwrap_or(false))
   }
 }
 
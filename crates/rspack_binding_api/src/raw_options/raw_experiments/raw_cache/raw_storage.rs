@@ -1,21 +1,67 @@
 use napi_derive::napi;
-use rspack_core::cache::persistent::storage::StorageOptions;
+use rspack_core::cache::persistent::storage::{StorageOptions, StoragePackOptions};
+use rspack_error::{Result, error};
+use rustc_hash::FxHashMap as HashMap;
 
 #[derive(Debug, Default)]
 #[napi(object)]
 pub struct RawStorageOptions {
-  #[napi(ts_type = r#""filesystem""#)]
+  #[napi(ts_type = r#""filesystem" | "remote""#)]
   pub r#type: String,
   pub directory: String,
+  pub url: Option<String>,
+  pub headers: Option<HashMap<String, String>>,
+  pub read_only: Option<bool>,
+  pub bucket_size: Option<u32>,
+  pub pack_size: Option<u32>,
+  pub expire: Option<f64>,
+  pub fresh_generation: Option<u32>,
+  pub release_generation: Option<u32>,
+  pub adaptive: Option<bool>,
 }
 
-impl From<RawStorageOptions> for StorageOptions {
-  fn from(value: RawStorageOptions) -> Self {
+impl TryFrom<RawStorageOptions> for StorageOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawStorageOptions) -> Result<Self> {
+    let mut headers = value
+      .headers
+      .unwrap_or_default()
+      .into_iter()
+      .collect::<Vec<_>>();
+    headers.sort();
+    let default_pack = StoragePackOptions::default();
+    let pack = StoragePackOptions {
+      bucket_size: value
+        .bucket_size
+        .map_or(default_pack.bucket_size, |v| v as usize),
+      pack_size: value
+        .pack_size
+        .map_or(default_pack.pack_size, |v| v as usize),
+      expire: value.expire.map_or(default_pack.expire, |v| v as u64),
+      fresh_generation: value
+        .fresh_generation
+        .map_or(default_pack.fresh_generation, |v| Some(v as usize)),
+      release_generation: value
+        .release_generation
+        .map_or(default_pack.release_generation, |v| Some(v as usize)),
+      adaptive: value.adaptive.unwrap_or(default_pack.adaptive),
+    };
     match value.r#type.as_str() {
-      "filesystem" => StorageOptions::FileSystem {
+      "filesystem" => Ok(StorageOptions::FileSystem {
+        directory: value.directory.into(),
+        pack,
+      }),
+      "remote" => Ok(StorageOptions::Remote {
         directory: value.directory.into(),
-      },
-      s => panic!("unsupported storage type {s}"),
+        pack,
+        url: value
+          .url
+          .ok_or_else(|| error!("cache.storage.url is required when the storage type is remote"))?,
+        headers,
+        read_only: value.read_only.unwrap_or_default(),
+      }),
+      s => Err(error!("unsupported storage type {s}")),
     }
   }
 }
@@ -777,6 +777,9 @@ pub struct RawCssAutoGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<String>,
   pub es_module: Option<bool>,
+  #[napi(ts_type = r#""false" | "write" | "asset" | "check""#)]
+  pub type_declaration: Option<String>,
+  pub remove_unused_rules: Option<bool>,
 }
 
 impl From<RawCssAutoGeneratorOptions> for CssAutoGeneratorOptions {
@@ -786,6 +789,8 @@ impl From<RawCssAutoGeneratorOptions> for CssAutoGeneratorOptions {
       exports_only: value.exports_only,
       local_ident_name: value.local_ident_name.map(|n| n.into()),
       es_module: value.es_module,
+      type_declaration: value.type_declaration.map(|n| n.into()),
+      remove_unused_rules: value.remove_unused_rules,
     }
   }
 }
@@ -798,6 +803,9 @@ pub struct RawCssModuleGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<String>,
   pub es_module: Option<bool>,
+  #[napi(ts_type = r#""false" | "write" | "asset" | "check""#)]
+  pub type_declaration: Option<String>,
+  pub remove_unused_rules: Option<bool>,
 }
 
 impl From<RawCssModuleGeneratorOptions> for CssModuleGeneratorOptions {
@@ -807,6 +815,8 @@ impl From<RawCssModuleGeneratorOptions> for CssModuleGeneratorOptions {
       exports_only: value.exports_only,
       local_ident_name: value.local_ident_name.map(|n| n.into()),
       es_module: value.es_module,
+      type_declaration: value.type_declaration.map(|n| n.into()),
+      remove_unused_rules: value.remove_unused_rules,
     }
   }
 }
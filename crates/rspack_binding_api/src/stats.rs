@@ -9,6 +9,7 @@ use napi_derive::napi;
 use rspack_collections::IdentifierMap;
 use rspack_core::{
   EntrypointsStatsOption, ExtendedStatsOptions, Stats, StatsChunk, StatsModule, StatsUsedExports,
+  incremental::IncrementalPassMetrics,
   rspack_sources::{RawBufferSource, Source, SourceValue},
 };
 use rspack_error::Severity;
@@ -954,6 +955,27 @@ impl<'a> From<rspack_core::StatsAssetsByChunkName<'a>> for JsStatsAssetsByChunkN
   }
 }
 
+#[napi(object, object_from_js = false)]
+pub struct JsStatsIncrementalPass {
+  pub pass: String,
+  pub incremental: bool,
+  pub recomputed: u32,
+  pub reused: u32,
+  pub total: u32,
+}
+
+impl From<IncrementalPassMetrics> for JsStatsIncrementalPass {
+  fn from(metrics: IncrementalPassMetrics) -> Self {
+    Self {
+      pass: metrics.pass.pass_name().to_string(),
+      incremental: metrics.incremental,
+      recomputed: metrics.recomputed as u32,
+      reused: metrics.reused() as u32,
+      total: metrics.total as u32,
+    }
+  }
+}
+
 #[napi(object, object_to_js = false)]
 pub struct JsStatsOptions {
   pub assets: bool,
@@ -969,6 +991,7 @@ pub struct JsStatsOptions {
   pub errors: bool,
   pub hash: bool,
   pub ids: bool,
+  pub incremental: bool,
   pub modules: bool,
   pub module_assets: bool,
   pub nested_modules: bool,
@@ -1001,6 +1024,7 @@ impl From<JsStatsOptions> for ExtendedStatsOptions {
       errors: value.errors,
       hash: value.hash,
       ids: value.ids,
+      incremental: value.incremental,
       modules: value.modules,
       module_assets: value.module_assets,
       nested_modules: value.nested_modules,
@@ -1030,6 +1054,7 @@ pub struct JsStatsCompilation<'a> {
   #[napi(ts_type = "Array<JsStatsError>")]
   pub errors: napi_value,
   pub hash: Option<&'a str>,
+  pub incremental: Option<Vec<JsStatsIncrementalPass>>,
   #[napi(ts_type = "Array<JsStatsModule>")]
   pub modules: Option<napi_value>,
   pub named_chunk_groups: Option<Vec<JsStatsChunkGroup<'a>>>,
@@ -1082,6 +1107,8 @@ impl JsStats {
 
     let hash = options.hash.then(|| self.hash()).flatten();
 
+    let incremental = options.incremental.then(|| self.incremental());
+
     let (assets, assets_by_chunk_name) = if options.assets {
       let asts = self.assets();
       (Some(asts.assets), Some(asts.assets_by_chunk_name))
@@ -1125,6 +1152,7 @@ impl JsStats {
       entrypoints,
       errors,
       hash,
+      incremental,
       modules,
       named_chunk_groups,
       warnings,
@@ -1230,6 +1258,15 @@ impl JsStats {
   fn hash(&self) -> Option<&str> {
     self.inner.get_hash()
   }
+
+  fn incremental(&self) -> Vec<JsStatsIncrementalPass> {
+    self
+      .inner
+      .get_incremental_metrics()
+      .into_iter()
+      .map(Into::into)
+      .collect()
+  }
 }
 
 pub fn create_stats_warnings<'a>(
@@ -0,0 +1,34 @@
+use crate::build_chunk_graph::snapshot::ChunkGraphSnapshot;
+
+/// Chunk graph shared with the persistent cache.
+///
+/// The snapshot is keyed by the fingerprint of the code splitting inputs, so the chunk graph
+/// can be restored without code splitting whenever the module graph is unchanged.
+///
+/// This artifact is only enabled by persistent cache.
+#[derive(Debug, Default)]
+pub struct ChunkGraphCacheArtifact {
+  enabled: bool,
+  snapshot: Option<ChunkGraphSnapshot>,
+}
+
+impl ChunkGraphCacheArtifact {
+  pub fn new(snapshot: Option<ChunkGraphSnapshot>) -> Self {
+    Self {
+      enabled: true,
+      snapshot,
+    }
+  }
+
+  pub fn is_enabled(&self) -> bool {
+    self.enabled
+  }
+
+  pub fn snapshot(&self) -> Option<&ChunkGraphSnapshot> {
+    self.snapshot.as_ref()
+  }
+
+  pub(crate) fn set_snapshot(&mut self, snapshot: Option<ChunkGraphSnapshot>) {
+    self.snapshot = snapshot;
+  }
+}
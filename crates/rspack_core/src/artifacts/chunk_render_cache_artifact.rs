@@ -21,6 +21,22 @@ impl ChunkRenderCacheArtifact {
       storage.start_next_generation();
     }
   }
+  /// The cached chunk sources keyed by chunk content hash.
+  pub fn entries(&self) -> Vec<(Identifier, BoxSource)> {
+    self
+      .storage
+      .as_ref()
+      .map(|storage| storage.entries())
+      .unwrap_or_default()
+  }
+  /// Restore chunk sources keyed by chunk content hash, e.g. from persistent cache.
+  pub fn restore(&self, entries: impl IntoIterator<Item = (Identifier, BoxSource)>) {
+    if let Some(storage) = &self.storage {
+      for (key, source) in entries {
+        storage.set(key, source);
+      }
+    }
+  }
   pub async fn use_cache<G, F>(
     &self,
     compilation: &Compilation,
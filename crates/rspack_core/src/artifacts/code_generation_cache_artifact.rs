@@ -0,0 +1,76 @@
+use rspack_collections::{Identifier, IdentifierMap};
+use rspack_hash::RspackHashDigest;
+
+use crate::{CodeGenerationResult, ModuleIdentifier};
+
+/// Code generation results shared with the persistent cache.
+///
+/// The results are keyed by `{module}|{module hash}`, the same key as the memory cache
+/// of code generation, so a result can be reused whenever the module hash is unchanged.
+///
+/// Only the results that can be serialized are collected, which means the result has no
+/// code generation data, no chunk init fragments and no concatenation scope.
+///
+/// This artifact is only enabled by persistent cache.
+#[derive(Debug, Default)]
+pub struct CodeGenerationCacheArtifact {
+  enabled: bool,
+  results: IdentifierMap<CodeGenerationResult>,
+  added: IdentifierMap<(ModuleIdentifier, CodeGenerationResult)>,
+}
+
+impl CodeGenerationCacheArtifact {
+  pub fn new(results: IdentifierMap<CodeGenerationResult>) -> Self {
+    Self {
+      enabled: true,
+      results,
+      added: Default::default(),
+    }
+  }
+
+  pub fn cache_key(module: &ModuleIdentifier, hash: &RspackHashDigest) -> Identifier {
+    Identifier::from(format!("{}|{}", module, hash.encoded()))
+  }
+
+  pub fn is_portable(result: &CodeGenerationResult) -> bool {
+    result.data.is_empty()
+      && result.chunk_init_fragments.is_empty()
+      && result.concatenation_scope.is_none()
+  }
+
+  pub fn get(
+    &self,
+    module: &ModuleIdentifier,
+    hash: &RspackHashDigest,
+  ) -> Option<CodeGenerationResult> {
+    if !self.enabled {
+      return None;
+    }
+    let result = self.results.get(&Self::cache_key(module, hash))?;
+    Some(CodeGenerationResult {
+      inner: result.inner.as_ref().clone().into(),
+      runtime_requirements: result.runtime_requirements,
+      hash: result.hash.clone(),
+      ..Default::default()
+    })
+  }
+
+  /// Collect a result used by the current compilation, the result will be saved by persistent cache.
+  pub fn add(
+    &mut self,
+    module: &ModuleIdentifier,
+    hash: &RspackHashDigest,
+    result: &CodeGenerationResult,
+  ) {
+    if !self.enabled || !Self::is_portable(result) {
+      return;
+    }
+    self
+      .added
+      .insert(Self::cache_key(module, hash), (*module, result.clone()));
+  }
+
+  pub fn added(&self) -> &IdentifierMap<(ModuleIdentifier, CodeGenerationResult)> {
+    &self.added
+  }
+}
@@ -0,0 +1,21 @@
+use rspack_collections::IdentifierMap;
+use rustc_hash::FxHashMap;
+
+use crate::{ModuleId, chunk_graph_chunk::ChunkId};
+
+/// The ids assigned by deterministic id plugins.
+///
+/// Modules are keyed by module identifier and chunks are keyed by full chunk name,
+/// both of them are stable between compilations, so the ids can be reused in rebuild
+/// and restored from persistent cache.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DeterministicIdsArtifact {
+  pub module_ids: IdentifierMap<ModuleId>,
+  pub chunk_ids: FxHashMap<String, ChunkId>,
+}
+
+impl DeterministicIdsArtifact {
+  pub fn is_empty(&self) -> bool {
+    self.module_ids.is_empty() && self.chunk_ids.is_empty()
+  }
+}
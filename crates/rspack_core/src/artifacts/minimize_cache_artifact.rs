@@ -0,0 +1,57 @@
+use rspack_collections::Identifier;
+use rspack_sources::BoxSource;
+
+use crate::MemoryGCStorage;
+
+/// The minified result of an asset.
+#[derive(Debug, Clone)]
+pub struct MinimizedAsset {
+  /// The minified source, its source map only maps to the input of the minimizer.
+  pub source: BoxSource,
+  /// The comments extracted from the asset.
+  pub extracted_comments: Vec<String>,
+}
+
+/// The minified assets keyed by the minimizer, e.g. the hash of the input source and the
+/// minimizer options, so the unchanged assets skip the minification.
+#[derive(Debug, Default)]
+pub struct MinimizeCacheArtifact {
+  storage: Option<MemoryGCStorage<MinimizedAsset>>,
+}
+
+impl MinimizeCacheArtifact {
+  pub fn new(storage: MemoryGCStorage<MinimizedAsset>) -> Self {
+    Self {
+      storage: Some(storage),
+    }
+  }
+  pub fn start_next_generation(&self) {
+    if let Some(storage) = &self.storage {
+      storage.start_next_generation();
+    }
+  }
+  /// The cached minified assets.
+  pub fn entries(&self) -> Vec<(Identifier, MinimizedAsset)> {
+    self
+      .storage
+      .as_ref()
+      .map(|storage| storage.entries())
+      .unwrap_or_default()
+  }
+  /// Restore minified assets, e.g. from persistent cache.
+  pub fn restore(&self, entries: impl IntoIterator<Item = (Identifier, MinimizedAsset)>) {
+    if let Some(storage) = &self.storage {
+      for (key, asset) in entries {
+        storage.set(key, asset);
+      }
+    }
+  }
+  pub fn get(&self, key: &Identifier) -> Option<MinimizedAsset> {
+    self.storage.as_ref().and_then(|storage| storage.get(key))
+  }
+  pub fn set(&self, key: Identifier, asset: MinimizedAsset) {
+    if let Some(storage) = &self.storage {
+      storage.set(key, asset);
+    }
+  }
+}
@@ -5,20 +5,28 @@ use crate::{ChunkRenderResult, ChunkUkey, ModuleId, RuntimeGlobals};
 
 mod cgm_hash_artifact;
 mod cgm_runtime_requirement_artifact;
+mod chunk_graph_cache_artifact;
 mod chunk_hashes_artifact;
 mod chunk_ids_artifact;
 mod chunk_render_cache_artifact;
+mod code_generation_cache_artifact;
 mod code_generation_results;
+mod deterministic_ids_artifact;
+mod minimize_cache_artifact;
 mod module_graph_cache_artifact;
 mod module_static_cache_artifact;
 mod side_effects_do_optimize_artifact;
 
 pub use cgm_hash_artifact::*;
 pub use cgm_runtime_requirement_artifact::*;
+pub use chunk_graph_cache_artifact::ChunkGraphCacheArtifact;
 pub use chunk_hashes_artifact::*;
 pub use chunk_ids_artifact::*;
 pub use chunk_render_cache_artifact::ChunkRenderCacheArtifact;
+pub use code_generation_cache_artifact::CodeGenerationCacheArtifact;
 pub use code_generation_results::*;
+pub use deterministic_ids_artifact::DeterministicIdsArtifact;
+pub use minimize_cache_artifact::{MinimizeCacheArtifact, MinimizedAsset};
 pub use module_graph_cache_artifact::*;
 pub use module_static_cache_artifact::*;
 pub use side_effects_do_optimize_artifact::*;
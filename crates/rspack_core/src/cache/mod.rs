@@ -34,6 +34,9 @@ pub trait Cache: Debug + Send + Sync {
 
   async fn before_build_module_graph(&mut self, _make_artifact: &mut BuildModuleGraphArtifact) {}
   async fn after_build_module_graph(&mut self, _make_artifact: &BuildModuleGraphArtifact) {}
+
+  async fn before_seal(&mut self, _compilation: &mut Compilation) {}
+  async fn after_seal(&mut self, _compilation: &Compilation) {}
 }
 
 pub fn new_cache(
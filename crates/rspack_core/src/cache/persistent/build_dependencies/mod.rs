@@ -9,7 +9,7 @@ use rustc_hash::FxHashSet as HashSet;
 
 use self::helper::{Helper, is_node_package_path};
 use super::{
-  snapshot::{Snapshot, SnapshotOptions},
+  snapshot::{Snapshot, SnapshotChange, SnapshotOptions},
   storage::Storage,
 };
 
@@ -87,21 +87,33 @@ impl BuildDeps {
 
   /// Validate build dependencies
   ///
-  /// If any build dependencies have changed, this method will reset storage.
-  pub async fn validate(&mut self) -> Result<()> {
+  /// If any build dependencies have changed, this method will reset storage
+  /// and return the changes which invalidate the cache. The changes are only
+  /// explained when `explain` is true, otherwise an empty list is returned.
+  pub async fn validate(&mut self, explain: bool) -> Result<Vec<SnapshotChange>> {
     let (_, modified_files, removed_files, no_changed_files) =
       self.snapshot.calc_modified_paths().await?;
 
     if !modified_files.is_empty() || !removed_files.is_empty() {
+      let changes = if explain {
+        let changed_files = modified_files
+          .iter()
+          .chain(removed_files.iter())
+          .cloned()
+          .collect();
+        self.snapshot.explain(&changed_files).await?
+      } else {
+        vec![]
+      };
       self.storage.reset().await;
 
       tracing::info!(
         "BuildDependencies: cache invalidate by modified_files {modified_files:?} and removed_files {removed_files:?}"
       );
-      return Ok(());
+      return Ok(changes);
     }
     self.added = no_changed_files;
-    Ok(())
+    Ok(vec![])
   }
 }
 
@@ -110,6 +122,7 @@ mod test {
   use std::{path::PathBuf, sync::Arc};
 
   use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+  use rspack_paths::ArcPath;
   use rspack_storage::Storage;
 
   use super::{super::storage::MemoryStorage, BuildDeps, SCOPE, SnapshotOptions};
@@ -161,10 +174,12 @@ mod test {
     fs.write("/b.js".into(), r#"require("./c")"#.as_bytes())
       .await
       .unwrap();
-    build_deps
-      .validate()
+    let changes = build_deps
+      .validate(true)
       .await
       .expect("should validate success");
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].path, ArcPath::from("/b.js"));
 
     let data = storage.load(SCOPE).await.expect("should load success");
     assert_eq!(data.len(), 0);
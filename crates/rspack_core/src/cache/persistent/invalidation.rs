@@ -0,0 +1,281 @@
+use std::collections::VecDeque;
+
+use rspack_collections::IdentifierSet;
+use serde_json::{Value, json};
+
+use super::snapshot::{SnapshotChange, Strategy};
+use crate::{
+  ModuleIdentifier, ResourceId, compilation::build_module_graph::BuildModuleGraphArtifact,
+};
+
+/// The modules invalidated by a changed file.
+#[derive(Debug)]
+pub struct FileInvalidation {
+  pub change: SnapshotChange,
+  /// The modules which depend on the file directly.
+  pub modules: Vec<ModuleIdentifier>,
+  /// The modules which import the invalidated modules directly or indirectly.
+  pub dependents: Vec<ModuleIdentifier>,
+}
+
+/// The cache version change of a cold start.
+#[derive(Debug)]
+pub struct VersionChange {
+  /// The version saved by the last compilation, `None` if there is no cache at all.
+  pub old: Option<String>,
+  pub new: String,
+}
+
+/// Invalidation Report
+///
+/// Records why the persistent cache is not fully reused in a warm start. Each
+/// module is only attributed to the first cause which invalidates it.
+#[derive(Debug, Default)]
+pub struct InvalidationReport {
+  /// Only set when there is no cache for the current version and no build
+  /// dependency changed, e.g. the configuration, rspack version or
+  /// `cache.version` changed.
+  pub version: Option<VersionChange>,
+  /// The changed build dependencies, which drop the whole cache.
+  pub build_dependencies: Vec<SnapshotChange>,
+  pub files: Vec<FileInvalidation>,
+}
+
+impl InvalidationReport {
+  pub fn is_empty(&self) -> bool {
+    self.version.is_none() && self.build_dependencies.is_empty() && self.files.is_empty()
+  }
+
+  /// Record the changed files
+  pub fn add_files(&mut self, changes: Vec<SnapshotChange>) {
+    self
+      .files
+      .extend(changes.into_iter().map(|change| FileInvalidation {
+        change,
+        modules: vec![],
+        dependents: vec![],
+      }));
+  }
+
+  /// Find the modules invalidated by each changed file in the recovered artifact.
+  pub fn attribute_modules(&mut self, artifact: &BuildModuleGraphArtifact) {
+    let module_graph = artifact.get_module_graph();
+    let mut visited = IdentifierSet::default();
+    for file in &mut self.files {
+      let path = &file.change.path;
+      let mut modules = vec![];
+      for resource_ids in [
+        artifact.file_dependencies.related_resource_ids(path),
+        artifact.context_dependencies.related_resource_ids(path),
+        artifact.missing_dependencies.related_resource_ids(path),
+      ]
+      .into_iter()
+      .flatten()
+      {
+        for resource_id in resource_ids {
+          let module = match resource_id {
+            ResourceId::Module(mid) => Some(*mid),
+            ResourceId::Dependency(dep_id) => module_graph.get_parent_module(dep_id).copied(),
+          };
+          if let Some(module) = module
+            && visited.insert(module)
+          {
+            modules.push(module);
+          }
+        }
+      }
+
+      let mut dependents = vec![];
+      let mut queue = modules.iter().copied().collect::<VecDeque<_>>();
+      while let Some(module) = queue.pop_front() {
+        for connection in module_graph.get_incoming_connections(&module) {
+          if let Some(origin) = connection.original_module_identifier
+            && visited.insert(origin)
+          {
+            dependents.push(origin);
+            queue.push_back(origin);
+          }
+        }
+      }
+
+      modules.sort();
+      dependents.sort();
+      file.modules = modules;
+      file.dependents = dependents;
+    }
+  }
+
+  pub fn to_json(&self) -> Value {
+    let build_dependencies = self
+      .build_dependencies
+      .iter()
+      .map(change_to_json)
+      .collect::<Vec<_>>();
+    let files = self
+      .files
+      .iter()
+      .map(|file| {
+        let mut value = change_to_json(&file.change);
+        value["modules"] = json!(file.modules);
+        value["dependents"] = json!(file.dependents);
+        value
+      })
+      .collect::<Vec<_>>();
+    let version = self.version.as_ref().map(|version| {
+      json!({
+        "old": version.old,
+        "new": version.new,
+      })
+    });
+    json!({
+      "version": version,
+      "buildDependencies": build_dependencies,
+      "files": files,
+    })
+  }
+}
+
+fn strategy_to_json(strategy: &Strategy) -> Value {
+  match strategy {
+    Strategy::PackageVersion(version) => json!({ "type": "packageVersion", "version": version }),
+    Strategy::PathHash { mtime, hash } => {
+      json!({ "type": "pathHash", "mtime": mtime, "hash": format!("{hash:016x}") })
+    }
+    Strategy::Missing => json!({ "type": "missing" }),
+    Strategy::ContentHash { mtime, hash } => {
+      json!({ "type": "contentHash", "mtime": mtime, "hash": format!("{hash:016x}") })
+    }
+  }
+}
+
+fn change_to_json(change: &SnapshotChange) -> Value {
+  json!({
+    "path": change.path.to_string_lossy(),
+    "old": strategy_to_json(&change.old),
+    "new": change.new.as_ref().map(strategy_to_json),
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_paths::{ArcPath, ArcPathSet};
+
+  use super::{InvalidationReport, SnapshotChange, Strategy, VersionChange};
+  use crate::{
+    Context, EntryDependency, ExportsInfoData, ModuleGraphModule, ModuleIdentifier, ResourceId,
+    compilation::build_module_graph::BuildModuleGraphArtifact,
+  };
+
+  fn change(path: &str) -> SnapshotChange {
+    SnapshotChange {
+      path: ArcPath::from(path),
+      old: Strategy::PathHash { mtime: 1, hash: 1 },
+      new: Some(Strategy::PathHash { mtime: 2, hash: 2 }),
+    }
+  }
+
+  /// Create a module graph in which `a` imports `b` and `b` imports `c`,
+  /// each module depends on the file with the same name.
+  fn create_artifact() -> BuildModuleGraphArtifact {
+    let mut artifact = BuildModuleGraphArtifact::default();
+    let [a, b, c] = ["a", "b", "c"].map(ModuleIdentifier::from);
+    let mut mg = artifact.get_module_graph_mut();
+    for module in [a, b, c] {
+      let exports_info = ExportsInfoData::default();
+      mg.add_module_graph_module(ModuleGraphModule::new(module, exports_info.id()));
+      mg.set_exports_info(exports_info.id(), exports_info);
+    }
+    for (origin, module) in [(a, b), (b, c)] {
+      let dep = EntryDependency::new(module.to_string(), Context::from(""), None, false);
+      let dep_id = *dep.id();
+      mg.add_dependency(Box::new(dep));
+      mg.set_resolved_module(Some(origin), dep_id, module)
+        .expect("should set resolved module");
+    }
+    for module in [a, b, c] {
+      let file = ArcPath::from(format!("/{module}.js").as_str());
+      artifact
+        .file_dependencies
+        .add_files(&ResourceId::Module(module), &ArcPathSet::from_iter([file]));
+    }
+    artifact
+  }
+
+  #[test]
+  fn should_report_be_empty() {
+    let mut report = InvalidationReport::default();
+    assert!(report.is_empty());
+    report.add_files(vec![]);
+    assert!(report.is_empty());
+    report.add_files(vec![change("/a.js")]);
+    assert!(!report.is_empty());
+    assert_eq!(report.files.len(), 1);
+    assert!(report.files[0].modules.is_empty());
+    assert!(report.files[0].dependents.is_empty());
+
+    let report = InvalidationReport {
+      version: Some(VersionChange {
+        old: None,
+        new: "new".into(),
+      }),
+      ..Default::default()
+    };
+    assert!(!report.is_empty());
+  }
+
+  #[test]
+  fn should_attribute_modules_to_first_cause() {
+    let artifact = create_artifact();
+    let mut report = InvalidationReport::default();
+    report.add_files(vec![change("/c.js"), change("/b.js"), change("/d.js")]);
+    report.attribute_modules(&artifact);
+
+    let [a, b, c] = ["a", "b", "c"].map(ModuleIdentifier::from);
+    assert_eq!(report.files[0].modules, vec![c]);
+    assert_eq!(report.files[0].dependents, vec![a, b]);
+    // `b` is invalidated by `/c.js` already
+    assert!(report.files[1].modules.is_empty());
+    assert!(report.files[1].dependents.is_empty());
+    // unknown files invalidate nothing
+    assert!(report.files[2].modules.is_empty());
+    assert!(report.files[2].dependents.is_empty());
+  }
+
+  #[test]
+  fn should_report_to_json() {
+    let artifact = create_artifact();
+    let mut report = InvalidationReport {
+      version: Some(VersionChange {
+        old: Some("old".into()),
+        new: "new".into(),
+      }),
+      build_dependencies: vec![SnapshotChange {
+        path: ArcPath::from("/rspack.config.js"),
+        old: Strategy::PackageVersion("1.0.0".into()),
+        new: None,
+      }],
+      files: vec![],
+    };
+    report.add_files(vec![change("/b.js")]);
+    report.attribute_modules(&artifact);
+
+    let json = report.to_json();
+    assert_eq!(json["version"]["old"], "old");
+    assert_eq!(json["version"]["new"], "new");
+    assert_eq!(json["buildDependencies"][0]["path"], "/rspack.config.js");
+    assert_eq!(
+      json["buildDependencies"][0]["old"]["type"],
+      "packageVersion"
+    );
+    assert_eq!(json["buildDependencies"][0]["old"]["version"], "1.0.0");
+    assert!(json["buildDependencies"][0]["new"].is_null());
+    assert_eq!(json["files"][0]["path"], "/b.js");
+    assert_eq!(json["files"][0]["old"]["type"], "pathHash");
+    assert_eq!(json["files"][0]["old"]["hash"], "0000000000000001");
+    assert_eq!(json["files"][0]["modules"], serde_json::json!(["b"]));
+    assert_eq!(json["files"][0]["dependents"], serde_json::json!(["a"]));
+
+    let json = InvalidationReport::default().to_json();
+    assert!(json["version"].is_null());
+  }
+}
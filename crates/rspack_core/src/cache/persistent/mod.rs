@@ -1,5 +1,6 @@
 pub mod build_dependencies;
 mod cacheable_context;
+mod invalidation;
 pub mod occasion;
 pub mod snapshot;
 pub mod storage;
@@ -10,28 +11,45 @@ use std::{
 };
 
 pub use cacheable_context::CacheableContext;
+pub use invalidation::{FileInvalidation, InvalidationReport, VersionChange};
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem};
 use rspack_paths::ArcPathSet;
 use rspack_workspace::rspack_pkg_version;
 
 use self::{
   build_dependencies::{BuildDeps, BuildDepsOptions},
-  occasion::{MakeOccasion, MetaOccasion},
+  occasion::{
+    ChunkGraphOccasion, ChunkRenderOccasion, CodeGenerateOccasion, DeterministicIdsOccasion,
+    MakeOccasion, MetaOccasion, MinimizeOccasion,
+  },
   snapshot::{Snapshot, SnapshotOptions},
-  storage::{Storage, StorageOptions, create_storage},
+  storage::{Storage, StorageOptions, create_storage, find_previous_version},
 };
 use super::Cache;
 use crate::{
-  Compilation, CompilerOptions, Logger,
+  ChunkGraphCacheArtifact, CodeGenerationCacheArtifact, Compilation, CompilerOptions, Logger,
   compilation::build_module_graph::{BuildModuleGraphArtifact, BuildModuleGraphArtifactState},
 };
 
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone)]
 pub struct PersistentCacheOptions {
   pub build_dependencies: BuildDepsOptions,
   pub version: String,
   pub snapshot: SnapshotOptions,
   pub storage: StorageOptions,
+  /// Report why the cache is invalidated in a warm start through the logger.
+  pub invalidation_report: bool,
+}
+
+impl Hash for PersistentCacheOptions {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    // `invalidation_report` is a diagnostic option, turning it on should not
+    // change the cache version.
+    self.build_dependencies.hash(state);
+    self.version.hash(state);
+    self.snapshot.hash(state);
+    self.storage.hash(state);
+  }
 }
 
 /// Persistent cache implementation
@@ -43,6 +61,16 @@ pub struct PersistentCache {
   storage: Arc<dyn Storage>,
   make_occasion: MakeOccasion,
   meta_occasion: MetaOccasion,
+  chunk_graph_occasion: ChunkGraphOccasion,
+  code_generate_occasion: CodeGenerateOccasion,
+  chunk_render_occasion: ChunkRenderOccasion,
+  deterministic_ids_occasion: DeterministicIdsOccasion,
+  minimize_occasion: MinimizeOccasion,
+  version: String,
+  storage_options: StorageOptions,
+  intermediate_filesystem: Arc<dyn IntermediateFileSystem>,
+  /// The report of the first compilation, `None` if the report is disabled or emitted.
+  invalidation_report: Option<InvalidationReport>,
   async_mode: bool,
   // TODO replace to logger and output warnings directly.
   warnings: Vec<String>,
@@ -66,10 +94,19 @@ impl PersistentCache {
       compiler_options.mode.hash(&mut hasher);
       hex::encode(hasher.finish().to_ne_bytes())
     };
-    let storage = create_storage(option.storage.clone(), version, intermediate_filesystem);
+    let storage = create_storage(
+      option.storage.clone(),
+      version.clone(),
+      intermediate_filesystem.clone(),
+    );
     let context = Arc::new(CacheableContext);
-    let make_occasion = MakeOccasion::new(storage.clone(), context);
+    let make_occasion = MakeOccasion::new(storage.clone(), context.clone());
     let meta_occasion = MetaOccasion::new(storage.clone());
+    let chunk_graph_occasion = ChunkGraphOccasion::new(storage.clone(), context.clone());
+    let code_generate_occasion = CodeGenerateOccasion::new(storage.clone(), context.clone());
+    let chunk_render_occasion = ChunkRenderOccasion::new(storage.clone(), context.clone());
+    let minimize_occasion = MinimizeOccasion::new(storage.clone(), context);
+    let deterministic_ids_occasion = DeterministicIdsOccasion::new(storage.clone());
     Self {
       initialized: false,
       build_deps: BuildDeps::new(
@@ -82,6 +119,15 @@ impl PersistentCache {
       storage,
       make_occasion,
       meta_occasion,
+      chunk_graph_occasion,
+      code_generate_occasion,
+      chunk_render_occasion,
+      deterministic_ids_occasion,
+      minimize_occasion,
+      version,
+      storage_options: option.storage.clone(),
+      intermediate_filesystem,
+      invalidation_report: option.invalidation_report.then(InvalidationReport::default),
       async_mode,
       warnings: Default::default(),
     }
@@ -93,8 +139,17 @@ impl PersistentCache {
     }
     self.initialized = true;
 
-    if let Err(err) = self.build_deps.validate().await {
-      self.warnings.push(err.to_string());
+    match self
+      .build_deps
+      .validate(self.invalidation_report.is_some())
+      .await
+    {
+      Ok(changes) => {
+        if let Some(report) = &mut self.invalidation_report {
+          report.build_dependencies = changes;
+        }
+      }
+      Err(err) => self.warnings.push(err.to_string()),
     }
     if let Err(err) = self.meta_occasion.recovery().await {
       self.warnings.push(err.to_string());
@@ -139,6 +194,36 @@ impl Cache for PersistentCache {
           }
         };
       tracing::debug!("cache::snapshot recovery {modified_paths:?} {removed_paths:?}",);
+      if let Some(report) = &mut self.invalidation_report {
+        if is_hot_start {
+          let changed_paths = modified_paths
+            .iter()
+            .chain(removed_paths.iter())
+            .cloned()
+            .collect();
+          match self.snapshot.explain(&changed_paths).await {
+            Ok(changes) => report.add_files(changes),
+            Err(err) => self.warnings.push(err.to_string()),
+          }
+        } else if report.build_dependencies.is_empty() {
+          // the storage is reset by the changed build dependencies, which are reported already.
+          match find_previous_version(
+            &self.storage_options,
+            &self.version,
+            self.intermediate_filesystem.clone(),
+          )
+          .await
+          {
+            Ok(old) => {
+              report.version = Some(VersionChange {
+                old,
+                new: self.version.clone(),
+              })
+            }
+            Err(err) => self.warnings.push(err.to_string()),
+          }
+        }
+      }
       compilation.modified_files.extend(modified_paths);
       compilation.removed_files.extend(removed_paths);
       return is_hot_start;
@@ -184,6 +269,14 @@ impl Cache for PersistentCache {
     for msg in std::mem::take(&mut self.warnings) {
       logger.warn(msg);
     }
+    if let Some(report) = self.invalidation_report.take()
+      && !report.is_empty()
+    {
+      logger.info(format!(
+        "cache invalidation report\n{}",
+        serde_json::to_string_pretty(&report.to_json()).expect("should serialize report")
+      ));
+    }
   }
 
   async fn before_build_module_graph(&mut self, make_artifact: &mut BuildModuleGraphArtifact) {
@@ -196,10 +289,73 @@ impl Cache for PersistentCache {
         Ok(artifact) => *make_artifact = artifact,
         Err(err) => self.warnings.push(err.to_string()),
       }
+      if let Some(report) = &mut self.invalidation_report {
+        report.attribute_modules(make_artifact);
+      }
     }
   }
 
   async fn after_build_module_graph(&mut self, make_artifact: &BuildModuleGraphArtifact) {
     self.make_occasion.save(make_artifact);
   }
+
+  async fn before_seal(&mut self, compilation: &mut Compilation) {
+    if compilation.is_rebuild {
+      // rebuild reuses the memory cache, only collect the new results.
+      compilation.chunk_graph_cache_artifact = ChunkGraphCacheArtifact::new(None);
+      compilation.code_generation_cache_artifact =
+        CodeGenerationCacheArtifact::new(Default::default());
+      return;
+    }
+    match self.chunk_graph_occasion.recovery().await {
+      Ok(artifact) => compilation.chunk_graph_cache_artifact = artifact,
+      Err(err) => self.warnings.push(err.to_string()),
+    }
+    match self.code_generate_occasion.recovery().await {
+      Ok(artifact) => compilation.code_generation_cache_artifact = artifact,
+      Err(err) => self.warnings.push(err.to_string()),
+    }
+    if let Err(err) = self
+      .chunk_render_occasion
+      .recovery(&compilation.chunk_render_cache_artifact)
+      .await
+    {
+      self.warnings.push(err.to_string());
+    }
+    match self.deterministic_ids_occasion.recovery().await {
+      Ok(artifact) => compilation.deterministic_ids_artifact = artifact,
+      Err(err) => self.warnings.push(err.to_string()),
+    }
+    if let Err(err) = self
+      .minimize_occasion
+      .recovery(&compilation.minimize_cache_artifact)
+      .await
+    {
+      self.warnings.push(err.to_string());
+    }
+  }
+
+  async fn after_seal(&mut self, compilation: &Compilation) {
+    let modules = compilation
+      .get_module_graph()
+      .modules()
+      .keys()
+      .copied()
+      .collect();
+    self
+      .chunk_graph_occasion
+      .save(&compilation.chunk_graph_cache_artifact);
+    self
+      .code_generate_occasion
+      .save(&compilation.code_generation_cache_artifact, &modules);
+    self
+      .chunk_render_occasion
+      .save(&compilation.chunk_render_cache_artifact);
+    self
+      .deterministic_ids_occasion
+      .save(&compilation.deterministic_ids_artifact);
+    self
+      .minimize_occasion
+      .save(&compilation.minimize_cache_artifact);
+  }
 }
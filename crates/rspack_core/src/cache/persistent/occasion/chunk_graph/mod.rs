@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use rspack_cacheable::{from_bytes, to_bytes};
+use rspack_error::Result;
+
+use super::super::{Storage, cacheable_context::CacheableContext};
+use crate::{ChunkGraphCacheArtifact, build_chunk_graph::snapshot::ChunkGraphSnapshot};
+
+pub const SCOPE: &str = "chunk_graph";
+
+const SNAPSHOT_KEY: &[u8] = b"snapshot";
+
+/// Chunk Graph Occasion is used to save the chunk graph snapshot.
+///
+/// The snapshot is keyed by the fingerprint of the code splitting inputs, see `ChunkGraphCacheArtifact`.
+#[derive(Debug)]
+pub struct ChunkGraphOccasion {
+  context: Arc<CacheableContext>,
+  storage: Arc<dyn Storage>,
+  /// the fingerprint of the saved snapshot
+  saved: Option<String>,
+}
+
+impl ChunkGraphOccasion {
+  pub fn new(storage: Arc<dyn Storage>, context: Arc<CacheableContext>) -> Self {
+    Self {
+      context,
+      storage,
+      saved: None,
+    }
+  }
+
+  #[tracing::instrument("Cache::Occasion::ChunkGraph::save", skip_all)]
+  pub fn save(&mut self, artifact: &ChunkGraphCacheArtifact) {
+    let Some(snapshot) = artifact.snapshot() else {
+      return;
+    };
+    if self.saved.as_deref() == Some(snapshot.fingerprint()) {
+      return;
+    }
+    match to_bytes(snapshot, self.context.as_ref()) {
+      Ok(bytes) => {
+        self.storage.set(SCOPE, SNAPSHOT_KEY.to_vec(), bytes);
+        self.saved = Some(snapshot.fingerprint().to_string());
+      }
+      Err(err) => {
+        // the chunk graph with unsupported options, e.g. a function filename, can not be saved
+        tracing::warn!("to bytes failed {:?}", err);
+      }
+    }
+  }
+
+  #[tracing::instrument("Cache::Occasion::ChunkGraph::recovery", skip_all)]
+  pub async fn recovery(&mut self) -> Result<ChunkGraphCacheArtifact> {
+    let mut snapshot = None;
+    for (key, value) in self.storage.load(SCOPE).await? {
+      if key.as_slice() != SNAPSHOT_KEY {
+        continue;
+      }
+      let value: ChunkGraphSnapshot = from_bytes(&value, self.context.as_ref())
+        .expect("unexpected chunk graph snapshot deserialize failed");
+      self.saved = Some(value.fingerprint().to_string());
+      snapshot = Some(value);
+    }
+    tracing::debug!("recovery chunk graph snapshot: {}", snapshot.is_some());
+    Ok(ChunkGraphCacheArtifact::new(snapshot))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use super::{
+    super::super::{cacheable_context::CacheableContext, storage::MemoryStorage},
+    ChunkGraphOccasion,
+  };
+  use crate::{ChunkGraphCacheArtifact, build_chunk_graph::snapshot::ChunkGraphSnapshot};
+
+  #[tokio::test]
+  async fn should_chunk_graph_occasion_work() {
+    let storage = Arc::new(MemoryStorage::default());
+    let context = Arc::new(CacheableContext);
+
+    let mut occasion = ChunkGraphOccasion::new(storage.clone(), context.clone());
+    let mut artifact = occasion.recovery().await.expect("should recovery");
+    assert!(artifact.is_enabled());
+    assert!(artifact.snapshot().is_none());
+    artifact.set_snapshot(Some(ChunkGraphSnapshot::from_entries(
+      "aaa",
+      &["main", "admin"],
+    )));
+    occasion.save(&artifact);
+
+    // restart
+    let mut occasion = ChunkGraphOccasion::new(storage.clone(), context.clone());
+    let artifact = occasion.recovery().await.expect("should recovery");
+    let snapshot = artifact.snapshot().expect("should have snapshot");
+    assert_eq!(snapshot.fingerprint(), "aaa");
+    assert_eq!(snapshot.runtimes().len(), 2);
+    assert_eq!(
+      snapshot.entrypoints().collect::<Vec<_>>(),
+      vec!["main", "admin"]
+    );
+
+    // a compilation without snapshot, e.g. with diagnostics, keeps the saved one
+    occasion.save(&ChunkGraphCacheArtifact::new(None));
+    let mut occasion = ChunkGraphOccasion::new(storage.clone(), context.clone());
+    let mut artifact = occasion.recovery().await.expect("should recovery");
+    assert_eq!(
+      artifact.snapshot().map(|snapshot| snapshot.fingerprint()),
+      Some("aaa")
+    );
+
+    // the module graph changed
+    artifact.set_snapshot(Some(ChunkGraphSnapshot::from_entries("bbb", &["main"])));
+    occasion.save(&artifact);
+    let mut occasion = ChunkGraphOccasion::new(storage, context);
+    let artifact = occasion.recovery().await.expect("should recovery");
+    let snapshot = artifact.snapshot().expect("should have snapshot");
+    assert_eq!(snapshot.fingerprint(), "bbb");
+    assert_eq!(snapshot.entrypoints().collect::<Vec<_>>(), vec!["main"]);
+  }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use rspack_cacheable::{cacheable, from_bytes, to_bytes, with::AsPreset};
+use rspack_collections::{Identifier, IdentifierSet};
+use rspack_error::Result;
+use rspack_sources::BoxSource;
+
+use super::super::{Storage, cacheable_context::CacheableContext};
+use crate::ChunkRenderCacheArtifact;
+
+pub const SCOPE: &str = "chunk_render";
+
+/// The rendered chunk source.
+#[cacheable]
+struct Entry {
+  #[cacheable(with=AsPreset)]
+  source: BoxSource,
+}
+
+/// Chunk Render Occasion is used to save ChunkRenderCacheArtifact.
+///
+/// The rendered sources are keyed by chunk content hash.
+#[derive(Debug)]
+pub struct ChunkRenderOccasion {
+  context: Arc<CacheableContext>,
+  storage: Arc<dyn Storage>,
+  /// the saved chunk content hashes
+  saved: IdentifierSet,
+}
+
+impl ChunkRenderOccasion {
+  pub fn new(storage: Arc<dyn Storage>, context: Arc<CacheableContext>) -> Self {
+    Self {
+      context,
+      storage,
+      saved: Default::default(),
+    }
+  }
+
+  #[tracing::instrument("Cache::Occasion::ChunkRender::save", skip_all)]
+  pub fn save(&mut self, artifact: &ChunkRenderCacheArtifact) {
+    let entries = artifact.entries();
+    let alive: IdentifierSet = entries.iter().map(|(key, _)| *key).collect();
+
+    // the sources collected by memory gc are removed as well
+    self.saved.retain(|key| {
+      let outdated = !alive.contains(key);
+      if outdated {
+        self.storage.remove(SCOPE, key.as_bytes());
+      }
+      !outdated
+    });
+
+    for (key, source) in entries {
+      if self.saved.contains(&key) {
+        continue;
+      }
+      match to_bytes(&Entry { source }, self.context.as_ref()) {
+        Ok(bytes) => {
+          self.storage.set(SCOPE, key.as_bytes().to_vec(), bytes);
+          self.saved.insert(key);
+        }
+        Err(err) => {
+          tracing::warn!("to bytes failed {:?}", err);
+        }
+      }
+    }
+  }
+
+  #[tracing::instrument("Cache::Occasion::ChunkRender::recovery", skip_all)]
+  pub async fn recovery(&mut self, artifact: &ChunkRenderCacheArtifact) -> Result<()> {
+    self.saved.clear();
+    let mut entries = vec![];
+    for (key, value) in self.storage.load(SCOPE).await? {
+      let entry: Entry = from_bytes(&value, self.context.as_ref())
+        .expect("unexpected chunk render result deserialize failed");
+      let key = Identifier::from(String::from_utf8(key.to_vec()).expect("should be utf8 key"));
+      self.saved.insert(key);
+      entries.push((key, entry.source));
+    }
+    tracing::debug!("recovery {} chunk render results", entries.len());
+    artifact.restore(entries);
+    Ok(())
+  }
+}
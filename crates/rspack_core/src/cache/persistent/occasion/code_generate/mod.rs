@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use rspack_cacheable::{
+  cacheable, from_bytes, to_bytes,
+  with::{AsCacheable, AsPreset, AsTuple2, AsVec},
+};
+use rspack_collections::{Identifier, IdentifierMap, IdentifierSet};
+use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
+use rspack_sources::BoxSource;
+
+use super::super::{Storage, cacheable_context::CacheableContext};
+use crate::{
+  CodeGenerationCacheArtifact, CodeGenerationResult, ModuleIdentifier, RuntimeGlobals, SourceType,
+};
+
+pub const SCOPE: &str = "code_generate";
+
+/// The serializable part of code generation result.
+#[cacheable]
+struct Entry {
+  module: ModuleIdentifier,
+  #[cacheable(with=AsVec<AsTuple2<AsCacheable, AsPreset>>)]
+  sources: Vec<(SourceType, BoxSource)>,
+  runtime_requirements: RuntimeGlobals,
+  hash: Option<RspackHashDigest>,
+}
+
+/// Code Generate Occasion is used to save code generation results.
+///
+/// The results are keyed by module identifier and module hash, see `CodeGenerationCacheArtifact`.
+#[derive(Debug)]
+pub struct CodeGenerateOccasion {
+  context: Arc<CacheableContext>,
+  storage: Arc<dyn Storage>,
+  /// the saved cache keys and their modules
+  saved: IdentifierMap<ModuleIdentifier>,
+}
+
+impl CodeGenerateOccasion {
+  pub fn new(storage: Arc<dyn Storage>, context: Arc<CacheableContext>) -> Self {
+    Self {
+      context,
+      storage,
+      saved: Default::default(),
+    }
+  }
+
+  #[tracing::instrument("Cache::Occasion::CodeGenerate::save", skip_all)]
+  pub fn save(&mut self, artifact: &CodeGenerationCacheArtifact, modules: &IdentifierSet) {
+    let added = artifact.added();
+    let added_modules: IdentifierSet = added.values().map(|(module, _)| *module).collect();
+
+    // remove the outdated results, which module hash has changed or module is removed
+    self.saved.retain(|key, module| {
+      let outdated =
+        !modules.contains(module) || (added_modules.contains(module) && !added.contains_key(key));
+      if outdated {
+        self.storage.remove(SCOPE, key.as_bytes());
+      }
+      !outdated
+    });
+
+    for (key, (module, result)) in added {
+      if self.saved.contains_key(key) {
+        continue;
+      }
+      let entry = Entry {
+        module: *module,
+        sources: result
+          .inner()
+          .iter()
+          .map(|(source_type, source)| (*source_type, source.clone()))
+          .collect(),
+        runtime_requirements: result.runtime_requirements,
+        hash: result.hash.clone(),
+      };
+      match to_bytes(&entry, self.context.as_ref()) {
+        Ok(bytes) => {
+          self.storage.set(SCOPE, key.as_bytes().to_vec(), bytes);
+          self.saved.insert(*key, *module);
+        }
+        Err(err) => {
+          tracing::warn!("to bytes failed {:?}", err);
+        }
+      }
+    }
+  }
+
+  #[tracing::instrument("Cache::Occasion::CodeGenerate::recovery", skip_all)]
+  pub async fn recovery(&mut self) -> Result<CodeGenerationCacheArtifact> {
+    let mut results = IdentifierMap::default();
+    self.saved.clear();
+    for (key, value) in self.storage.load(SCOPE).await? {
+      let entry: Entry = from_bytes(&value, self.context.as_ref())
+        .expect("unexpected code generation result deserialize failed");
+      let key = Identifier::from(String::from_utf8(key.to_vec()).expect("should be utf8 key"));
+      let mut result = CodeGenerationResult {
+        runtime_requirements: entry.runtime_requirements,
+        hash: entry.hash,
+        ..Default::default()
+      };
+      for (source_type, source) in entry.sources {
+        result.inner.insert(source_type, source);
+      }
+      self.saved.insert(key, entry.module);
+      results.insert(key, result);
+    }
+    tracing::debug!("recovery {} code generation results", results.len());
+    Ok(CodeGenerationCacheArtifact::new(results))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_collections::{Identifier, IdentifierSet};
+  use rspack_hash::RspackHashDigest;
+  use rspack_sources::{RawStringSource, SourceExt};
+
+  use super::{
+    super::super::{cacheable_context::CacheableContext, storage::MemoryStorage},
+    CodeGenerateOccasion,
+  };
+  use crate::{CodeGenerationResult, RuntimeGlobals, SourceType};
+
+  #[tokio::test]
+  async fn should_code_generate_occasion_work() {
+    let storage = Arc::new(MemoryStorage::default());
+    let context = Arc::new(CacheableContext);
+    let module = Identifier::from("./a.js");
+    let modules = IdentifierSet::from_iter([module]);
+    let hash_a = RspackHashDigest::from("aaa");
+    let hash_b = RspackHashDigest::from("bbb");
+
+    let mut occasion = CodeGenerateOccasion::new(storage.clone(), context.clone());
+    let mut artifact = occasion.recovery().await.expect("should recovery");
+    assert!(artifact.get(&module, &hash_a).is_none());
+    let mut result = CodeGenerationResult::default()
+      .with_javascript(RawStringSource::from("console.log(1)").boxed());
+    result.runtime_requirements = RuntimeGlobals::REQUIRE;
+    artifact.add(&module, &hash_a, &result);
+    occasion.save(&artifact, &modules);
+
+    // restart
+    let mut occasion = CodeGenerateOccasion::new(storage.clone(), context.clone());
+    let mut artifact = occasion.recovery().await.expect("should recovery");
+    let cached = artifact
+      .get(&module, &hash_a)
+      .expect("should have cached result");
+    assert_eq!(cached.runtime_requirements, RuntimeGlobals::REQUIRE);
+    assert_eq!(
+      cached
+        .get(&SourceType::JavaScript)
+        .map(|s| s.source().into_string_lossy().into_owned()),
+      Some("console.log(1)".to_string())
+    );
+
+    // module hash changed, the outdated result should be removed
+    artifact.add(&module, &hash_b, &result);
+    occasion.save(&artifact, &modules);
+    let mut occasion = CodeGenerateOccasion::new(storage, context);
+    let artifact = occasion.recovery().await.expect("should recovery");
+    assert!(artifact.get(&module, &hash_a).is_none());
+    assert!(artifact.get(&module, &hash_b).is_some());
+  }
+}
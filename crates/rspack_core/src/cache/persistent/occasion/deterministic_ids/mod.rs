@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use rspack_collections::Identifier;
+use rspack_error::Result;
+
+use super::super::Storage;
+use crate::DeterministicIdsArtifact;
+
+pub const SCOPE: &str = "deterministic_ids";
+
+const MODULE_KEY_PREFIX: &str = "m:";
+const CHUNK_KEY_PREFIX: &str = "c:";
+
+/// Deterministic Ids Occasion is used to save DeterministicIdsArtifact.
+///
+/// The module ids are keyed by module identifier and the chunk ids are keyed by full chunk name,
+/// so a cold start with cache yields the same ids as the last compilation.
+#[derive(Debug)]
+pub struct DeterministicIdsOccasion {
+  storage: Arc<dyn Storage>,
+  /// the saved ids
+  saved: DeterministicIdsArtifact,
+}
+
+impl DeterministicIdsOccasion {
+  pub fn new(storage: Arc<dyn Storage>) -> Self {
+    Self {
+      storage,
+      saved: Default::default(),
+    }
+  }
+
+  #[tracing::instrument("Cache::Occasion::DeterministicIds::save", skip_all)]
+  pub fn save(&mut self, artifact: &DeterministicIdsArtifact) {
+    if &self.saved == artifact {
+      return;
+    }
+
+    for module in self.saved.module_ids.keys() {
+      if !artifact.module_ids.contains_key(module) {
+        self.storage.remove(SCOPE, module_key(module).as_bytes());
+      }
+    }
+    for (module, id) in &artifact.module_ids {
+      if self.saved.module_ids.get(module) != Some(id) {
+        self.storage.set(
+          SCOPE,
+          module_key(module).into_bytes(),
+          id.as_str().as_bytes().to_vec(),
+        );
+      }
+    }
+
+    for name in self.saved.chunk_ids.keys() {
+      if !artifact.chunk_ids.contains_key(name) {
+        self.storage.remove(SCOPE, chunk_key(name).as_bytes());
+      }
+    }
+    for (name, id) in &artifact.chunk_ids {
+      if self.saved.chunk_ids.get(name) != Some(id) {
+        self.storage.set(
+          SCOPE,
+          chunk_key(name).into_bytes(),
+          id.as_str().as_bytes().to_vec(),
+        );
+      }
+    }
+
+    self.saved = artifact.clone();
+  }
+
+  #[tracing::instrument("Cache::Occasion::DeterministicIds::recovery", skip_all)]
+  pub async fn recovery(&mut self) -> Result<DeterministicIdsArtifact> {
+    let mut artifact = DeterministicIdsArtifact::default();
+    for (key, value) in self.storage.load(SCOPE).await? {
+      let key = String::from_utf8(key.to_vec()).expect("should be utf8 key");
+      let id = String::from_utf8(value.to_vec()).expect("should be utf8 id");
+      if let Some(module) = key.strip_prefix(MODULE_KEY_PREFIX) {
+        artifact
+          .module_ids
+          .insert(Identifier::from(module), id.into());
+      } else if let Some(name) = key.strip_prefix(CHUNK_KEY_PREFIX) {
+        artifact.chunk_ids.insert(name.to_string(), id.into());
+      }
+    }
+    tracing::debug!(
+      "recovery {} module ids and {} chunk ids",
+      artifact.module_ids.len(),
+      artifact.chunk_ids.len()
+    );
+    self.saved = artifact.clone();
+    Ok(artifact)
+  }
+}
+
+fn module_key(module: &Identifier) -> String {
+  format!("{MODULE_KEY_PREFIX}{module}")
+}
+
+fn chunk_key(name: &str) -> String {
+  format!("{CHUNK_KEY_PREFIX}{name}")
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_collections::Identifier;
+
+  use super::{super::super::storage::MemoryStorage, DeterministicIdsOccasion};
+
+  #[tokio::test]
+  async fn should_deterministic_ids_occasion_work() {
+    let storage = Arc::new(MemoryStorage::default());
+    let module_a = Identifier::from("javascript/auto|/src/a.js");
+    let module_b = Identifier::from("javascript/auto|/src/b.js");
+
+    let mut occasion = DeterministicIdsOccasion::new(storage.clone());
+    let mut artifact = occasion.recovery().await.expect("should recovery");
+    assert!(artifact.is_empty());
+    artifact.module_ids.insert(module_a, "123".into());
+    artifact.module_ids.insert(module_b, "456".into());
+    artifact
+      .chunk_ids
+      .insert("src_a_js-src_b_js".to_string(), "789".into());
+    occasion.save(&artifact);
+
+    // restart
+    let mut occasion = DeterministicIdsOccasion::new(storage.clone());
+    let mut recovered = occasion.recovery().await.expect("should recovery");
+    assert_eq!(recovered, artifact);
+
+    // the removed module should be removed from storage
+    recovered.module_ids.remove(&module_b);
+    occasion.save(&recovered);
+    let mut occasion = DeterministicIdsOccasion::new(storage);
+    let artifact = occasion.recovery().await.expect("should recovery");
+    assert_eq!(artifact.module_ids.len(), 1);
+    assert_eq!(
+      artifact.module_ids.get(&module_a).map(|id| id.as_str()),
+      Some("123")
+    );
+    assert_eq!(artifact.chunk_ids.len(), 1);
+  }
+}
@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use rspack_cacheable::{cacheable, from_bytes, to_bytes, with::AsPreset};
+use rspack_collections::{Identifier, IdentifierSet};
+use rspack_error::Result;
+use rspack_sources::BoxSource;
+
+use super::super::{Storage, cacheable_context::CacheableContext};
+use crate::{MinimizeCacheArtifact, MinimizedAsset};
+
+pub const SCOPE: &str = "minimize";
+
+/// The minified asset.
+#[cacheable]
+struct Entry {
+  #[cacheable(with=AsPreset)]
+  source: BoxSource,
+  extracted_comments: Vec<String>,
+}
+
+/// Minimize Occasion is used to save MinimizeCacheArtifact.
+///
+/// The minified assets are keyed by the hash of the input source and the minimizer options.
+#[derive(Debug)]
+pub struct MinimizeOccasion {
+  context: Arc<CacheableContext>,
+  storage: Arc<dyn Storage>,
+  /// the saved keys
+  saved: IdentifierSet,
+}
+
+impl MinimizeOccasion {
+  pub fn new(storage: Arc<dyn Storage>, context: Arc<CacheableContext>) -> Self {
+    Self {
+      context,
+      storage,
+      saved: Default::default(),
+    }
+  }
+
+  #[tracing::instrument("Cache::Occasion::Minimize::save", skip_all)]
+  pub fn save(&mut self, artifact: &MinimizeCacheArtifact) {
+    let entries = artifact.entries();
+    let alive: IdentifierSet = entries.iter().map(|(key, _)| *key).collect();
+
+    // the assets collected by memory gc are removed as well
+    self.saved.retain(|key| {
+      let outdated = !alive.contains(key);
+      if outdated {
+        self.storage.remove(SCOPE, key.as_bytes());
+      }
+      !outdated
+    });
+
+    for (key, asset) in entries {
+      if self.saved.contains(&key) {
+        continue;
+      }
+      let entry = Entry {
+        source: asset.source,
+        extracted_comments: asset.extracted_comments,
+      };
+      match to_bytes(&entry, self.context.as_ref()) {
+        Ok(bytes) => {
+          self.storage.set(SCOPE, key.as_bytes().to_vec(), bytes);
+          self.saved.insert(key);
+        }
+        Err(err) => {
+          tracing::warn!("to bytes failed {:?}", err);
+        }
+      }
+    }
+  }
+
+  #[tracing::instrument("Cache::Occasion::Minimize::recovery", skip_all)]
+  pub async fn recovery(&mut self, artifact: &MinimizeCacheArtifact) -> Result<()> {
+    self.saved.clear();
+    let mut entries = vec![];
+    for (key, value) in self.storage.load(SCOPE).await? {
+      let entry: Entry = from_bytes(&value, self.context.as_ref())
+        .expect("unexpected minimize result deserialize failed");
+      let key = Identifier::from(String::from_utf8(key.to_vec()).expect("should be utf8 key"));
+      self.saved.insert(key);
+      entries.push((
+        key,
+        MinimizedAsset {
+          source: entry.source,
+          extracted_comments: entry.extracted_comments,
+        },
+      ));
+    }
+    tracing::debug!("recovery {} minimize results", entries.len());
+    artifact.restore(entries);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_collections::Identifier;
+  use rspack_sources::{RawStringSource, Source, SourceExt};
+
+  use super::{
+    super::super::{cacheable_context::CacheableContext, storage::MemoryStorage},
+    MinimizeOccasion,
+  };
+  use crate::{MemoryGCStorage, MinimizeCacheArtifact, MinimizedAsset};
+
+  #[tokio::test]
+  async fn should_minimize_occasion_work() {
+    let storage = Arc::new(MemoryStorage::default());
+    let context = Arc::new(CacheableContext);
+    let key = Identifier::from("hash_a");
+
+    let mut occasion = MinimizeOccasion::new(storage.clone(), context.clone());
+    let artifact = MinimizeCacheArtifact::new(MemoryGCStorage::new(0));
+    occasion.recovery(&artifact).await.expect("should recovery");
+    assert!(artifact.get(&key).is_none());
+    artifact.set(
+      key,
+      MinimizedAsset {
+        source: RawStringSource::from("console.log(1)").boxed(),
+        extracted_comments: vec!["/*! license */".to_string()],
+      },
+    );
+    occasion.save(&artifact);
+
+    // restart
+    let mut occasion = MinimizeOccasion::new(storage.clone(), context.clone());
+    let artifact = MinimizeCacheArtifact::new(MemoryGCStorage::new(0));
+    occasion.recovery(&artifact).await.expect("should recovery");
+    let cached = artifact.get(&key).expect("should have cached result");
+    assert_eq!(cached.source.source().into_string_lossy(), "console.log(1)");
+    assert_eq!(
+      cached.extracted_comments,
+      vec!["/*! license */".to_string()]
+    );
+
+    // the asset is not used in the next generation, it should be removed
+    artifact.start_next_generation();
+    occasion.save(&artifact);
+    let mut occasion = MinimizeOccasion::new(storage, context);
+    let artifact = MinimizeCacheArtifact::new(MemoryGCStorage::new(0));
+    occasion.recovery(&artifact).await.expect("should recovery");
+    assert!(artifact.get(&key).is_none());
+  }
+}
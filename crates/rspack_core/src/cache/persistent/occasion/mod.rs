@@ -1,5 +1,15 @@
+pub mod chunk_graph;
+pub mod chunk_render;
+pub mod code_generate;
+pub mod deterministic_ids;
 pub mod make;
 pub mod meta;
+pub mod minimize;
 
+pub use chunk_graph::ChunkGraphOccasion;
+pub use chunk_render::ChunkRenderOccasion;
+pub use code_generate::CodeGenerateOccasion;
+pub use deterministic_ids::DeterministicIdsOccasion;
 pub use make::MakeOccasion;
 pub use meta::MetaOccasion;
+pub use minimize::MinimizeOccasion;
@@ -18,6 +18,15 @@ use crate::FutureConsumer;
 
 pub const SCOPE: &str = "snapshot";
 
+/// The saved strategy and the current strategy of a path.
+#[derive(Debug)]
+pub struct SnapshotChange {
+  pub path: ArcPath,
+  pub old: Strategy,
+  /// `None` means the path is immutable now.
+  pub new: Option<Strategy>,
+}
+
 /// Snapshot is used to check if files have been modified or deleted.
 ///
 /// Snapshot will generate `Strategy` for target file, and check the modification
@@ -72,7 +81,12 @@ impl Snapshot {
     {
       return Some(v);
     }
-    if let Some(h) = helper.path_hash(path).await {
+    let hash = if options.is_content_hash() {
+      helper.content_hash(path).await
+    } else {
+      helper.path_hash(path).await
+    };
+    if let Some(h) = hash {
       return Some(h);
     }
     Some(Strategy::Missing)
@@ -110,6 +124,28 @@ impl Snapshot {
     }
   }
 
+  /// Compare the saved strategies of the paths with the current ones.
+  ///
+  /// This is used to explain why the paths are invalidated, paths which are not
+  /// in snapshot will be skipped.
+  #[tracing::instrument("Cache::Snapshot::explain", skip_all)]
+  pub async fn explain(&self, paths: &ArcPathSet) -> Result<Vec<SnapshotChange>> {
+    let helper = Arc::new(StrategyHelper::new(self.fs.clone()));
+    let mut changes = vec![];
+    for (key, value) in self.storage.load(self.scope).await? {
+      let path: ArcPath = Path::new(&*String::from_utf8_lossy(&key)).into();
+      if !paths.contains(&path) {
+        continue;
+      }
+      let old: Strategy =
+        from_bytes::<Strategy, ()>(&value, &()).expect("should from bytes success");
+      let new = Self::calc_strategy(&self.options, &helper, &path).await;
+      changes.push(SnapshotChange { path, old, new });
+    }
+    changes.sort_by(|a, b| Path::cmp(&a.path, &b.path));
+    Ok(changes)
+  }
+
   #[allow(clippy::type_complexity)]
   #[tracing::instrument("Cache::Snapshot::calc_modified_path", skip_all)]
   pub async fn calc_modified_paths(&self) -> Result<(bool, ArcPathSet, ArcPathSet, ArcPathSet)> {
@@ -129,18 +165,32 @@ impl Snapshot {
           let strategy: Strategy =
             from_bytes::<Strategy, ()>(&value, &()).expect("should from bytes success");
           let validate = helper.validate(&path, &strategy).await;
-          (path, validate)
+          let refreshed = if matches!(validate, ValidateResult::NoChanged) {
+            helper.refresh(&path, &strategy).await
+          } else {
+            None
+          };
+          (key, path, validate, refreshed)
         }
       })
-      .fut_consume(|(path, validate)| match validate {
-        ValidateResult::Modified => {
-          modified_path.insert(path);
-        }
-        ValidateResult::Deleted => {
-          deleted_path.insert(path);
+      .fut_consume(|(key, path, validate, refreshed)| {
+        if let Some(strategy) = refreshed {
+          self.storage.set(
+            self.scope,
+            key.to_vec(),
+            to_bytes::<_, ()>(&strategy, &()).expect("should to bytes success"),
+          );
         }
-        ValidateResult::NoChanged => {
-          no_change_path.insert(path);
+        match validate {
+          ValidateResult::Modified => {
+            modified_path.insert(path);
+          }
+          ValidateResult::Deleted => {
+            deleted_path.insert(path);
+          }
+          ValidateResult::NoChanged => {
+            no_change_path.insert(path);
+          }
         }
       })
       .await;
@@ -156,7 +206,10 @@ mod tests {
   use rspack_fs::{MemoryFileSystem, WritableFileSystem};
   use rspack_paths::ArcPath;
 
-  use super::{super::storage::MemoryStorage, PathMatcher, Snapshot, SnapshotOptions};
+  use super::{
+    super::storage::{MemoryStorage, Storage},
+    PathMatcher, Snapshot, SnapshotOptions, Strategy,
+  };
 
   macro_rules! p {
     ($tt:tt) => {
@@ -253,4 +306,55 @@ mod tests {
     assert!(modified_paths.contains(&p!("/node_modules/lib/file1")));
     assert_eq!(no_change_paths.len(), 1);
   }
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+  async fn should_content_hash_snapshot_work() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = Arc::new(MemoryStorage::default());
+    let options = SnapshotOptions::default().with_content_hash(true);
+
+    fs.create_dir_all("/".into()).await.unwrap();
+    fs.write("/file1".into(), "abc".as_bytes()).await.unwrap();
+    fs.write("/file2".into(), "abc".as_bytes()).await.unwrap();
+
+    let snapshot = Snapshot::new(options, fs.clone(), storage.clone());
+    snapshot.add([p!("/file1"), p!("/file2")].into_iter()).await;
+    let data = storage.load(super::SCOPE).await.unwrap();
+
+    // every mtime changed but only file2 content changed
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    fs.write("/file1".into(), "abc".as_bytes()).await.unwrap();
+    fs.write("/file2".into(), "abcd".as_bytes()).await.unwrap();
+
+    let (is_hot_start, modified_paths, deleted_paths, no_change_paths) =
+      snapshot.calc_modified_paths().await.unwrap();
+    assert!(is_hot_start);
+    assert!(deleted_paths.is_empty());
+    assert!(modified_paths.contains(&p!("/file2")));
+    assert!(no_change_paths.contains(&p!("/file1")));
+
+    // the strategy of the unchanged file is refreshed with the new mtime
+    let refreshed = storage.load(super::SCOPE).await.unwrap();
+    let find = |data: &[(Arc<Vec<u8>>, Arc<Vec<u8>>)]| {
+      data
+        .iter()
+        .find(|(key, _)| key.as_slice() == "/file1".as_bytes())
+        .map(|(_, value)| value.clone())
+        .unwrap()
+    };
+    assert_ne!(find(&data), find(&refreshed));
+
+    let changes = snapshot
+      .explain(&modified_paths)
+      .await
+      .expect("should explain");
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].path, p!("/file2"));
+    assert!(matches!(changes[0].old, Strategy::ContentHash { .. }));
+    assert!(
+      changes
+        .iter()
+        .all(|change| change.new.as_ref() != Some(&change.old))
+    );
+  }
 }
@@ -26,6 +26,9 @@ pub struct SnapshotOptions {
   unmanaged_paths: Vec<PathMatcher>,
   /// managed_paths, snapshot will use lib version strategy
   managed_paths: Vec<PathMatcher>,
+  /// use xxhash64 digest of file content instead of the process-local
+  /// hash, which keeps the snapshot valid on other machines
+  content_hash: bool,
 }
 
 impl SnapshotOptions {
@@ -38,9 +41,19 @@ impl SnapshotOptions {
       immutable_paths,
       unmanaged_paths,
       managed_paths,
+      content_hash: false,
     }
   }
 
+  pub fn with_content_hash(mut self, content_hash: bool) -> Self {
+    self.content_hash = content_hash;
+    self
+  }
+
+  pub fn is_content_hash(&self) -> bool {
+    self.content_hash
+  }
+
   pub fn is_immutable_path(&self, path_str: &str) -> bool {
     for item in &self.immutable_paths {
       if item.try_match(path_str) {
@@ -6,6 +6,7 @@ use std::{
 use rspack_fs::ReadableFileSystem;
 use rspack_paths::{ArcPath, ArcPathDashMap, AssertUtf8};
 use rustc_hash::FxHasher;
+use xxhash_rust::xxh64::Xxh64;
 
 #[derive(Debug, Clone)]
 pub struct ContentHash {
@@ -21,6 +22,9 @@ pub struct HashHelper {
 
   /// Cache mapping file paths to their computed content hashes.
   hash_cache: ArcPathDashMap<Option<ContentHash>>,
+
+  /// Use xxhash64 digests which are stable across platforms and processes.
+  stable: bool,
 }
 
 impl HashHelper {
@@ -29,6 +33,29 @@ impl HashHelper {
     Self {
       fs,
       hash_cache: Default::default(),
+      stable: false,
+    }
+  }
+
+  /// Creates a new HashHelper instance which computes xxhash64 digests.
+  ///
+  /// The digests only depend on file contents, so they can be compared
+  /// with the ones computed on another machine.
+  pub fn new_stable(fs: Arc<dyn ReadableFileSystem>) -> Self {
+    Self {
+      fs,
+      hash_cache: Default::default(),
+      stable: true,
+    }
+  }
+
+  fn hash_bytes(&self, content: &[u8]) -> u64 {
+    if self.stable {
+      xxhash_rust::xxh64::xxh64(content, 0)
+    } else {
+      let mut hasher = FxHasher::default();
+      content.hash(&mut hasher);
+      hasher.finish()
     }
   }
 
@@ -55,10 +82,8 @@ impl HashHelper {
         } else {
           metadata.mtime_ms
         };
-        let mut hasher = FxHasher::default();
-        content.hash(&mut hasher);
         Some(ContentHash {
-          hash: hasher.finish(),
+          hash: self.hash_bytes(&content),
           mtime,
         })
       } else {
@@ -68,14 +93,23 @@ impl HashHelper {
       if let Ok(mut children) = self.fs.read_dir(utf8_path).await {
         children.sort();
         let mut hasher = FxHasher::default();
+        let mut stable_hasher = Xxh64::new(0);
         for item in children {
           let child_path = ArcPath::from(path.join(item));
           if let Some(ContentHash { hash, .. }) = self.content_hash(&child_path).await {
-            hash.hash(&mut hasher);
+            if self.stable {
+              stable_hasher.update(&hash.to_le_bytes());
+            } else {
+              hash.hash(&mut hasher);
+            }
           }
         }
         Some(ContentHash {
-          hash: hasher.finish(),
+          hash: if self.stable {
+            stable_hasher.digest()
+          } else {
+            hasher.finish()
+          },
           // The mtime value is always set to 0 for directories to force hash comparison.
           mtime: 0,
         })
@@ -180,4 +214,31 @@ mod tests {
     assert_ne!(hash1.hash, hash4.hash);
     assert_eq!(hash4.mtime, 0);
   }
+
+  #[tokio::test]
+  async fn stable_content_hash() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    fs.create_dir_all("/a".into()).await.unwrap();
+    fs.write("/a/a1.js".into(), "abc".as_bytes()).await.unwrap();
+
+    let helper = HashHelper::new_stable(fs.clone());
+    let hash1 = helper
+      .content_hash(&ArcPath::from("/a/a1.js"))
+      .await
+      .unwrap();
+    assert_eq!(hash1.hash, xxhash_rust::xxh64::xxh64("abc".as_bytes(), 0));
+    let dir_hash1 = helper.content_hash(&ArcPath::from("/a")).await.unwrap();
+
+    helper.hash_cache.clear();
+    fs.write("/a/a1.js".into(), "abcd".as_bytes())
+      .await
+      .unwrap();
+    let hash2 = helper
+      .content_hash(&ArcPath::from("/a/a1.js"))
+      .await
+      .unwrap();
+    assert_ne!(hash1.hash, hash2.hash);
+    let dir_hash2 = helper.content_hash(&ArcPath::from("/a")).await.unwrap();
+    assert_ne!(dir_hash1.hash, dir_hash2.hash);
+  }
 }
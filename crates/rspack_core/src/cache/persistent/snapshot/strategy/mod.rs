@@ -33,6 +33,13 @@ pub enum Strategy {
   /// This strategy indicates that the current file is in a missing state,
   /// and will return ValidateResult::Modified if it exists.
   Missing,
+
+  /// Check by xxhash64 digest of file content
+  ///
+  /// Similar to `PathHash`, the modified time is only used as a fast pre-check.
+  /// The digest is stable across machines, so the snapshot is still valid after
+  /// the cache directory is restored somewhere else.
+  ContentHash { mtime: u64, hash: u64 },
 }
 
 impl PartialEq for Strategy {
@@ -41,6 +48,7 @@ impl PartialEq for Strategy {
       (Self::PackageVersion(v1), Self::PackageVersion(v2)) => v1 == v2,
       (Self::PathHash { hash: h1, .. }, Self::PathHash { hash: h2, .. }) => h1 == h2,
       (Self::Missing, Self::Missing) => true,
+      (Self::ContentHash { hash: h1, .. }, Self::ContentHash { hash: h2, .. }) => h1 == h2,
       _ => false,
     }
   }
@@ -61,6 +69,7 @@ pub struct StrategyHelper {
   fs: Arc<dyn ReadableFileSystem>,
   package_helper: PackageHelper,
   hash_helper: HashHelper,
+  stable_hash_helper: HashHelper,
 }
 
 impl StrategyHelper {
@@ -68,7 +77,8 @@ impl StrategyHelper {
     Self {
       fs: fs.clone(),
       package_helper: PackageHelper::new(fs.clone()),
-      hash_helper: HashHelper::new(fs),
+      hash_helper: HashHelper::new(fs.clone()),
+      stable_hash_helper: HashHelper::new_stable(fs),
     }
   }
 
@@ -101,6 +111,38 @@ impl StrategyHelper {
     Some(Strategy::PathHash { mtime, hash })
   }
 
+  /// get path file content hash strategy
+  pub async fn content_hash(&self, path: &ArcPath) -> Option<Strategy> {
+    let ContentHash { hash, mtime } = self.stable_hash_helper.content_hash(path).await?;
+    Some(Strategy::ContentHash { mtime, hash })
+  }
+
+  /// Get the strategy with current modified time for an unchanged `Strategy::ContentHash`.
+  ///
+  /// After `git checkout` or restoring the cache, the modified time of every file changes
+  /// and the content has to be hashed again in each build, saving the refreshed strategy
+  /// makes the modified time pre-check work again.
+  pub async fn refresh(&self, path: &ArcPath, strategy: &Strategy) -> Option<Strategy> {
+    let Strategy::ContentHash { mtime, hash } = strategy else {
+      return None;
+    };
+    if &self.modified_time(path).await? == mtime {
+      return None;
+    }
+    let ContentHash {
+      hash: cur_hash,
+      mtime: cur_mtime,
+    } = self.stable_hash_helper.content_hash(path).await?;
+    // directories always have a zero mtime
+    if &cur_hash != hash || &cur_mtime == mtime {
+      return None;
+    }
+    Some(Strategy::ContentHash {
+      mtime: cur_mtime,
+      hash: cur_hash,
+    })
+  }
+
   /// validate path file by target strategy
   pub async fn validate(&self, path: &ArcPath, strategy: &Strategy) -> ValidateResult {
     match strategy {
@@ -131,6 +173,24 @@ impl StrategyHelper {
           ValidateResult::Modified
         }
       }
+      Strategy::ContentHash { mtime, hash } => {
+        let Some(modified_time) = self.modified_time(path).await else {
+          return ValidateResult::Deleted;
+        };
+        if &modified_time == mtime {
+          return ValidateResult::NoChanged;
+        }
+        let Some(ContentHash { hash: cur_hash, .. }) =
+          self.stable_hash_helper.content_hash(path).await
+        else {
+          return ValidateResult::Deleted;
+        };
+        if &cur_hash == hash {
+          ValidateResult::NoChanged
+        } else {
+          ValidateResult::Modified
+        }
+      }
       Strategy::Missing => {
         if self.modified_time(path).await.is_some() {
           ValidateResult::Modified
@@ -253,6 +313,90 @@ mod tests {
     ));
   }
 
+  #[tokio::test]
+  async fn validate_content_hash() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    fs.create_dir_all("/".into()).await.unwrap();
+    fs.write("/file1.js".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+
+    let helper = StrategyHelper::new(fs.clone());
+    let strategy = helper
+      .content_hash(&ArcPath::from("/file1.js"))
+      .await
+      .unwrap();
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::NoChanged
+    ));
+    assert!(
+      helper
+        .refresh(&ArcPath::from("/file1.js"), &strategy)
+        .await
+        .is_none()
+    );
+
+    // same content with a new mtime, e.g. after git checkout
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    let helper = StrategyHelper::new(fs.clone());
+    fs.write("/file1.js".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::NoChanged
+    ));
+    let refreshed = helper
+      .refresh(&ArcPath::from("/file1.js"), &strategy)
+      .await
+      .unwrap();
+    assert_eq!(refreshed, strategy);
+    let (
+      Strategy::ContentHash {
+        mtime: old_mtime, ..
+      },
+      Strategy::ContentHash {
+        mtime: new_mtime, ..
+      },
+    ) = (&strategy, &refreshed)
+    else {
+      panic!("should be content hash strategy");
+    };
+    assert!(old_mtime < new_mtime);
+
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    let helper = StrategyHelper::new(fs.clone());
+    fs.write("/file1.js".into(), "abcd".as_bytes())
+      .await
+      .unwrap();
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::Modified
+    ));
+    assert!(
+      helper
+        .refresh(&ArcPath::from("/file1.js"), &strategy)
+        .await
+        .is_none()
+    );
+
+    let helper = StrategyHelper::new(fs.clone());
+    fs.remove_file("/file1.js".into()).await.unwrap();
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::Deleted
+    ));
+  }
+
   #[tokio::test]
   async fn validate_missing() {
     let fs = Arc::new(MemoryFileSystem::default());
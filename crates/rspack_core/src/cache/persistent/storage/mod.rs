@@ -1,20 +1,119 @@
 // TODO add #[cfg(test)]
 mod memory;
 
-use std::{path::PathBuf, sync::Arc};
+use std::{
+  hash::{Hash, Hasher},
+  path::PathBuf,
+  sync::Arc,
+};
 
 pub use memory::MemoryStorage;
 use rspack_fs::IntermediateFileSystem;
+use rspack_paths::AssertUtf8;
 pub use rspack_storage::Storage;
-use rspack_storage::{BridgeFileSystem, PackStorage, PackStorageOptions};
+use rspack_storage::{
+  BridgeFileSystem, HttpRemoteClient, PackStorage, PackStorageInspector, PackStorageOptions,
+  RemoteStorage, RemoteStorageOptions, Result,
+};
+
+/// Pack Options
+///
+/// The sizing, expiry and generation policy of pack files.
+#[derive(Debug, Clone, Hash)]
+pub struct StoragePackOptions {
+  /// Count of buckets in each scope, items are hashed into buckets.
+  pub bucket_size: usize,
+  /// Max size of a pack file in bytes.
+  pub pack_size: usize,
+  /// Expiration of the cache in milliseconds.
+  pub expire: u64,
+  /// Packs written within this count of generations are considered fresh,
+  /// fresh packs are merged or split during saving.
+  pub fresh_generation: Option<usize>,
+  /// Contents of packs which are not used for this count of generations are
+  /// released from memory.
+  pub release_generation: Option<usize>,
+  /// Pick bucket size and pack size of each scope from its item count
+  /// stored in scope meta, `bucket_size` and `pack_size` are only used for new scopes.
+  pub adaptive: bool,
+}
+
+impl Default for StoragePackOptions {
+  fn default() -> Self {
+    Self {
+      bucket_size: 20,
+      pack_size: 500 * 1024,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+      adaptive: false,
+    }
+  }
+}
 
 /// Storage Options
 ///
 /// This enum contains all of supported storage options.
 /// Since MemoryStorage is only used in unit test, there is no need to add it here.
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone)]
 pub enum StorageOptions {
-  FileSystem { directory: PathBuf },
+  FileSystem {
+    directory: PathBuf,
+    pack: StoragePackOptions,
+  },
+  /// Share packs through a content-addressed remote store,
+  /// the `directory` is used as the local copy.
+  Remote {
+    directory: PathBuf,
+    pack: StoragePackOptions,
+    url: String,
+    headers: Vec<(String, String)>,
+    read_only: bool,
+  },
+}
+
+impl Hash for StorageOptions {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    std::mem::discriminant(self).hash(state);
+    match self {
+      StorageOptions::FileSystem { directory, pack } => {
+        directory.hash(state);
+        pack.hash(state);
+      }
+      // headers and read_only are excluded, they usually differ between
+      // the producer and consumers of the same remote cache
+      StorageOptions::Remote {
+        directory,
+        pack,
+        url,
+        ..
+      } => {
+        directory.hash(state);
+        pack.hash(state);
+        url.hash(state);
+      }
+    }
+  }
+}
+
+fn create_pack_options(
+  directory: PathBuf,
+  pack: StoragePackOptions,
+  version: String,
+  fs: Arc<dyn IntermediateFileSystem>,
+) -> PackStorageOptions {
+  PackStorageOptions {
+    temp_root: directory.join(".temp"),
+    root: directory,
+    clean: true,
+    bucket_size: pack.bucket_size,
+    pack_size: pack.pack_size,
+    expire: pack.expire,
+    fs: Arc::new(BridgeFileSystem(fs)),
+    fresh_generation: pack.fresh_generation,
+    release_generation: pack.release_generation,
+    version,
+  }
 }
 
 pub fn create_storage(
@@ -23,20 +122,47 @@ pub fn create_storage(
   fs: Arc<dyn IntermediateFileSystem>,
 ) -> Arc<dyn Storage> {
   match options {
-    StorageOptions::FileSystem { directory } => {
-      let option = PackStorageOptions {
-        temp_root: directory.join(".temp"),
-        root: directory,
-        clean: true,
-        bucket_size: 20,
-        pack_size: 500 * 1024,
-        expire: 7 * 24 * 60 * 60 * 1000,
-        fs: Arc::new(BridgeFileSystem(fs)),
-        fresh_generation: Some(1),
-        release_generation: Some(2),
-        version,
-      };
-      Arc::new(PackStorage::new(option))
+    StorageOptions::FileSystem { directory, pack } => {
+      let adaptive = pack.adaptive;
+      let options = create_pack_options(directory, pack, version, fs);
+      if adaptive {
+        Arc::new(PackStorage::new_adaptive(options))
+      } else {
+        Arc::new(PackStorage::new(options))
+      }
     }
+    StorageOptions::Remote {
+      directory,
+      pack,
+      url,
+      headers,
+      read_only,
+    } => Arc::new(RemoteStorage::new(RemoteStorageOptions {
+      adaptive: pack.adaptive,
+      pack: create_pack_options(directory, pack, version, fs),
+      client: Arc::new(HttpRemoteClient::new(url, headers)),
+      read_only,
+    })),
   }
 }
+
+/// Find the version saved by the last compilation, which is not `version`.
+///
+/// Only used by the invalidation report to explain a version change.
+pub async fn find_previous_version(
+  options: &StorageOptions,
+  version: &str,
+  fs: Arc<dyn IntermediateFileSystem>,
+) -> Result<Option<String>> {
+  let directory = match options {
+    StorageOptions::FileSystem { directory, .. } | StorageOptions::Remote { directory, .. } => {
+      directory
+    }
+  };
+  let inspector = PackStorageInspector::new(
+    directory.clone().assert_utf8(),
+    directory.join(".temp").assert_utf8(),
+    Arc::new(BridgeFileSystem(fs)),
+  );
+  inspector.latest_version(version).await
+}
@@ -1,5 +1,6 @@
 use futures::Future;
 use indexmap::IndexMap;
+use itertools::Itertools;
 use rspack_collections::{IdentifierIndexMap, IdentifierMap};
 use rspack_error::Result;
 use rspack_util::tracing_preset::TRACING_BENCH_TARGET;
@@ -8,8 +9,11 @@ use tracing::instrument;
 
 use crate::{
   ChunkByUkey, ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, Compilation, Logger,
-  ModuleIdentifier,
-  build_chunk_graph::code_splitter::{CodeSplitter, DependenciesBlockIdentifier},
+  ModuleIdentifier, RuntimeSpec,
+  build_chunk_graph::{
+    code_splitter::{CodeSplitter, DependenciesBlockIdentifier},
+    snapshot::{ChunkGraphSnapshot, fingerprint},
+  },
   incremental::{IncrementalPasses, Mutation},
 };
 
@@ -172,14 +176,30 @@ where
   T: Fn(&'a mut Compilation) -> F,
   F: Future<Output = Result<&'a mut Compilation>>,
 {
+  let diagnostics_len = compilation.diagnostics().len();
+  let restored = restore_chunk_graph(compilation)?;
+  let computed = match restored {
+    RestoreResult::Restored => return Ok(()),
+    RestoreResult::Mismatched(computed) => Some(computed),
+    RestoreResult::Skipped => None,
+  };
+
   if !compilation.incremental.enabled() {
-    task(compilation).await?;
+    let compilation = task(compilation).await?;
+    capture_chunk_graph(compilation, diagnostics_len, computed)?;
     return Ok(());
   }
 
+  // the code splitter seeded from persistent cache has no chunk group to update incrementally
   let incremental_code_splitting = compilation
     .incremental
-    .passes_enabled(IncrementalPasses::BUILD_CHUNK_GRAPH);
+    .passes_enabled(IncrementalPasses::BUILD_CHUNK_GRAPH)
+    && !compilation
+      .build_chunk_graph_artifact
+      .code_splitting_cache
+      .code_splitter
+      .chunk_group_infos
+      .is_empty();
   let no_change = compilation
     .build_chunk_graph_artifact
     .code_splitting_cache
@@ -214,6 +234,12 @@ where
   }
 
   let compilation = task(compilation).await?;
+  fill_code_splitting_cache(compilation);
+  capture_chunk_graph(compilation, diagnostics_len, computed)
+}
+
+/// Copy the chunk graph of the compilation to the code splitting cache, for the next rebuild.
+fn fill_code_splitting_cache(compilation: &mut Compilation) {
   let cache = &mut compilation.build_chunk_graph_artifact.code_splitting_cache;
   rayon::scope(|s| {
     s.spawn(|_| cache.chunk_by_ukey = compilation.chunk_by_ukey.clone());
@@ -236,6 +262,91 @@ where
   }
   let cache = &mut compilation.build_chunk_graph_artifact.code_splitting_cache;
   cache.module_idx = map;
+}
+
+/// The runtimes used by code splitting and the fingerprint of the module graph in them.
+type ComputedFingerprint = (Vec<Option<RuntimeSpec>>, String);
+
+enum RestoreResult {
+  Restored,
+  /// The fingerprint computed for the snapshot, it's reused by capturing if the runtimes are
+  /// unchanged.
+  Mismatched(ComputedFingerprint),
+  Skipped,
+}
+
+// restore the chunk graph from persistent cache in a cold start
+// if the inputs of code splitting are unchanged
+fn restore_chunk_graph(compilation: &mut Compilation) -> Result<RestoreResult> {
+  if compilation.is_rebuild {
+    return Ok(RestoreResult::Skipped);
+  }
+  let Some(snapshot) = compilation.chunk_graph_cache_artifact.snapshot() else {
+    return Ok(RestoreResult::Skipped);
+  };
+  let logger = compilation.get_logger("rspack.Compilation.codeSplittingCache");
+  let runtimes = snapshot.runtimes().to_vec();
+  let (fingerprint, splitter) = fingerprint(compilation, &runtimes)?;
+  if fingerprint != snapshot.fingerprint() {
+    logger.log("module graph change detected, rebuilding chunk graph");
+    return Ok(RestoreResult::Mismatched((runtimes, fingerprint)));
+  }
+
+  let artifact = std::mem::take(&mut compilation.chunk_graph_cache_artifact);
+  artifact
+    .snapshot()
+    .expect("should have snapshot")
+    .restore(compilation);
+  compilation.chunk_graph_cache_artifact = artifact;
+
+  // seed the cache so the next rebuild can skip code splitting as well, the splitter only has
+  // the block modules which are used to detect the changed outgoings
+  fill_code_splitting_cache(compilation);
+  compilation
+    .build_chunk_graph_artifact
+    .code_splitting_cache
+    .code_splitter = splitter;
+  logger.log("chunk graph restored from persistent cache");
+  Ok(RestoreResult::Restored)
+}
+
+// capture the chunk graph for persistent cache in the initial build, the chunk graph with
+// diagnostics is not captured since the diagnostics would be lost after restoring
+fn capture_chunk_graph(
+  compilation: &mut Compilation,
+  diagnostics_len: usize,
+  computed: Option<ComputedFingerprint>,
+) -> Result<()> {
+  if !compilation.chunk_graph_cache_artifact.is_enabled() || compilation.is_rebuild {
+    return Ok(());
+  }
+  if compilation.diagnostics().len() != diagnostics_len {
+    compilation.chunk_graph_cache_artifact.set_snapshot(None);
+    return Ok(());
+  }
+
+  let runtimes = compilation
+    .build_chunk_graph_artifact
+    .code_splitting_cache
+    .code_splitter
+    .block_modules_runtime_map
+    .keys()
+    .map(|runtime| runtime.as_deref().cloned())
+    .sorted_unstable_by(|a, b| {
+      a.as_ref()
+        .map(|runtime| runtime.as_str())
+        .cmp(&b.as_ref().map(|runtime| runtime.as_str()))
+    })
+    .collect::<Vec<_>>();
+  let fingerprint = match computed {
+    // the module graph is unchanged since the fingerprint is computed before code splitting
+    Some((computed_runtimes, fingerprint)) if computed_runtimes == runtimes => fingerprint,
+    _ => fingerprint(compilation, &runtimes)?.0,
+  };
+  let snapshot = ChunkGraphSnapshot::capture(compilation, fingerprint, runtimes);
+  compilation
+    .chunk_graph_cache_artifact
+    .set_snapshot(Some(snapshot));
   Ok(())
 }
 
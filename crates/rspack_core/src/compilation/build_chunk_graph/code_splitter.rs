@@ -1,6 +1,6 @@
 use std::{
   collections::{HashSet as RawHashSet, VecDeque},
-  hash::{BuildHasherDefault, Hash},
+  hash::{BuildHasherDefault, Hash, Hasher},
   sync::{Arc, atomic::AtomicU32},
 };
 
@@ -2113,6 +2113,56 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
 
     Ok(())
   }
+
+  /// Hash the modules of each block of a module in the given runtime, which are the inputs
+  /// of code splitting. Should be called after `prepare`.
+  ///
+  /// The block modules are kept in `block_modules_runtime_map` like code splitting does, so
+  /// the next compilation can check whether the outgoings of a module are changed.
+  ///
+  /// Dependency ids are not hashed since they are allocated per process.
+  pub(crate) fn hash_block_modules<H: Hasher>(
+    &mut self,
+    module: ModuleIdentifier,
+    runtime: Option<Arc<RuntimeSpec>>,
+    compilation: &Compilation,
+    hasher: &mut H,
+  ) {
+    let module_graph = compilation.get_module_graph();
+    let map = self
+      .block_modules_runtime_map
+      .entry(runtime.clone())
+      .or_default();
+    extract_block_modules(
+      module,
+      runtime,
+      compilation,
+      &self.prepared_blocks_map,
+      &self.prepared_connection_map,
+      map,
+    );
+
+    let root = DependenciesBlockIdentifier::Module(module);
+    let blocks = self
+      .prepared_blocks_map
+      .get(&root)
+      .expect("should have blocks");
+    for block in std::iter::once(root).chain(blocks.iter().map(|block| (*block).into())) {
+      match block {
+        DependenciesBlockIdentifier::Module(module) => module.as_str().hash(hasher),
+        DependenciesBlockIdentifier::AsyncDependenciesBlock(block_id) => {
+          block_id.as_str().hash(hasher);
+          let block = module_graph.block_by_id_expect(&block_id);
+          block.get_group_options().hash(hasher);
+          block.request().hash(hasher);
+        }
+      }
+      for (module, state, _) in map.get(&block).expect("should have block modules").iter() {
+        module.as_str().hash(hasher);
+        state.hash(hasher);
+      }
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -7,6 +7,7 @@ use crate::{Compilation, incremental::IncrementalPasses};
 pub(crate) mod artifact;
 pub(crate) mod code_splitter;
 pub(crate) mod incremental;
+pub mod snapshot;
 
 #[instrument("Compilation:build_chunk_graph", skip_all)]
 pub fn build_chunk_graph(compilation: &mut Compilation) -> rspack_error::Result<()> {
@@ -45,6 +46,20 @@ pub fn build_chunk_graph(compilation: &mut Compilation) -> rspack_error::Result<
   // remove empty chunk groups
   splitter.remove_orphan(compilation)?;
 
+  let chunk_groups_len = compilation.chunk_group_by_ukey.len();
+  let recomputed = if enable_incremental {
+    // chunk groups recovered from cache are not walked again
+    (splitter.stat_chunk_group_created as usize).saturating_sub(splitter.stat_use_cache as usize)
+  } else {
+    chunk_groups_len
+  };
+  compilation.incremental.record_pass(
+    IncrementalPasses::BUILD_CHUNK_GRAPH,
+    enable_incremental,
+    recomputed.min(chunk_groups_len),
+    chunk_groups_len,
+  );
+
   // make sure all module (weak dependency particularly) has a cgm
   for module_identifier in all_modules {
     compilation.chunk_graph.add_module(module_identifier)
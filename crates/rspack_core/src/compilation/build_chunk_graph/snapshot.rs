@@ -0,0 +1,533 @@
+use std::{hash::Hash, sync::Arc};
+
+use indexmap::IndexMap;
+use itertools::Itertools;
+use rspack_cacheable::cacheable;
+use rspack_collections::{DatabaseItem, IdentifierMap, UkeyMap};
+use rspack_error::Result;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+
+use super::code_splitter::CodeSplitter;
+use crate::{
+  AsyncDependenciesBlockIdentifier, Chunk, ChunkGroup, ChunkGroupKind, ChunkGroupOptions,
+  ChunkGroupUkey, ChunkKind, ChunkUkey, Compilation, DependencyLocation, EntryData, EntryOptions,
+  Filename, ModuleIdentifier, RuntimeSpec,
+};
+
+#[cacheable]
+#[derive(Debug)]
+struct ChunkSnapshot {
+  name: Option<String>,
+  id_name_hints: Vec<String>,
+  filename_template: Option<Filename>,
+  prevent_integration: bool,
+  runtime: RuntimeSpec,
+  chunk_reason: Option<String>,
+  groups: Vec<usize>,
+  modules: Vec<ModuleIdentifier>,
+  entry_modules: Vec<(ModuleIdentifier, usize)>,
+}
+
+#[cacheable]
+#[derive(Debug)]
+enum ChunkGroupKindSnapshot {
+  Entrypoint {
+    initial: bool,
+    options: EntryOptions,
+  },
+  Normal {
+    options: ChunkGroupOptions,
+  },
+}
+
+#[cacheable]
+#[derive(Debug)]
+struct OriginSnapshot {
+  module: Option<ModuleIdentifier>,
+  loc: Option<DependencyLocation>,
+  request: Option<String>,
+}
+
+#[cacheable]
+#[derive(Debug)]
+struct ChunkGroupSnapshot {
+  kind: ChunkGroupKindSnapshot,
+  chunks: Vec<usize>,
+  index: Option<u32>,
+  parents: Vec<usize>,
+  children: Vec<usize>,
+  async_entrypoints: Vec<usize>,
+  origins: Vec<OriginSnapshot>,
+  module_pre_order_indices: Vec<(ModuleIdentifier, usize)>,
+  module_post_order_indices: Vec<(ModuleIdentifier, usize)>,
+  next_pre_order_index: usize,
+  next_post_order_index: usize,
+  runtime_chunk: Option<usize>,
+  entrypoint_chunk: Option<usize>,
+  is_over_size_limit: Option<bool>,
+}
+
+#[cacheable]
+#[derive(Debug)]
+struct ModuleSnapshot {
+  module: ModuleIdentifier,
+  pre_order_index: Option<u32>,
+  post_order_index: Option<u32>,
+  depth: Option<usize>,
+}
+
+/// A serializable snapshot of the chunk graph right after code splitting.
+///
+/// Chunk and chunk group ukeys are allocated per process, so they are replaced with the
+/// index of the chunk and chunk group in creation order. The snapshot is only valid for the
+/// module graph with the same fingerprint, see [`fingerprint`].
+#[cacheable]
+#[derive(Debug)]
+pub struct ChunkGraphSnapshot {
+  fingerprint: String,
+  /// the runtimes used by code splitting, which are needed to compute the fingerprint
+  runtimes: Vec<Option<RuntimeSpec>>,
+  chunks: Vec<ChunkSnapshot>,
+  chunk_groups: Vec<ChunkGroupSnapshot>,
+  entrypoints: Vec<(String, usize)>,
+  async_entrypoints: Vec<usize>,
+  named_chunk_groups: Vec<(String, usize)>,
+  named_chunks: Vec<(String, usize)>,
+  block_to_chunk_group: Vec<(AsyncDependenciesBlockIdentifier, usize)>,
+  modules: Vec<ModuleSnapshot>,
+}
+
+/// Hash the inputs of code splitting, which are the entries, the modules and the modules of
+/// each block in the given runtimes.
+///
+/// Returns the code splitter with the block modules of the runtimes as well.
+pub(crate) fn fingerprint(
+  compilation: &Compilation,
+  runtimes: &[Option<RuntimeSpec>],
+) -> Result<(String, CodeSplitter)> {
+  let module_graph = compilation.get_module_graph();
+  let mut hasher = RspackHash::new(&HashFunction::SHA256);
+
+  let hash_entry = |entry: &EntryData, hasher: &mut RspackHash| {
+    entry.options.hash(hasher);
+    for dep in entry.all_dependencies() {
+      module_graph
+        .module_identifier_by_dependency_id(dep)
+        .map(|module| module.as_str())
+        .hash(hasher);
+    }
+  };
+  for (name, entry) in &compilation.entries {
+    name.hash(&mut hasher);
+    hash_entry(entry, &mut hasher);
+  }
+  hash_entry(&compilation.global_entry, &mut hasher);
+
+  let all_modules = module_graph
+    .modules()
+    .keys()
+    .copied()
+    .sorted_unstable_by(|a, b| a.as_str().cmp(b.as_str()))
+    .collect::<Vec<_>>();
+  for module in &all_modules {
+    module.as_str().hash(&mut hasher);
+  }
+
+  let mut splitter = CodeSplitter::default();
+  splitter.prepare(&all_modules, compilation)?;
+  for runtime in runtimes {
+    runtime.hash(&mut hasher);
+    let runtime = runtime.clone().map(Arc::new);
+    for module in &all_modules {
+      splitter.hash_block_modules(*module, runtime.clone(), compilation, &mut hasher);
+    }
+  }
+
+  Ok((
+    hasher.digest(&HashDigest::Hex).encoded().to_string(),
+    splitter,
+  ))
+}
+
+impl ChunkGraphSnapshot {
+  pub fn fingerprint(&self) -> &str {
+    &self.fingerprint
+  }
+
+  pub(crate) fn runtimes(&self) -> &[Option<RuntimeSpec>] {
+    &self.runtimes
+  }
+
+  /// Create a snapshot with an entry chunk for each entry, only used in tests.
+  #[cfg(test)]
+  pub(crate) fn from_entries(fingerprint: &str, entries: &[&str]) -> Self {
+    let runtimes = entries
+      .iter()
+      .map(|entry| Some(RuntimeSpec::from_entry(entry, None)))
+      .collect::<Vec<_>>();
+    Self {
+      fingerprint: fingerprint.to_string(),
+      chunks: entries
+        .iter()
+        .zip(&runtimes)
+        .enumerate()
+        .map(|(index, (entry, runtime))| ChunkSnapshot {
+          name: Some(entry.to_string()),
+          id_name_hints: vec![],
+          filename_template: None,
+          prevent_integration: false,
+          runtime: runtime.clone().expect("should have runtime"),
+          chunk_reason: None,
+          groups: vec![index],
+          modules: vec![ModuleIdentifier::from(format!("./{entry}.js"))],
+          entry_modules: vec![(ModuleIdentifier::from(format!("./{entry}.js")), index)],
+        })
+        .collect(),
+      chunk_groups: entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| ChunkGroupSnapshot {
+          kind: ChunkGroupKindSnapshot::Entrypoint {
+            initial: true,
+            options: EntryOptions {
+              name: Some(entry.to_string()),
+              ..Default::default()
+            },
+          },
+          chunks: vec![index],
+          index: Some(index as u32),
+          parents: vec![],
+          children: vec![],
+          async_entrypoints: vec![],
+          origins: vec![OriginSnapshot {
+            module: None,
+            loc: None,
+            request: Some(format!("./{entry}.js")),
+          }],
+          module_pre_order_indices: vec![(ModuleIdentifier::from(format!("./{entry}.js")), 0)],
+          module_post_order_indices: vec![(ModuleIdentifier::from(format!("./{entry}.js")), 0)],
+          next_pre_order_index: 1,
+          next_post_order_index: 1,
+          runtime_chunk: Some(index),
+          entrypoint_chunk: Some(index),
+          is_over_size_limit: None,
+        })
+        .collect(),
+      entrypoints: entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| (entry.to_string(), index))
+        .collect(),
+      async_entrypoints: vec![],
+      named_chunk_groups: entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| (entry.to_string(), index))
+        .collect(),
+      named_chunks: entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| (entry.to_string(), index))
+        .collect(),
+      block_to_chunk_group: vec![],
+      modules: vec![],
+      runtimes,
+    }
+  }
+
+  #[cfg(test)]
+  pub(crate) fn entrypoints(&self) -> impl Iterator<Item = &str> {
+    self.entrypoints.iter().map(|(name, _)| name.as_str())
+  }
+
+  /// Capture the chunk graph of the compilation, should be called right after code splitting.
+  pub(crate) fn capture(
+    compilation: &Compilation,
+    fingerprint: String,
+    runtimes: Vec<Option<RuntimeSpec>>,
+  ) -> Self {
+    let chunk_ukeys = compilation
+      .chunk_by_ukey
+      .keys()
+      .copied()
+      .sorted_unstable()
+      .collect::<Vec<_>>();
+    let chunk_index: UkeyMap<ChunkUkey, usize> = chunk_ukeys
+      .iter()
+      .enumerate()
+      .map(|(index, ukey)| (*ukey, index))
+      .collect();
+    let group_ukeys = compilation
+      .chunk_group_by_ukey
+      .keys()
+      .copied()
+      .sorted_unstable()
+      .collect::<Vec<_>>();
+    let group_index: UkeyMap<ChunkGroupUkey, usize> = group_ukeys
+      .iter()
+      .enumerate()
+      .map(|(index, ukey)| (*ukey, index))
+      .collect();
+    let sorted_indices =
+      |ukeys: &mut dyn Iterator<Item = usize>| -> Vec<usize> { ukeys.sorted_unstable().collect() };
+
+    let chunks = chunk_ukeys
+      .iter()
+      .map(|ukey| {
+        let chunk = compilation.chunk_by_ukey.expect_get(ukey);
+        ChunkSnapshot {
+          name: chunk.name().map(ToString::to_string),
+          id_name_hints: chunk.id_name_hints().iter().cloned().sorted().collect(),
+          filename_template: chunk.filename_template().cloned(),
+          prevent_integration: chunk.prevent_integration(),
+          runtime: chunk.runtime().clone(),
+          chunk_reason: chunk.chunk_reason().map(ToString::to_string),
+          groups: sorted_indices(&mut chunk.groups().iter().map(|g| group_index[g])),
+          modules: compilation
+            .chunk_graph
+            .get_chunk_modules_identifier(ukey)
+            .iter()
+            .copied()
+            .sorted_unstable_by(|a, b| a.as_str().cmp(b.as_str()))
+            .collect(),
+          entry_modules: compilation
+            .chunk_graph
+            .get_chunk_entry_modules_with_chunk_group_iterable(ukey)
+            .iter()
+            .map(|(module, group)| (*module, group_index[group]))
+            .collect(),
+        }
+      })
+      .collect();
+
+    let sorted_module_indices = |indices: &IdentifierMap<usize>| -> Vec<(ModuleIdentifier, usize)> {
+      indices
+        .iter()
+        .map(|(module, index)| (*module, *index))
+        .sorted_unstable_by_key(|(_, index)| *index)
+        .collect()
+    };
+    let chunk_groups = group_ukeys
+      .iter()
+      .map(|ukey| {
+        let group = compilation.chunk_group_by_ukey.expect_get(ukey);
+        ChunkGroupSnapshot {
+          kind: match &group.kind {
+            ChunkGroupKind::Entrypoint { initial, options } => ChunkGroupKindSnapshot::Entrypoint {
+              initial: *initial,
+              options: options.as_ref().clone(),
+            },
+            ChunkGroupKind::Normal { options } => ChunkGroupKindSnapshot::Normal {
+              options: options.clone(),
+            },
+          },
+          chunks: group.chunks.iter().map(|c| chunk_index[c]).collect(),
+          index: group.index,
+          parents: sorted_indices(&mut group.parents.iter().map(|g| group_index[g])),
+          children: group.children.iter().map(|g| group_index[g]).collect(),
+          async_entrypoints: sorted_indices(
+            &mut group.async_entrypoints_iterable().map(|g| group_index[g]),
+          ),
+          origins: group
+            .origins()
+            .iter()
+            .map(|origin| OriginSnapshot {
+              module: origin.module,
+              loc: origin.loc.clone(),
+              request: origin.request.clone(),
+            })
+            .collect(),
+          module_pre_order_indices: sorted_module_indices(&group.module_pre_order_indices),
+          module_post_order_indices: sorted_module_indices(&group.module_post_order_indices),
+          next_pre_order_index: group.next_pre_order_index,
+          next_post_order_index: group.next_post_order_index,
+          runtime_chunk: group.runtime_chunk.map(|c| chunk_index[&c]),
+          entrypoint_chunk: group.entrypoint_chunk.map(|c| chunk_index[&c]),
+          is_over_size_limit: group.is_over_size_limit,
+        }
+      })
+      .collect();
+
+    let module_graph = compilation.get_module_graph();
+    let modules = module_graph
+      .module_graph_modules()
+      .into_iter()
+      .filter(|(_, mgm)| {
+        mgm.pre_order_index.is_some() || mgm.post_order_index.is_some() || mgm.depth.is_some()
+      })
+      .map(|(module, mgm)| ModuleSnapshot {
+        module,
+        pre_order_index: mgm.pre_order_index,
+        post_order_index: mgm.post_order_index,
+        depth: mgm.depth,
+      })
+      .collect();
+
+    Self {
+      fingerprint,
+      runtimes,
+      chunks,
+      chunk_groups,
+      entrypoints: compilation
+        .entrypoints
+        .iter()
+        .map(|(name, group)| (name.clone(), group_index[group]))
+        .collect(),
+      async_entrypoints: compilation
+        .async_entrypoints
+        .iter()
+        .map(|group| group_index[group])
+        .collect(),
+      named_chunk_groups: compilation
+        .named_chunk_groups
+        .iter()
+        .map(|(name, group)| (name.clone(), group_index[group]))
+        .sorted_unstable()
+        .collect(),
+      named_chunks: compilation
+        .named_chunks
+        .iter()
+        .map(|(name, chunk)| (name.clone(), chunk_index[chunk]))
+        .sorted_unstable()
+        .collect(),
+      block_to_chunk_group: compilation
+        .chunk_graph
+        .block_to_chunk_group_ukey
+        .iter()
+        .map(|(block, group)| (*block, group_index[group]))
+        .sorted_unstable_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()))
+        .collect(),
+      modules,
+    }
+  }
+
+  /// Restore the chunk graph to the compilation with new ukeys.
+  ///
+  /// The chunks and chunk groups are created in the captured order, so the order of the new
+  /// ukeys is the same as the previous compilation.
+  pub(crate) fn restore(&self, compilation: &mut Compilation) {
+    let chunk_ukeys = self
+      .chunks
+      .iter()
+      .map(|snapshot| {
+        let mut chunk = Chunk::new(snapshot.name.clone(), ChunkKind::Normal);
+        for hint in &snapshot.id_name_hints {
+          chunk.add_id_name_hints(hint.clone());
+        }
+        chunk.set_filename_template(snapshot.filename_template.clone());
+        chunk.set_prevent_integration(snapshot.prevent_integration);
+        chunk.set_runtime(snapshot.runtime.clone());
+        *chunk.chunk_reason_mut() = snapshot.chunk_reason.clone();
+        let ukey = compilation.chunk_by_ukey.add(chunk).ukey();
+        compilation.chunk_graph.add_chunk(ukey);
+        ukey
+      })
+      .collect::<Vec<_>>();
+
+    let group_ukeys = self
+      .chunk_groups
+      .iter()
+      .map(|snapshot| {
+        let kind = match &snapshot.kind {
+          ChunkGroupKindSnapshot::Entrypoint { initial, options } => {
+            ChunkGroupKind::new_entrypoint(*initial, Box::new(options.clone()))
+          }
+          ChunkGroupKindSnapshot::Normal { options } => ChunkGroupKind::Normal {
+            options: options.clone(),
+          },
+        };
+        let group = ChunkGroup::new(kind);
+        let ukey = group.ukey;
+        compilation.chunk_group_by_ukey.add(group);
+        ukey
+      })
+      .collect::<Vec<_>>();
+
+    for (snapshot, ukey) in self.chunk_groups.iter().zip(&group_ukeys) {
+      let group = compilation.chunk_group_by_ukey.expect_get_mut(ukey);
+      group.chunks = snapshot.chunks.iter().map(|c| chunk_ukeys[*c]).collect();
+      group.index = snapshot.index;
+      group.parents = snapshot.parents.iter().map(|g| group_ukeys[*g]).collect();
+      group.children = snapshot.children.iter().map(|g| group_ukeys[*g]).collect();
+      for async_entrypoint in &snapshot.async_entrypoints {
+        group.add_async_entrypoint(group_ukeys[*async_entrypoint]);
+      }
+      for origin in &snapshot.origins {
+        group.add_origin(origin.module, origin.loc.clone(), origin.request.clone());
+      }
+      group.module_pre_order_indices = snapshot.module_pre_order_indices.iter().copied().collect();
+      group.module_post_order_indices =
+        snapshot.module_post_order_indices.iter().copied().collect();
+      group.next_pre_order_index = snapshot.next_pre_order_index;
+      group.next_post_order_index = snapshot.next_post_order_index;
+      group.runtime_chunk = snapshot.runtime_chunk.map(|c| chunk_ukeys[c]);
+      group.entrypoint_chunk = snapshot.entrypoint_chunk.map(|c| chunk_ukeys[c]);
+      group.is_over_size_limit = snapshot.is_over_size_limit;
+    }
+
+    for (snapshot, ukey) in self.chunks.iter().zip(&chunk_ukeys) {
+      let chunk = compilation.chunk_by_ukey.expect_get_mut(ukey);
+      for group in &snapshot.groups {
+        chunk.add_group(group_ukeys[*group]);
+      }
+      for module in &snapshot.modules {
+        compilation
+          .chunk_graph
+          .connect_chunk_and_module(*ukey, *module);
+      }
+      for (module, group) in &snapshot.entry_modules {
+        compilation
+          .chunk_graph
+          .connect_chunk_and_entry_module(*ukey, *module, group_ukeys[*group]);
+      }
+    }
+
+    for (block, group) in &self.block_to_chunk_group {
+      compilation
+        .chunk_graph
+        .connect_block_and_chunk_group(*block, group_ukeys[*group]);
+    }
+
+    compilation.entrypoints = self
+      .entrypoints
+      .iter()
+      .map(|(name, group)| (name.clone(), group_ukeys[*group]))
+      .collect::<IndexMap<_, _>>();
+    compilation.async_entrypoints = self
+      .async_entrypoints
+      .iter()
+      .map(|group| group_ukeys[*group])
+      .collect();
+    compilation.named_chunk_groups = self
+      .named_chunk_groups
+      .iter()
+      .map(|(name, group)| (name.clone(), group_ukeys[*group]))
+      .collect();
+    compilation.named_chunks = self
+      .named_chunks
+      .iter()
+      .map(|(name, chunk)| (name.clone(), chunk_ukeys[*chunk]))
+      .collect();
+
+    // make sure all module (weak dependency particularly) has a cgm
+    let all_modules = compilation
+      .get_module_graph()
+      .modules()
+      .keys()
+      .copied()
+      .collect::<Vec<_>>();
+    for module in all_modules {
+      compilation.chunk_graph.add_module(module);
+    }
+
+    let mut module_graph = compilation.get_seal_module_graph_mut();
+    for snapshot in &self.modules {
+      let Some(mgm) = module_graph.module_graph_module_by_identifier_mut(&snapshot.module) else {
+        continue;
+      };
+      mgm.pre_order_index = snapshot.pre_order_index;
+      mgm.post_order_index = snapshot.post_order_index;
+      mgm.depth = snapshot.depth;
+    }
+  }
+}
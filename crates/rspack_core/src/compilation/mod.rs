@@ -42,18 +42,19 @@ use ustr::Ustr;
 use crate::{
   AsyncModulesArtifact, BindingCell, BoxDependency, BoxModule, CacheCount, CacheOptions,
   CgcRuntimeRequirementsArtifact, CgmHashArtifact, CgmRuntimeRequirementsArtifact, Chunk,
-  ChunkByUkey, ChunkContentHash, ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey, ChunkHashesArtifact,
-  ChunkKind, ChunkNamedIdArtifact, ChunkRenderArtifact, ChunkRenderCacheArtifact,
-  ChunkRenderResult, ChunkUkey, CodeGenerationJob, CodeGenerationResult, CodeGenerationResults,
-  CompilationLogger, CompilationLogging, CompilerOptions, ConcatenationScope,
-  DependenciesDiagnosticsArtifact, DependencyCodeGeneration, DependencyTemplate,
-  DependencyTemplateType, DependencyType, DerefOption, Entry, EntryData, EntryOptions,
-  EntryRuntime, Entrypoint, ExecuteModuleId, Filename, ImportPhase, ImportVarMap,
-  ImportedByDeferModulesArtifact, Logger, MemoryGCStorage, ModuleFactory, ModuleGraph,
-  ModuleGraphCacheArtifact, ModuleGraphMut, ModuleGraphPartial, ModuleGraphRef, ModuleIdentifier,
-  ModuleIdsArtifact, ModuleStaticCacheArtifact, PathData, ResolverFactory, RuntimeGlobals,
-  RuntimeKeyMap, RuntimeMode, RuntimeModule, RuntimeSpec, RuntimeSpecMap, RuntimeTemplate,
-  SharedPluginDriver, SideEffectsOptimizeArtifact, SourceType, Stats, ValueCacheVersions,
+  ChunkByUkey, ChunkContentHash, ChunkGraph, ChunkGraphCacheArtifact, ChunkGroupByUkey,
+  ChunkGroupUkey, ChunkHashesArtifact, ChunkKind, ChunkNamedIdArtifact, ChunkRenderArtifact,
+  ChunkRenderCacheArtifact, ChunkRenderResult, ChunkUkey, CodeGenerationCacheArtifact,
+  CodeGenerationJob, CodeGenerationResult, CodeGenerationResults, CompilationLogger,
+  CompilationLogging, CompilerOptions, ConcatenationScope, DependenciesDiagnosticsArtifact,
+  DependencyCodeGeneration, DependencyTemplate, DependencyTemplateType, DependencyType,
+  DerefOption, DeterministicIdsArtifact, Entry, EntryData, EntryOptions, EntryRuntime, Entrypoint,
+  ExecuteModuleId, Filename, ImportPhase, ImportVarMap, ImportedByDeferModulesArtifact, Logger,
+  MemoryGCStorage, MinimizeCacheArtifact, ModuleFactory, ModuleGraph, ModuleGraphCacheArtifact,
+  ModuleGraphMut, ModuleGraphPartial, ModuleGraphRef, ModuleIdentifier, ModuleIdsArtifact,
+  ModuleStaticCacheArtifact, PathData, ResolverFactory, RuntimeGlobals, RuntimeKeyMap, RuntimeMode,
+  RuntimeModule, RuntimeSpec, RuntimeSpecMap, RuntimeTemplate, SharedPluginDriver,
+  SideEffectsOptimizeArtifact, SourceType, Stats, ValueCacheVersions,
   build_chunk_graph::artifact::BuildChunkGraphArtifact,
   compilation::build_module_graph::{
     BuildModuleGraphArtifact, ModuleExecutor, UpdateParam, build_module_graph,
@@ -255,6 +256,8 @@ pub struct Compilation {
   pub module_ids_artifact: ModuleIdsArtifact,
   // artifact for named_chunk_ids
   pub named_chunk_ids_artifact: ChunkNamedIdArtifact,
+  // artifact for deterministic_module_ids and deterministic_chunk_ids
+  pub deterministic_ids_artifact: DeterministicIdsArtifact,
   // artifact for code_generation
   pub code_generation_results: BindingCell<CodeGenerationResults>,
   // artifact for create_module_hashes
@@ -273,6 +276,12 @@ pub struct Compilation {
   pub module_static_cache_artifact: ModuleStaticCacheArtifact,
   // artifact for chunk render cache
   pub chunk_render_cache_artifact: ChunkRenderCacheArtifact,
+  // artifact for code generation results shared with persistent cache
+  pub code_generation_cache_artifact: CodeGenerationCacheArtifact,
+  // artifact for minimized assets shared with persistent cache
+  pub minimize_cache_artifact: MinimizeCacheArtifact,
+  // artifact for chunk graph shared with persistent cache
+  pub chunk_graph_cache_artifact: ChunkGraphCacheArtifact,
   pub imported_by_defer_modules_artifact: ImportedByDeferModulesArtifact,
 
   pub code_generated_modules: IdentifierSet,
@@ -393,6 +402,7 @@ impl Compilation {
       side_effects_optimize_artifact: DerefOption::new(Default::default()),
       module_ids_artifact: Default::default(),
       named_chunk_ids_artifact: Default::default(),
+      deterministic_ids_artifact: Default::default(),
       code_generation_results: Default::default(),
       cgm_hash_artifact: Default::default(),
       cgm_runtime_requirements_artifact: Default::default(),
@@ -408,6 +418,14 @@ impl Compilation {
           CacheOptions::Disabled => 0, // FIXME: this should be removed in future
         },
       )),
+      code_generation_cache_artifact: Default::default(),
+      minimize_cache_artifact: MinimizeCacheArtifact::new(MemoryGCStorage::new(
+        match &options.cache {
+          CacheOptions::Memory { max_generations } => max_generations.unwrap_or(1),
+          CacheOptions::Disabled => 0,
+        },
+      )),
+      chunk_graph_cache_artifact: Default::default(),
       build_time_executed_modules: Default::default(),
       old_cache,
       incremental,
@@ -1163,6 +1181,13 @@ impl Compilation {
           let module = module_graph
             .module_by_identifier(&job.module)
             .expect("should have module");
+          if job.scope.is_none()
+            && let Some(codegen_res) = this
+              .code_generation_cache_artifact
+              .get(&job.module, &job.hash)
+          {
+            return (job.module, job.hash, job.runtimes, (Ok(codegen_res), true));
+          }
           let codegen_res = old_cache
             .code_generate_occasion
             .use_cache(&job, || async {
@@ -1180,7 +1205,7 @@ impl Compilation {
             })
             .await;
 
-          (job.module, job.runtimes, codegen_res)
+          (job.module, job.hash, job.runtimes, codegen_res)
         })
       })
     })
@@ -1190,7 +1215,7 @@ impl Compilation {
       .map(|res| res.to_rspack_result())
       .collect::<Result<Vec<_>>>()?;
 
-    for (module, runtimes, (codegen_res, from_cache)) in results {
+    for (module, hash, runtimes, (codegen_res, from_cache)) in results {
       if let Some(counter) = cache_counter {
         if from_cache {
           counter.hit();
@@ -1199,7 +1224,12 @@ impl Compilation {
         }
       }
       let codegen_res = match codegen_res {
-        Ok(codegen_res) => codegen_res,
+        Ok(codegen_res) => {
+          self
+            .code_generation_cache_artifact
+            .add(&module, &hash, &codegen_res);
+          codegen_res
+        }
         Err(err) => {
           let mut diagnostic = Diagnostic::from(err);
           diagnostic.module_identifier = Some(module);
@@ -1310,8 +1340,20 @@ impl Compilation {
         chunks.len(),
         self.chunk_by_ukey.len()
       ));
+      self.incremental.record_pass(
+        IncrementalPasses::CHUNKS_RENDER,
+        true,
+        chunks.len(),
+        self.chunk_by_ukey.len(),
+      );
       chunks
     } else {
+      self.incremental.record_pass(
+        IncrementalPasses::CHUNKS_RENDER,
+        false,
+        self.chunk_by_ukey.len(),
+        self.chunk_by_ukey.len(),
+      );
       self.chunk_by_ukey.keys().copied().collect()
     };
     let results = rspack_futures::scope::<_, Result<_>>(|token| {
@@ -1518,6 +1560,12 @@ impl Compilation {
       );
       tracing::debug!(target: incremental::TRACING_TARGET, passes = %IncrementalPasses::MAKE, %mutations);
     }
+    self.incremental.record_pass(
+      IncrementalPasses::MAKE,
+      self.incremental.mutations_readable(IncrementalPasses::MAKE),
+      self.build_module_graph_artifact.built_modules().count(),
+      self.get_module_graph().modules().len(),
+    );
 
     let start = logger.time("finish modules");
     // finish_modules means the module graph (modules, connections, dependencies) are
@@ -1576,18 +1624,32 @@ impl Compilation {
             modules.len(),
             self.get_module_graph().modules().len()
           ));
+          self.incremental.record_pass(
+            IncrementalPasses::DEPENDENCIES_DIAGNOSTICS,
+            true,
+            modules.len(),
+            self.get_module_graph().modules().len(),
+          );
           (modules, true)
         } else {
-          (
-            self.get_module_graph().modules().keys().copied().collect(),
-            true,
-          )
+          let modules: IdentifierSet = self.get_module_graph().modules().keys().copied().collect();
+          self.incremental.record_pass(
+            IncrementalPasses::DEPENDENCIES_DIAGNOSTICS,
+            false,
+            modules.len(),
+            modules.len(),
+          );
+          (modules, true)
         }
       } else {
-        (
-          self.get_module_graph().modules().keys().copied().collect(),
+        let modules: IdentifierSet = self.get_module_graph().modules().keys().copied().collect();
+        self.incremental.record_pass(
+          IncrementalPasses::DEPENDENCIES_DIAGNOSTICS,
           false,
-        )
+          modules.len(),
+          modules.len(),
+        );
+        (modules, false)
       }
     };
 
@@ -1833,10 +1895,23 @@ impl Compilation {
         modules.len(),
         mg.modules().len()
       ));
+      self.incremental.record_pass(
+        IncrementalPasses::MODULES_HASHES,
+        true,
+        modules.len(),
+        mg.modules().len(),
+      );
 
       modules
     } else {
-      self.get_module_graph().modules().keys().copied().collect()
+      let modules: IdentifierSet = self.get_module_graph().modules().keys().copied().collect();
+      self.incremental.record_pass(
+        IncrementalPasses::MODULES_HASHES,
+        false,
+        modules.len(),
+        modules.len(),
+      );
+      modules
     };
     self
       .create_module_hashes(create_module_hashes_modules)
@@ -1873,9 +1948,22 @@ impl Compilation {
         modules.len(),
         self.get_module_graph().modules().len()
       ));
+      self.incremental.record_pass(
+        IncrementalPasses::MODULES_CODEGEN,
+        true,
+        modules.len(),
+        self.get_module_graph().modules().len(),
+      );
       modules
     } else {
-      self.get_module_graph().modules().keys().copied().collect()
+      let modules: IdentifierSet = self.get_module_graph().modules().keys().copied().collect();
+      self.incremental.record_pass(
+        IncrementalPasses::MODULES_CODEGEN,
+        false,
+        modules.len(),
+        modules.len(),
+      );
+      modules
     };
     self.code_generation(code_generation_modules).await?;
 
@@ -1915,9 +2003,22 @@ impl Compilation {
         modules.len(),
         self.get_module_graph().modules().len()
       ));
+      self.incremental.record_pass(
+        IncrementalPasses::MODULES_RUNTIME_REQUIREMENTS,
+        true,
+        modules.len(),
+        self.get_module_graph().modules().len(),
+      );
       modules
     } else {
-      self.get_module_graph().modules().keys().copied().collect()
+      let modules: IdentifierSet = self.get_module_graph().modules().keys().copied().collect();
+      self.incremental.record_pass(
+        IncrementalPasses::MODULES_RUNTIME_REQUIREMENTS,
+        false,
+        modules.len(),
+        modules.len(),
+      );
+      modules
     };
     self
       .process_modules_runtime_requirements(
@@ -1956,8 +2057,20 @@ impl Compilation {
         affected_chunks.len(),
         self.chunk_by_ukey.len()
       ));
+      self.incremental.record_pass(
+        IncrementalPasses::CHUNKS_RUNTIME_REQUIREMENTS,
+        true,
+        affected_chunks.len(),
+        self.chunk_by_ukey.len(),
+      );
       affected_chunks
     } else {
+      self.incremental.record_pass(
+        IncrementalPasses::CHUNKS_RUNTIME_REQUIREMENTS,
+        false,
+        self.chunk_by_ukey.len(),
+        self.chunk_by_ukey.len(),
+      );
       self.chunk_by_ukey.keys().copied().collect()
     };
     self
@@ -2356,8 +2469,20 @@ impl Compilation {
         chunks.len(),
         self.chunk_by_ukey.len(),
       ));
+      self.incremental.record_pass(
+        IncrementalPasses::CHUNKS_HASHES,
+        true,
+        chunks.len(),
+        self.chunk_by_ukey.len(),
+      );
       chunks
     } else {
+      self.incremental.record_pass(
+        IncrementalPasses::CHUNKS_HASHES,
+        false,
+        self.chunk_by_ukey.len(),
+        self.chunk_by_ukey.len(),
+      );
       self.chunk_by_ukey.keys().copied().collect()
     };
 
@@ -332,7 +332,9 @@ impl Compiler {
       )
       .await?;
     self.compilation.extend_diagnostics(diagnostics);
+    self.cache.before_seal(&mut self.compilation).await;
     self.compilation.seal(self.plugin_driver.clone()).await?;
+    self.cache.after_seal(&self.compilation).await;
     logger.time_end(start);
 
     // Consume plugin driver diagnostic
@@ -415,6 +417,12 @@ impl Compiler {
     }
 
     let mut new_emitted_asset_versions = HashMap::default();
+    let incremental = self
+      .compilation
+      .incremental
+      .passes_enabled(IncrementalPasses::EMIT_ASSETS)
+      && !self.emitted_asset_versions.is_empty();
+    let mut emitted = 0;
 
     rspack_futures::scope(|token| {
       self
@@ -437,6 +445,7 @@ impl Compiler {
           {
             return;
           }
+          emitted += 1;
 
           // SAFETY: await immediately and trust caller to poll future entirely
           let s = unsafe { token.used((&self, filename, asset, output_path)) };
@@ -448,6 +457,12 @@ impl Compiler {
     })
     .await;
 
+    self.compilation.incremental.record_pass(
+      IncrementalPasses::EMIT_ASSETS,
+      incremental,
+      emitted,
+      self.compilation.assets().len(),
+    );
     self.emitted_asset_versions = new_emitted_asset_versions;
 
     self
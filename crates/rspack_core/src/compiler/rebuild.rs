@@ -130,6 +130,10 @@ impl Compiler {
         new_compilation.named_chunk_ids_artifact =
           std::mem::take(&mut self.compilation.named_chunk_ids_artifact);
       }
+      // the deterministic ids are keyed by stable names, the id plugins decide
+      // whether to reuse them
+      new_compilation.deterministic_ids_artifact =
+        std::mem::take(&mut self.compilation.deterministic_ids_artifact);
       if new_compilation
         .incremental
         .mutations_readable(IncrementalPasses::MODULES_HASHES)
@@ -176,6 +180,11 @@ impl Compiler {
       new_compilation
         .chunk_render_cache_artifact
         .start_next_generation();
+      new_compilation.minimize_cache_artifact =
+        std::mem::take(&mut self.compilation.minimize_cache_artifact);
+      new_compilation
+        .minimize_cache_artifact
+        .start_next_generation();
 
       // FOR BINDING SAFETY:
       // Update `compilation` for each rebuild.
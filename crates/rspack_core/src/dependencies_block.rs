@@ -45,6 +45,12 @@ pub fn dependencies_block_update_hash(
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct AsyncDependenciesBlockIdentifier(Identifier);
 
+impl AsyncDependenciesBlockIdentifier {
+  pub fn as_str(&self) -> &str {
+    self.0.as_str()
+  }
+}
+
 impl From<String> for AsyncDependenciesBlockIdentifier {
   fn from(value: String) -> Self {
     Self(value.into())
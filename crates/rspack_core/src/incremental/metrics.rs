@@ -0,0 +1,85 @@
+use super::IncrementalPasses;
+
+/// The recomputed and reused items of an incremental pass.
+#[derive(Debug, Clone, Copy)]
+pub struct IncrementalPassMetrics {
+  pub pass: IncrementalPasses,
+  /// Whether the pass read the mutations, `false` means the pass fell back to
+  /// full recomputation, e.g. cold build or the pass is disabled.
+  pub incremental: bool,
+  /// The count of recomputed items, the items are modules or chunks depending on the pass.
+  pub recomputed: usize,
+  /// The count of all items.
+  pub total: usize,
+}
+
+impl IncrementalPassMetrics {
+  pub fn reused(&self) -> usize {
+    self.total.saturating_sub(self.recomputed)
+  }
+}
+
+/// The metrics of incremental passes in a compilation.
+#[derive(Debug, Default)]
+pub struct IncrementalMetrics {
+  inner: Vec<IncrementalPassMetrics>,
+}
+
+impl IncrementalMetrics {
+  /// Record the metrics of a pass, the counts are accumulated if a pass is recorded
+  /// more than once, e.g. the pass runs for several items.
+  pub fn add(&mut self, metrics: IncrementalPassMetrics) {
+    if let Some(item) = self.inner.iter_mut().find(|item| item.pass == metrics.pass) {
+      item.incremental &= metrics.incremental;
+      item.recomputed += metrics.recomputed;
+      item.total += metrics.total;
+    } else {
+      self.inner.push(metrics);
+    }
+  }
+
+  /// Get the metrics in the order of passes.
+  pub fn to_vec(&self) -> Vec<IncrementalPassMetrics> {
+    let mut res = self.inner.clone();
+    res.sort_by_key(|item| item.pass.bits());
+    res
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{IncrementalMetrics, IncrementalPassMetrics};
+  use crate::incremental::IncrementalPasses;
+
+  #[test]
+  fn should_accumulate_metrics() {
+    let mut metrics = IncrementalMetrics::default();
+    metrics.add(IncrementalPassMetrics {
+      pass: IncrementalPasses::CHUNKS_HASHES,
+      incremental: true,
+      recomputed: 1,
+      total: 4,
+    });
+    metrics.add(IncrementalPassMetrics {
+      pass: IncrementalPasses::MAKE,
+      incremental: true,
+      recomputed: 2,
+      total: 10,
+    });
+    metrics.add(IncrementalPassMetrics {
+      pass: IncrementalPasses::CHUNKS_HASHES,
+      incremental: false,
+      recomputed: 3,
+      total: 3,
+    });
+
+    let res = metrics.to_vec();
+    assert_eq!(res.len(), 2);
+    assert_eq!(res[0].pass, IncrementalPasses::MAKE);
+    assert_eq!(res[0].reused(), 8);
+    assert_eq!(res[1].pass, IncrementalPasses::CHUNKS_HASHES);
+    assert!(!res[1].incremental);
+    assert_eq!(res[1].recomputed, 4);
+    assert_eq!(res[1].reused(), 3);
+  }
+}
@@ -1,3 +1,4 @@
+mod metrics;
 mod mutations;
 
 use std::{
@@ -9,6 +10,7 @@ use std::{
 };
 
 use bitflags::bitflags;
+pub use metrics::{IncrementalMetrics, IncrementalPassMetrics};
 pub use mutations::{Mutation, Mutations};
 use rspack_error::{Diagnostic, Error};
 
@@ -126,6 +128,7 @@ pub struct Incremental {
   silent: bool,
   passes: AtomicU16,
   state: IncrementalState,
+  metrics: Mutex<IncrementalMetrics>,
 }
 
 impl fmt::Debug for Incremental {
@@ -144,6 +147,7 @@ impl Incremental {
       silent: options.silent,
       passes: AtomicU16::new(options.passes.bits()),
       state: IncrementalState::Cold,
+      metrics: Default::default(),
     }
   }
 
@@ -154,6 +158,7 @@ impl Incremental {
       state: IncrementalState::Hot {
         mutations: Mutex::new(Mutations::default()),
       },
+      metrics: Default::default(),
     }
   }
 
@@ -241,6 +246,50 @@ impl Incremental {
   }
 }
 
+impl Incremental {
+  /// Record how many items are recomputed by a pass, the others are reused from
+  /// the previous compilation.
+  ///
+  /// `incremental` should be `false` if the pass recomputes all items.
+  pub fn record_pass(
+    &self,
+    pass: IncrementalPasses,
+    incremental: bool,
+    recomputed: usize,
+    total: usize,
+  ) {
+    let metrics = IncrementalPassMetrics {
+      pass,
+      incremental,
+      recomputed,
+      total,
+    };
+    tracing::debug!(
+      target: TRACING_TARGET,
+      pass = pass.pass_name(),
+      incremental,
+      recomputed,
+      reused = metrics.reused(),
+      total,
+      "incremental pass metrics"
+    );
+    self
+      .metrics
+      .lock()
+      .expect("Mutex poisoned: failed to acquire lock on incremental metrics")
+      .add(metrics);
+  }
+
+  /// Get the metrics of recorded passes in this compilation.
+  pub fn metrics(&self) -> Vec<IncrementalPassMetrics> {
+    self
+      .metrics
+      .lock()
+      .expect("Mutex poisoned: failed to acquire lock on incremental metrics")
+      .to_vec()
+  }
+}
+
 #[derive(Debug)]
 pub struct NotFriendlyForIncremental {
   pub thing: &'static str,
@@ -664,6 +664,30 @@ impl From<String> for DataUrlEncoding {
   }
 }
 
+#[cacheable]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, MergeFrom)]
+pub enum CssTypeDeclaration {
+  None,
+  /// Write the declaration next to the source, as `{resource}.d.ts`.
+  Write,
+  /// Emit the declaration as an asset.
+  Asset,
+  /// Report an error when the declaration next to the source is missing or outdated.
+  Check,
+}
+
+impl From<String> for CssTypeDeclaration {
+  fn from(value: String) -> Self {
+    match value.as_str() {
+      "false" => Self::None,
+      "write" => Self::Write,
+      "asset" => Self::Asset,
+      "check" => Self::Check,
+      _ => unreachable!("CssTypeDeclaration should be false, write, asset or check"),
+    }
+  }
+}
+
 #[cacheable]
 #[derive(Debug, Clone, MergeFrom)]
 pub struct CssGeneratorOptions {
@@ -678,6 +702,8 @@ pub struct CssAutoGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<LocalIdentName>,
   pub es_module: Option<bool>,
+  pub type_declaration: Option<CssTypeDeclaration>,
+  pub remove_unused_rules: Option<bool>,
 }
 
 impl From<CssGeneratorOptions> for CssAutoGeneratorOptions {
@@ -697,6 +723,8 @@ pub struct CssModuleGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<LocalIdentName>,
   pub es_module: Option<bool>,
+  pub type_declaration: Option<CssTypeDeclaration>,
+  pub remove_unused_rules: Option<bool>,
 }
 
 impl From<CssGeneratorOptions> for CssModuleGeneratorOptions {
@@ -17,7 +17,7 @@ use crate::{
   BoxModule, BoxRuntimeModule, Chunk, ChunkGraph, ChunkGroupOrderKey, ChunkGroupUkey, ChunkUkey,
   Compilation, LogType, ModuleGraph, ModuleGraphCacheArtifact, ModuleIdentifier,
   PrefetchExportsInfoMode, ProvidedExports, RuntimeSpec, SourceType, UsedExports,
-  compilation::build_module_graph::ExecutedRuntimeModule,
+  compilation::build_module_graph::ExecutedRuntimeModule, incremental::IncrementalPassMetrics,
 };
 
 #[derive(Debug, Clone)]
@@ -728,6 +728,10 @@ impl Stats<'_> {
     self.compilation.get_hash()
   }
 
+  pub fn get_incremental_metrics(&self) -> Vec<IncrementalPassMetrics> {
+    self.compilation.incremental.metrics()
+  }
+
   #[allow(clippy::too_many_arguments)]
   fn get_module<'a>(
     &'a self,
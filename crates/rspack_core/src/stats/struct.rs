@@ -26,6 +26,7 @@ pub struct ExtendedStatsOptions {
   pub errors: bool,
   pub hash: bool,
   pub ids: bool,
+  pub incremental: bool,
   pub modules: bool,
   pub module_assets: bool,
   pub nested_modules: bool,
@@ -55,6 +56,7 @@ impl Default for ExtendedStatsOptions {
       depth: false,
       entrypoints: EntrypointsStatsOption::Bool(false),
       ids: false,
+      incremental: false,
       modules: false,
       module_assets: false,
       nested_modules: false,
@@ -49,6 +49,14 @@ where
       CacheData::new(data, self.generation.load(Ordering::Relaxed)),
     );
   }
+  /// all the items alive in the current generation
+  pub(crate) fn entries(&self) -> Vec<(Identifier, Item)> {
+    self
+      .data
+      .iter()
+      .map(|item| (*item.key(), item.value().item.clone()))
+      .collect()
+  }
   /// notify storage that the current generation is over and start a new one
   pub(crate) fn start_next_generation(&self) {
     let generation = self.generation.fetch_add(1, Ordering::Relaxed) + 1;
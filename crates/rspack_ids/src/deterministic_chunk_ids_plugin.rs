@@ -1,6 +1,10 @@
 use rayon::prelude::*;
 use rspack_collections::{DatabaseItem, UkeyMap};
-use rspack_core::{CompilationChunkIds, Plugin, incremental::IncrementalPasses};
+use rspack_core::{
+  ChunkUkey, CompilationChunkIds, Plugin,
+  chunk_graph_chunk::ChunkId,
+  incremental::{IncrementalPasses, Mutation, Mutations},
+};
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
 use rustc_hash::{FxBuildHasher, FxHashMap};
@@ -24,17 +28,18 @@ impl DeterministicChunkIdsPlugin {
 
 #[plugin_hook(CompilationChunkIds for DeterministicChunkIdsPlugin)]
 async fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> rspack_error::Result<()> {
-  if let Some(diagnostic) = compilation.incremental.disable_passes(
-    IncrementalPasses::CHUNK_IDS,
-    "DeterministicChunkIdsPlugin (optimization.chunkIds = \"deterministic\")",
-    "it requires calculating the id of all the chunks, which is a global effect",
-  ) && let Some(diagnostic) = diagnostic
+  // the chunk ukeys are not stable between compilations, so the previous ids
+  // are keyed by full chunk name
+  let mut previous_ids = std::mem::take(&mut compilation.deterministic_ids_artifact.chunk_ids);
+  if !compilation
+    .incremental
+    .passes_enabled(IncrementalPasses::CHUNK_IDS)
   {
-    compilation.push_diagnostic(diagnostic);
+    previous_ids.clear();
   }
+  let incremental = !previous_ids.is_empty();
 
   let mut used_ids = get_used_chunk_ids(compilation);
-  let used_ids_len = used_ids.len();
 
   let chunk_graph = &compilation.chunk_graph;
   let module_graph = compilation.get_module_graph();
@@ -53,7 +58,7 @@ async fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> rspack_
     .values()
     .filter(|chunk| chunk.id().is_none())
     .collect::<Vec<_>>();
-  let mut chunk_key_to_id =
+  let mut chunk_key_to_id: FxHashMap<ChunkUkey, ChunkId> =
     FxHashMap::with_capacity_and_hasher(chunks.len(), FxBuildHasher::default());
 
   let chunk_names = chunks
@@ -72,10 +77,28 @@ async fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> rspack_
     })
     .collect::<UkeyMap<_, _>>();
 
+  // reuse the previous ids, only the new chunks and the chunks whose previous id
+  // is taken need to be assigned
+  let mut new_chunks = vec![];
+  for chunk in chunks {
+    let name = chunk_names
+      .get(&chunk.ukey())
+      .expect("should have generated full chunk name");
+    if let Some(id) = previous_ids.get(name)
+      && used_ids.insert(id.to_string())
+    {
+      chunk_key_to_id.insert(chunk.ukey(), id.clone());
+    } else {
+      new_chunks.push(chunk);
+    }
+  }
+  let new_chunks_len = new_chunks.len();
+  let used_ids_len = used_ids.len();
+
   let mut ordered_chunk_modules_cache = Default::default();
 
   assign_deterministic_ids(
-    chunks,
+    new_chunks,
     |chunk| {
       chunk_names
         .get(&chunk.ukey())
@@ -99,7 +122,7 @@ async fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> rspack_
         return false;
       }
 
-      chunk_key_to_id.insert(chunk.ukey(), id);
+      chunk_key_to_id.insert(chunk.ukey(), id.to_string().into());
       true
     },
     &[usize::pow(10, max_length)],
@@ -108,10 +131,42 @@ async fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> rspack_
     salt,
   );
 
+  compilation.incremental.record_pass(
+    IncrementalPasses::CHUNK_IDS,
+    incremental,
+    new_chunks_len,
+    compilation.chunk_by_ukey.len(),
+  );
+
+  // only keep the ids of the chunks in this compilation
+  let previous_ids = chunk_key_to_id
+    .iter()
+    .map(|(chunk_ukey, id)| {
+      let name = chunk_names
+        .get(chunk_ukey)
+        .expect("should have generated full chunk name");
+      (name.clone(), id.clone())
+    })
+    .collect();
+
+  let mut mutations = compilation
+    .incremental
+    .mutations_writeable()
+    .then(Mutations::default);
   chunk_key_to_id.into_iter().for_each(|(chunk_ukey, id)| {
     let chunk = compilation.chunk_by_ukey.expect_get_mut(&chunk_ukey);
-    chunk.set_id(id.to_string());
+    if chunk.set_id(id)
+      && let Some(mutations) = &mut mutations
+    {
+      mutations.add(Mutation::ChunkSetId { chunk: chunk_ukey });
+    }
   });
+  if let Some(mut compilation_mutations) = compilation.incremental.mutations_write()
+    && let Some(mutations) = mutations
+  {
+    compilation_mutations.extend(mutations);
+  }
+  compilation.deterministic_ids_artifact.chunk_ids = previous_ids;
 
   Ok(())
 }
@@ -1,7 +1,8 @@
 use rayon::prelude::*;
 use rspack_collections::IdentifierMap;
 use rspack_core::{
-  ChunkGraph, Compilation, CompilationModuleIds, Plugin, incremental::IncrementalPasses,
+  ChunkGraph, Compilation, CompilationModuleIds, ModuleId, Plugin,
+  incremental::{self, IncrementalPasses, Mutation, Mutations},
 };
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
@@ -17,20 +18,57 @@ pub struct DeterministicModuleIdsPlugin;
 
 #[plugin_hook(CompilationModuleIds for DeterministicModuleIdsPlugin)]
 async fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
-  if let Some(diagnostic) = compilation.incremental.disable_passes(
-    IncrementalPasses::MODULE_IDS,
-    "DeterministicModuleIdsPlugin (optimization.moduleIds = \"deterministic\")",
-    "it requires calculating the id of all the modules, which is a global effect",
-  ) {
-    if let Some(diagnostic) = diagnostic {
-      compilation.push_diagnostic(diagnostic);
+  // the previous ids are keyed by module identifier, which is stable between compilations
+  let mut previous_ids = std::mem::take(&mut compilation.deterministic_ids_artifact.module_ids);
+  if !compilation
+    .incremental
+    .passes_enabled(IncrementalPasses::MODULE_IDS)
+  {
+    previous_ids.clear();
+  }
+  let mutations_readable = compilation
+    .incremental
+    .mutations_readable(IncrementalPasses::MODULE_IDS);
+  if let Some(mutations) = compilation
+    .incremental
+    .mutations_read(IncrementalPasses::MODULE_IDS)
+  {
+    tracing::debug!(target: incremental::TRACING_TARGET, passes = %IncrementalPasses::MODULE_IDS, %mutations);
+    for mutation in mutations.iter() {
+      if let Mutation::ModuleRemove { module } = mutation {
+        compilation.module_ids_artifact.remove(module);
+        previous_ids.remove(module);
+      }
     }
-    compilation.module_ids_artifact.clear();
   }
+  let incremental = mutations_readable || !previous_ids.is_empty();
 
   let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
 
   let mut module_ids = std::mem::take(&mut compilation.module_ids_artifact);
+  let mut mutations = compilation
+    .incremental
+    .mutations_writeable()
+    .then(Mutations::default);
+
+  // reuse the previous ids, only the new modules and the modules whose previous id
+  // is taken need to be assigned
+  let mut new_modules = vec![];
+  for module in modules {
+    if let Some(id) = previous_ids.get(&module)
+      && used_ids.insert(id.to_string())
+    {
+      if ChunkGraph::set_module_id(&mut module_ids, module, id.clone())
+        && let Some(mutations) = &mut mutations
+      {
+        mutations.add(Mutation::ModuleSetId { module });
+      }
+    } else {
+      new_modules.push(module);
+    }
+  }
+  let new_modules_len = new_modules.len();
+
   let context = compilation.options.context.as_ref();
   let max_length = 3;
   let fail_on_conflict = false;
@@ -39,19 +77,19 @@ async fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
   let mut conflicts = 0;
 
   let module_graph = compilation.get_module_graph();
-  let modules = modules
+  let new_modules = new_modules
     .into_iter()
     .filter_map(|i| module_graph.module_by_identifier(&i))
     .collect::<Vec<_>>();
   let used_ids_len = used_ids.len();
 
-  let module_names = modules
+  let module_names = new_modules
     .par_iter()
     .map(|m| (m.identifier(), get_full_module_name(m, context)))
     .collect::<IdentifierMap<String>>();
 
   assign_deterministic_ids(
-    modules,
+    new_modules,
     |m| {
       module_names
         .get(&m.identifier())
@@ -70,7 +108,15 @@ async fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
         conflicts += 1;
         return false;
       }
-      ChunkGraph::set_module_id(&mut module_ids, module.identifier(), id.to_string().into());
+      let id: ModuleId = id.to_string().into();
+      if ChunkGraph::set_module_id(&mut module_ids, module.identifier(), id.clone())
+        && let Some(mutations) = &mut mutations
+      {
+        mutations.add(Mutation::ModuleSetId {
+          module: module.identifier(),
+        });
+      }
+      previous_ids.insert(module.identifier(), id);
       true
     },
     &[usize::pow(10, max_length)],
@@ -78,7 +124,26 @@ async fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
     used_ids_len,
     salt,
   );
+
+  if !mutations_readable {
+    // drop the ids of the modules which no longer exist
+    previous_ids.retain(|module, _| module_ids.contains_key(module));
+  }
+  compilation.incremental.record_pass(
+    IncrementalPasses::MODULE_IDS,
+    incremental,
+    new_modules_len,
+    module_ids.len(),
+  );
+
+  if let Some(mut compilation_mutations) = compilation.incremental.mutations_write()
+    && let Some(mutations) = mutations
+  {
+    compilation_mutations.extend(mutations);
+  }
+
   compilation.module_ids_artifact = module_ids;
+  compilation.deterministic_ids_artifact.module_ids = previous_ids;
   if fail_on_conflict && conflicts > 0 {
     // TODO: better error msg
     panic!("Assigning deterministic module ids has lead to conflicts {conflicts}");
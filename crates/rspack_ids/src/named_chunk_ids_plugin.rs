@@ -233,6 +233,14 @@ async fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> rspack_
     .collect();
 
   let chunks_len = chunks.len();
+  compilation.incremental.record_pass(
+    IncrementalPasses::CHUNK_IDS,
+    compilation
+      .incremental
+      .mutations_readable(IncrementalPasses::CHUNK_IDS),
+    chunks_len.saturating_sub(named_chunk_ids_artifact.chunk_ids.len()),
+    chunks_len,
+  );
 
   let mut mutations = compilation
     .incremental
@@ -131,6 +131,10 @@ async fn module_ids(&self, compilation: &mut rspack_core::Compilation) -> Result
     .map(|(&module, id)| (id.clone(), module))
     .collect();
   let module_graph = compilation.get_module_graph();
+  let incremental = compilation
+    .incremental
+    .mutations_readable(IncrementalPasses::MODULE_IDS)
+    && !module_ids.is_empty();
   if let Some(mutations) = compilation
     .incremental
     .mutations_read(IncrementalPasses::MODULE_IDS)
@@ -176,6 +180,12 @@ async fn module_ids(&self, compilation: &mut rspack_core::Compilation) -> Result
     .map(|(m, _)| *m)
     .collect();
   let modules_len = modules.len();
+  compilation.incremental.record_pass(
+    IncrementalPasses::MODULE_IDS,
+    incremental,
+    modules_len,
+    module_graph.modules().len(),
+  );
 
   let context: &str = compilation.options.context.as_ref();
   let mut mutations = compilation
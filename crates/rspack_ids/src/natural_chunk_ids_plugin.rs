@@ -20,6 +20,11 @@ async fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> rspack_
     compilation.push_diagnostic(diagnostic);
   }
 
+  let chunks_len = compilation.chunk_by_ukey.len();
+  compilation
+    .incremental
+    .record_pass(IncrementalPasses::CHUNK_IDS, false, chunks_len, chunks_len);
+
   let module_ids = &compilation.module_ids_artifact;
   let chunk_graph = &compilation.chunk_graph;
   let mut ordered_chunk_modules_cache = Default::default();
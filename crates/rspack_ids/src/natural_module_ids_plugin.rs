@@ -25,6 +25,12 @@ async fn module_ids(&self, compilation: &mut rspack_core::Compilation) -> Result
   }
 
   let (used_ids, mut modules_in_natural_order) = get_used_module_ids_and_modules(compilation, None);
+  compilation.incremental.record_pass(
+    IncrementalPasses::MODULE_IDS,
+    false,
+    modules_in_natural_order.len(),
+    modules_in_natural_order.len(),
+  );
 
   let mut module_ids = std::mem::take(&mut compilation.module_ids_artifact);
   let module_graph = compilation.get_module_graph();
@@ -36,6 +36,11 @@ async fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> Result<
     compilation.push_diagnostic(diagnostic);
   }
 
+  let chunks_len = compilation.chunk_by_ukey.len();
+  compilation
+    .incremental
+    .record_pass(IncrementalPasses::CHUNK_IDS, false, chunks_len, chunks_len);
+
   let chunk_graph = &compilation.chunk_graph;
   let chunk_group_by_ukey = &compilation.chunk_group_by_ukey;
   let mut occurs_in_initial_chunks_map = HashMap::new();
@@ -0,0 +1,331 @@
+use std::{collections::BTreeSet, sync::Mutex};
+
+use rspack_collections::DatabaseItem;
+use rspack_core::{
+  Chunk, ChunkGraph, ChunkUkey, Compilation, CompilationChunkIds, CompilationModuleIds,
+  CompilationParams, CompilerAfterEmit, CompilerThisCompilation, ModuleId, ModuleIdentifier,
+  Plugin,
+  chunk_graph_chunk::ChunkId,
+  incremental::{Mutation, Mutations},
+};
+use rspack_error::{Result, ToStringResultToRspackResultExt, error};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_paths::Utf8PathBuf;
+use rspack_util::identifier::make_paths_relative;
+use serde_json::{Map, Value};
+
+use crate::id_helpers::{get_used_chunk_ids, get_used_module_ids_and_modules};
+
+#[derive(Debug, Default)]
+pub struct RecordIdsPluginOptions {
+  pub input_path: Option<Utf8PathBuf>,
+  pub output_path: Option<Utf8PathBuf>,
+}
+
+/// Revives the module and chunk ids from a records file and records them back
+/// after emit, the same format as webpack `recordsPath`.
+#[plugin]
+#[derive(Debug)]
+pub struct RecordIdsPlugin {
+  options: RecordIdsPluginOptions,
+  records: Mutex<Map<String, Value>>,
+}
+
+impl RecordIdsPlugin {
+  pub fn new(options: RecordIdsPluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+}
+
+#[plugin_hook(CompilerThisCompilation for RecordIdsPlugin)]
+async fn this_compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  let Some(input_path) = &self.options.input_path else {
+    return Ok(());
+  };
+  let fs = compilation.intermediate_filesystem.as_ref();
+  // a missing records file is the same as an empty one
+  let records = if fs.stat(input_path).await.is_ok() {
+    let content = fs.read_file(input_path).await?;
+    match serde_json::from_slice::<Value>(&content) {
+      Ok(Value::Object(records)) => records,
+      Ok(_) => {
+        return Err(error!(
+          "Cannot parse records: {input_path} is not an object"
+        ));
+      }
+      Err(e) => return Err(error!("Cannot parse records: {e}")),
+    }
+  } else {
+    Map::new()
+  };
+  *self.records.lock().expect("should lock records") = records;
+  Ok(())
+}
+
+#[plugin_hook(CompilationModuleIds for RecordIdsPlugin, stage = -100)]
+async fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let by_identifier = self
+    .records
+    .lock()
+    .expect("should lock records")
+    .get("modules")
+    .and_then(|modules| modules.get("byIdentifier"))
+    .and_then(Value::as_object)
+    .cloned();
+  let Some(by_identifier) = by_identifier else {
+    return Ok(());
+  };
+
+  let context = compilation.options.context.as_str();
+  let (mut used_ids, mut modules) = get_used_module_ids_and_modules(compilation, None);
+  modules.sort_unstable();
+
+  let mut revived: Vec<(ModuleIdentifier, ModuleId)> = vec![];
+  for module in modules {
+    let Some(id) = by_identifier
+      .get(&make_paths_relative(context, &module))
+      .and_then(record_id_to_string)
+    else {
+      continue;
+    };
+    if used_ids.insert(id.clone()) {
+      revived.push((module, id.into()));
+    }
+  }
+
+  let mut mutations = compilation
+    .incremental
+    .mutations_writeable()
+    .then(Mutations::default);
+  for (module, id) in revived {
+    if ChunkGraph::set_module_id(&mut compilation.module_ids_artifact, module, id)
+      && let Some(mutations) = &mut mutations
+    {
+      mutations.add(Mutation::ModuleSetId { module });
+    }
+  }
+  if let Some(mut compilation_mutations) = compilation.incremental.mutations_write()
+    && let Some(mutations) = mutations
+  {
+    compilation_mutations.extend(mutations);
+  }
+  Ok(())
+}
+
+#[plugin_hook(CompilationChunkIds for RecordIdsPlugin, stage = -100)]
+async fn chunk_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let chunks_records = self
+    .records
+    .lock()
+    .expect("should lock records")
+    .get("chunks")
+    .and_then(Value::as_object)
+    .cloned();
+  let Some(chunks_records) = chunks_records else {
+    return Ok(());
+  };
+  let by_name = chunks_records.get("byName").and_then(Value::as_object);
+  let by_source = chunks_records.get("bySource").and_then(Value::as_object);
+
+  let context = compilation.options.context.as_str();
+  let mut used_ids = get_used_chunk_ids(compilation);
+  let mut chunks = compilation
+    .chunk_by_ukey
+    .values()
+    .filter(|chunk| chunk.id().is_none())
+    .collect::<Vec<_>>();
+  chunks.sort_unstable_by(|a, b| a.name().cmp(&b.name()).then(a.ukey().cmp(&b.ukey())));
+
+  let mut revived: Vec<(ChunkUkey, ChunkId)> = vec![];
+  let mut remaining = vec![];
+  if let Some(by_name) = by_name {
+    for chunk in chunks {
+      if let Some(id) = chunk
+        .name()
+        .and_then(|name| by_name.get(name))
+        .and_then(record_id_to_string)
+        && used_ids.insert(id.clone())
+      {
+        revived.push((chunk.ukey(), id.into()));
+      } else {
+        remaining.push(chunk);
+      }
+    }
+  } else {
+    remaining = chunks;
+  }
+  if let Some(by_source) = by_source {
+    for chunk in remaining {
+      let id = get_chunk_sources(chunk, compilation, context)
+        .iter()
+        .filter_map(|source| by_source.get(source))
+        .find_map(record_id_to_string);
+      if let Some(id) = id
+        && used_ids.insert(id.clone())
+      {
+        revived.push((chunk.ukey(), id.into()));
+      }
+    }
+  }
+
+  let mut mutations = compilation
+    .incremental
+    .mutations_writeable()
+    .then(Mutations::default);
+  for (chunk_ukey, id) in revived {
+    let chunk = compilation.chunk_by_ukey.expect_get_mut(&chunk_ukey);
+    if chunk.set_id(id)
+      && let Some(mutations) = &mut mutations
+    {
+      mutations.add(Mutation::ChunkSetId { chunk: chunk_ukey });
+    }
+  }
+  if let Some(mut compilation_mutations) = compilation.incremental.mutations_write()
+    && let Some(mutations) = mutations
+  {
+    compilation_mutations.extend(mutations);
+  }
+  Ok(())
+}
+
+#[plugin_hook(CompilerAfterEmit for RecordIdsPlugin)]
+async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(output_path) = &self.options.output_path else {
+    return Ok(());
+  };
+  let json = {
+    let mut records = self.records.lock().expect("should lock records");
+    record_modules(&mut records, compilation);
+    record_chunks(&mut records, compilation);
+    serde_json::to_string_pretty(&*records).to_rspack_result()?
+  };
+
+  let fs = compilation.intermediate_filesystem.as_ref();
+  if let Some(dir) = output_path.parent() {
+    fs.create_dir_all(dir).await?;
+  }
+  fs.write(output_path, json.as_bytes()).await?;
+  Ok(())
+}
+
+impl Plugin for RecordIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.RecordIdsPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx
+      .compiler_hooks
+      .this_compilation
+      .tap(this_compilation::new(self));
+    ctx.compilation_hooks.module_ids.tap(module_ids::new(self));
+    ctx.compilation_hooks.chunk_ids.tap(chunk_ids::new(self));
+    ctx.compiler_hooks.after_emit.tap(after_emit::new(self));
+    Ok(())
+  }
+}
+
+fn record_id_to_string(id: &Value) -> Option<String> {
+  match id {
+    Value::Number(n) => Some(n.to_string()),
+    Value::String(s) => Some(s.clone()),
+    _ => None,
+  }
+}
+
+fn object_entry<'a>(map: &'a mut Map<String, Value>, key: &str) -> &'a mut Map<String, Value> {
+  let value = map.entry(key).or_insert_with(|| Value::Object(Map::new()));
+  if !value.is_object() {
+    *value = Value::Object(Map::new());
+  }
+  value.as_object_mut().expect("should be object")
+}
+
+/// The sources identify a chunk without a name by the chunk groups it belongs to,
+/// in the form of `"{index} {group name}"` or `"{index} {origin module} {request}"`.
+fn get_chunk_sources(chunk: &Chunk, compilation: &Compilation, context: &str) -> Vec<String> {
+  let mut sources = vec![];
+  for group_ukey in chunk.groups() {
+    let Some(group) = compilation.chunk_group_by_ukey.get(group_ukey) else {
+      continue;
+    };
+    let index = group
+      .chunks
+      .iter()
+      .position(|c| *c == chunk.ukey())
+      .map_or(-1, |index| index as i64);
+    if let Some(name) = group.name() {
+      sources.push(format!("{index} {name}"));
+      continue;
+    }
+    for origin in group.origins() {
+      let Some(module) = origin.module else {
+        continue;
+      };
+      let module = make_paths_relative(context, &module);
+      if let Some(request) = &origin.request {
+        sources.push(format!("{index} {module} {request}"));
+      } else if let Some(loc) = &origin.loc {
+        sources.push(format!("{index} {module} {loc}"));
+      }
+    }
+  }
+  sources.sort_unstable();
+  sources
+}
+
+fn record_modules(records: &mut Map<String, Value>, compilation: &Compilation) {
+  let context = compilation.options.context.as_str();
+  let module_graph = compilation.get_module_graph();
+  let mut used_ids = BTreeSet::new();
+  // rebuilt from scratch, so the removed modules are dropped from the records
+  let mut by_identifier = Map::new();
+  for (module, id) in compilation.module_ids_artifact.iter() {
+    // only the numeric ids need to be recorded, the others are derived from the module
+    let Some(id) = id.as_number() else {
+      continue;
+    };
+    if module_graph.module_by_identifier(module).is_none() {
+      continue;
+    }
+    by_identifier.insert(make_paths_relative(context, module), Value::from(id));
+    used_ids.insert(id);
+  }
+  let modules = object_entry(records, "modules");
+  modules.insert("byIdentifier".to_string(), Value::Object(by_identifier));
+  modules.insert(
+    "usedIds".to_string(),
+    used_ids.into_iter().map(Value::from).collect(),
+  );
+}
+
+fn record_chunks(records: &mut Map<String, Value>, compilation: &Compilation) {
+  let context = compilation.options.context.as_str();
+  let mut used_ids = BTreeSet::new();
+  // rebuilt from scratch, so the removed chunks are dropped from the records
+  let mut by_name = Map::new();
+  let mut by_source = Map::new();
+  for chunk in compilation.chunk_by_ukey.values() {
+    let Some(id) = chunk.id().and_then(|id| id.as_str().parse::<u32>().ok()) else {
+      continue;
+    };
+    if let Some(name) = chunk.name() {
+      by_name.insert(name.to_string(), Value::from(id));
+    }
+    for source in get_chunk_sources(chunk, compilation, context) {
+      by_source.insert(source, Value::from(id));
+    }
+    used_ids.insert(id);
+  }
+  let chunks = object_entry(records, "chunks");
+  chunks.insert("byName".to_string(), Value::Object(by_name));
+  chunks.insert("bySource".to_string(), Value::Object(by_source));
+  chunks.insert(
+    "usedIds".to_string(),
+    used_ids.into_iter().map(Value::from).collect(),
+  );
+}
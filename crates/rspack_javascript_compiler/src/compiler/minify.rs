@@ -2,11 +2,11 @@ use std::sync::Arc;
 
 use rspack_error::BatchErrors;
 use rspack_util::swc::minify_file_comments;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
 pub use swc_core::base::BoolOrDataConfig;
 use swc_core::{
-  atoms::Atom,
+  atoms::{Atom, Wtf8Atom},
   base::{
     BoolOr,
     config::{IsModule, JsMinifyCommentOption, JsMinifyFormatOptions, SourceMapsConfig},
@@ -17,7 +17,10 @@ use swc_core::{
     errors::HANDLER,
   },
   ecma::{
-    ast::Ident,
+    ast::{
+      AssignPat, ComputedPropName, Expr, Ident, IdentName, KeyValuePatProp, KeyValueProp, Lit,
+      MemberProp, ObjectPatProp, Pat, Prop, PropName, Str, SuperProp,
+    },
     parser::{EsSyntax, Syntax},
     transforms::base::{
       fixer::{fixer, paren_remover},
@@ -25,7 +28,7 @@ use swc_core::{
       hygiene::hygiene,
       resolver,
     },
-    visit::{Visit, VisitMutWith, noop_visit_type},
+    visit::{Visit, VisitMut, VisitMutWith, VisitWith, noop_visit_mut_type, noop_visit_type},
   },
 };
 pub use swc_ecma_minifier::option::{
@@ -118,22 +121,20 @@ impl JavaScriptCompiler {
         let comments = SingleThreadedComments::default();
 
         let target = opts.ecma.clone().into();
-        let program = self.parse_js(
+        let mut program = self.parse_js(
           fm.clone(),
           target,
-          Syntax::Es(EsSyntax {
-            jsx: true,
-            decorators: true,
-            decorators_before_export: true,
-            import_attributes: true,
-            ..Default::default()
-          }),
+          minify_syntax(),
           opts
             .module
             .map_or_else(|| IsModule::Unknown, IsModule::Bool),
           Some(&comments),
         )?;
 
+        if let Some(names) = &opts.rename_properties {
+          program.visit_mut_with(&mut PropertyRenamer { names });
+        }
+
         let unresolved_mark = Mark::new();
         let top_level_mark = Mark::new();
 
@@ -201,6 +202,47 @@ impl JavaScriptCompiler {
       })
     })
   }
+
+  /// Collects the names of the properties accessed or defined with a static name in the code,
+  /// e.g. `a.b`, `a["b"]`, `{ b: 1 }`, `const { b } = a` and `class { b() {} }`. These are the
+  /// properties renamed by [JsMinifyOptions::rename_properties].
+  pub fn collect_property_names<S: Into<String>>(
+    &self,
+    filename: FileName,
+    source: S,
+    ecma: TerserEcmaVersion,
+    module: Option<bool>,
+  ) -> Result<Vec<String>, BatchErrors> {
+    self.run(|| {
+      let fm = self.cm.new_source_file(Arc::new(filename), source.into());
+      let program = self.parse_js(
+        fm,
+        ecma.into(),
+        minify_syntax(),
+        module.map_or_else(|| IsModule::Unknown, IsModule::Bool),
+        None,
+      )?;
+      let mut collector = PropertyNameCollector::default();
+      program.visit_with(&mut collector);
+      let mut names = collector
+        .names
+        .into_iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+      names.sort_unstable();
+      Ok(names)
+    })
+  }
+}
+
+fn minify_syntax() -> Syntax {
+  Syntax::Es(EsSyntax {
+    jsx: true,
+    decorators: true,
+    decorators_before_export: true,
+    import_attributes: true,
+    ..Default::default()
+  })
 }
 
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
@@ -258,6 +300,10 @@ pub struct JsMinifyOptions {
   #[serde(default = "true_as_default")]
   /// Indicates whether to inline the source content in the source map.
   pub inline_sources_content: bool,
+
+  #[serde(skip)]
+  /// The new names of the properties, the properties are renamed before minifying.
+  pub rename_properties: Option<Arc<FxHashMap<String, String>>>,
 }
 
 const fn true_as_default() -> bool {
@@ -283,3 +329,250 @@ impl Visit for IdentCollector {
     self.names.insert(ident.span.lo, ident.sym.clone());
   }
 }
+
+#[derive(Default)]
+struct PropertyNameCollector {
+  names: FxHashSet<Atom>,
+}
+
+impl PropertyNameCollector {
+  fn insert_str(&mut self, str: &Str) {
+    if let Some(value) = str.value.as_str() {
+      self.names.insert(Atom::from(value));
+    }
+  }
+}
+
+impl Visit for PropertyNameCollector {
+  noop_visit_type!();
+
+  fn visit_member_prop(&mut self, prop: &MemberProp) {
+    if let MemberProp::Ident(ident) = prop {
+      self.names.insert(ident.sym.clone());
+    }
+    prop.visit_children_with(self);
+  }
+
+  fn visit_super_prop(&mut self, prop: &SuperProp) {
+    if let SuperProp::Ident(ident) = prop {
+      self.names.insert(ident.sym.clone());
+    }
+    prop.visit_children_with(self);
+  }
+
+  fn visit_computed_prop_name(&mut self, prop: &ComputedPropName) {
+    if let Expr::Lit(Lit::Str(str)) = &*prop.expr {
+      self.insert_str(str);
+    }
+    prop.visit_children_with(self);
+  }
+
+  fn visit_prop_name(&mut self, name: &PropName) {
+    match name {
+      PropName::Ident(ident) => {
+        self.names.insert(ident.sym.clone());
+      }
+      PropName::Str(str) => self.insert_str(str),
+      _ => {}
+    }
+    name.visit_children_with(self);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop) {
+    if let Prop::Shorthand(ident) = prop {
+      self.names.insert(ident.sym.clone());
+    }
+    prop.visit_children_with(self);
+  }
+
+  fn visit_object_pat_prop(&mut self, prop: &ObjectPatProp) {
+    if let ObjectPatProp::Assign(assign) = prop {
+      self.names.insert(assign.key.id.sym.clone());
+    }
+    prop.visit_children_with(self);
+  }
+}
+
+/// Renames the properties found by [PropertyNameCollector], the shorthand properties are
+/// expanded to keep their values, e.g. `{ b }` is renamed to `{ c: b }`.
+struct PropertyRenamer<'a> {
+  names: &'a FxHashMap<String, String>,
+}
+
+impl PropertyRenamer<'_> {
+  fn rename(&self, name: &str) -> Option<Atom> {
+    self.names.get(name).map(|name| Atom::from(name.as_str()))
+  }
+
+  fn rename_str(&self, str: &mut Str) {
+    if let Some(name) = str.value.as_str().and_then(|value| self.names.get(value)) {
+      str.value = Wtf8Atom::from(name.as_str());
+      str.raw = None;
+    }
+  }
+}
+
+impl VisitMut for PropertyRenamer<'_> {
+  noop_visit_mut_type!();
+
+  fn visit_mut_member_prop(&mut self, prop: &mut MemberProp) {
+    if let MemberProp::Ident(ident) = prop
+      && let Some(name) = self.rename(&ident.sym)
+    {
+      ident.sym = name;
+    }
+    prop.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_super_prop(&mut self, prop: &mut SuperProp) {
+    if let SuperProp::Ident(ident) = prop
+      && let Some(name) = self.rename(&ident.sym)
+    {
+      ident.sym = name;
+    }
+    prop.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_computed_prop_name(&mut self, prop: &mut ComputedPropName) {
+    if let Expr::Lit(Lit::Str(str)) = &mut *prop.expr {
+      self.rename_str(str);
+    }
+    prop.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_prop_name(&mut self, name: &mut PropName) {
+    match name {
+      PropName::Ident(ident) => {
+        if let Some(new_name) = self.rename(&ident.sym) {
+          ident.sym = new_name;
+        }
+      }
+      PropName::Str(str) => self.rename_str(str),
+      _ => {}
+    }
+    name.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_prop(&mut self, prop: &mut Prop) {
+    if let Prop::Shorthand(ident) = prop
+      && let Some(name) = self.rename(&ident.sym)
+    {
+      *prop = Prop::KeyValue(KeyValueProp {
+        key: PropName::Ident(IdentName::new(name, ident.span)),
+        value: Box::new(Expr::Ident(ident.clone())),
+      });
+      return;
+    }
+    prop.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_object_pat_prop(&mut self, prop: &mut ObjectPatProp) {
+    if let ObjectPatProp::Assign(assign) = prop
+      && let Some(name) = self.rename(&assign.key.id.sym)
+    {
+      let key = assign.key.clone();
+      let span = key.id.span;
+      let value = match assign.value.take() {
+        Some(default) => Pat::Assign(AssignPat {
+          span: assign.span,
+          left: Box::new(Pat::Ident(key)),
+          right: default,
+        }),
+        None => Pat::Ident(key),
+      };
+      *prop = ObjectPatProp::KeyValue(KeyValuePatProp {
+        key: PropName::Ident(IdentName::new(name, span)),
+        value: Box::new(value),
+      });
+    }
+    prop.visit_mut_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn collect_property_names(code: &str) -> Vec<String> {
+    JavaScriptCompiler::new()
+      .collect_property_names(
+        FileName::Anon,
+        code,
+        TerserEcmaVersion::default(),
+        Some(true),
+      )
+      .unwrap()
+  }
+
+  fn rename_properties(code: &str, names: &[(&str, &str)]) -> String {
+    let names = names
+      .iter()
+      .map(|(property, name)| (property.to_string(), name.to_string()))
+      .collect::<FxHashMap<_, _>>();
+    JavaScriptCompiler::new()
+      .minify(
+        FileName::Anon,
+        code,
+        JsMinifyOptions {
+          minify: true,
+          compress: BoolOrDataConfig::from_bool(false),
+          mangle: BoolOrDataConfig::from_bool(false),
+          module: Some(true),
+          rename_properties: Some(Arc::new(names)),
+          ..Default::default()
+        },
+        None::<fn(&SingleThreadedComments)>,
+      )
+      .unwrap()
+      .code
+  }
+
+  #[test]
+  fn test_collect_property_names() {
+    assert_eq!(
+      collect_property_names(
+        r#"
+        a.b;
+        a["c"];
+        a[d];
+        ({ e: 1, "f": 2, g, [h]: 3, ["i"]: 4 });
+        const { j, k = 1, l: m } = a;
+        class A extends B { n() { super.o; } #p = 1; }
+        "#
+      ),
+      vec!["b", "c", "e", "f", "g", "i", "j", "k", "l", "n", "o"]
+    );
+    assert!(
+      JavaScriptCompiler::new()
+        .collect_property_names(FileName::Anon, "a.", TerserEcmaVersion::default(), None)
+        .is_err()
+    );
+  }
+
+  #[test]
+  fn test_rename_properties() {
+    let code = rename_properties(
+      r#"
+      obj.foo;
+      obj["foo"];
+      obj.other;
+      ({ foo: 1 });
+      ({ foo });
+      const { foo: bar } = obj;
+      const { foo = 1 } = obj;
+      class A { foo() {} }
+      "#,
+      &[("foo", "x")],
+    );
+    assert!(code.contains("obj.x;"));
+    assert!(code.contains(r#"obj["x"];"#));
+    assert!(code.contains("obj.other;"));
+    assert!(code.contains("{x:1}"));
+    // the shorthand properties keep their values
+    assert!(code.contains("{x:foo}"));
+    assert!(code.contains("const{x:bar}=obj"));
+    assert!(code.contains("const{x:foo=1}=obj"));
+    assert!(code.contains("class A{x(){}}"));
+    assert!(!code.contains(".foo") && !code.contains("\"foo\"") && !code.contains("foo:"));
+  }
+}
@@ -1,10 +1,11 @@
 use std::fmt::Display;
 
 use rspack_cacheable::{cacheable, cacheable_dyn};
+use rspack_collections::IdentifierSet;
 use rspack_core::{
   AsContextDependency, Dependency, DependencyCategory, DependencyCodeGeneration, DependencyId,
   DependencyRange, DependencyTemplate, DependencyTemplateType, DependencyType, FactorizeInfo,
-  ModuleDependency, TemplateContext, TemplateReplaceSource,
+  ModuleDependency, ModuleGraph, ModuleIdentifier, TemplateContext, TemplateReplaceSource,
 };
 
 #[cacheable]
@@ -100,21 +101,84 @@ impl ModuleDependency for CssImportDependency {
   }
 }
 
-#[derive(Clone)]
-pub struct CssMedia(pub String);
-
-#[derive(Clone)]
-pub struct CssSupports(pub String);
-
-impl Display for CssMedia {
+impl Display for CssLayer {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    self.0.fmt(f)
+    match self {
+      CssLayer::Anonymous => write!(f, "@layer"),
+      CssLayer::Named(name) => write!(f, "@layer {name}"),
+    }
   }
 }
 
-impl Display for CssSupports {
-  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    self.0.fmt(f)
+/// The `layer()`, `supports()` and media query of an `@import`.
+#[derive(Debug, Clone, Default)]
+pub struct CssImportCondition {
+  pub layer: Option<CssLayer>,
+  pub supports: Option<String>,
+  pub media: Option<String>,
+}
+
+/// The conditions of the `@import` chain of a module, from the nearest `@import` to the
+/// outermost one. It's stored in the code generation data and the module is wrapped in
+/// the conditions when rendering the chunk.
+#[derive(Debug, Clone, Default)]
+pub struct CssImportConditions(pub Vec<CssImportCondition>);
+
+impl CssImportConditions {
+  /// Collect the conditions of a module by walking up its `@import` chain. When a module
+  /// is imported by several modules, the importer with the smallest identifier is chosen
+  /// so the result is stable.
+  pub fn from_module_graph(module_graph: &ModuleGraph, module: &ModuleIdentifier) -> Self {
+    let mut conditions = vec![];
+    let mut visited = IdentifierSet::default();
+    let mut current = *module;
+    while visited.insert(current) {
+      let importer = module_graph
+        .get_incoming_connections(&current)
+        .filter_map(|conn| {
+          let origin = conn.original_module_identifier?;
+          let dep = module_graph
+            .dependency_by_id(&conn.dependency_id)?
+            .downcast_ref::<CssImportDependency>()?;
+          Some((origin, dep))
+        })
+        .min_by(|(a, _), (b, _)| a.cmp(b));
+      let Some((origin, dep)) = importer else {
+        break;
+      };
+      if dep.layer.is_some() || dep.supports.is_some() || dep.media.is_some() {
+        conditions.push(CssImportCondition {
+          layer: dep.layer.clone(),
+          supports: dep.supports.clone(),
+          media: dep.media.clone(),
+        });
+      }
+      current = origin;
+    }
+    Self(conditions)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// The full names of the layers the module is nested in, from the outermost one,
+  /// e.g. `a` and `a.b` for `@layer a { @layer b { ... } }`.
+  ///
+  /// The layers inside an anonymous layer can't be referenced, so they are skipped.
+  pub fn layer_names(&self) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+    for condition in self.0.iter().rev() {
+      match &condition.layer {
+        Some(CssLayer::Named(name)) => names.push(match names.last() {
+          Some(parent) => format!("{parent}.{name}"),
+          None => name.clone(),
+        }),
+        Some(CssLayer::Anonymous) => break,
+        None => {}
+      }
+    }
+    names
   }
 }
 
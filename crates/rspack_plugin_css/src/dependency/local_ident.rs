@@ -7,7 +7,7 @@ use rspack_core::{
 };
 use rspack_util::ext::DynHash;
 
-use crate::utils::escape_css;
+use crate::{parser_and_generator::CodeGenerationDataUnusedStyleRules, utils::escape_css};
 
 #[cacheable]
 #[derive(Debug, Clone)]
@@ -107,13 +107,21 @@ impl DependencyTemplate for CssLocalIdentDependencyTemplate {
     &self,
     dep: &dyn DependencyCodeGeneration,
     source: &mut TemplateReplaceSource,
-    _code_generatable_context: &mut TemplateContext,
+    code_generatable_context: &mut TemplateContext,
   ) {
     let dep = dep
       .as_any()
       .downcast_ref::<CssLocalIdentDependency>()
       .expect("CssLocalIdentDependencyTemplate should be used for CssLocalIdentDependency");
 
+    if code_generatable_context
+      .data
+      .get::<CodeGenerationDataUnusedStyleRules>()
+      .is_some_and(|unused_rules| unused_rules.contains(dep.start, dep.end))
+    {
+      return;
+    }
+
     source.replace(dep.start, dep.end, &escape_css(&dep.local_ident), None);
   }
 }
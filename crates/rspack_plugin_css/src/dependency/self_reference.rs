@@ -7,7 +7,7 @@ use rspack_core::{
 };
 use rspack_util::atom::Atom;
 
-use crate::utils::escape_css;
+use crate::{parser_and_generator::CodeGenerationDataUnusedStyleRules, utils::escape_css};
 
 #[cacheable]
 #[derive(Debug, Clone)]
@@ -111,14 +111,22 @@ impl DependencyTemplate for CssSelfReferenceLocalIdentDependencyTemplate {
     &self,
     dep: &dyn DependencyCodeGeneration,
     source: &mut TemplateReplaceSource,
-    _code_generatable_context: &mut TemplateContext,
+    code_generatable_context: &mut TemplateContext,
   ) {
     let dep = dep
       .as_any()
       .downcast_ref::<CssSelfReferenceLocalIdentDependency>()
       .expect("CssSelfReferenceLocalIdentDependencyTemplate should be used for CssSelfReferenceLocalIdentDependency");
 
+    let unused_rules = code_generatable_context
+      .data
+      .get::<CodeGenerationDataUnusedStyleRules>();
     for replace in &dep.replaces {
+      if unused_rules
+        .is_some_and(|unused_rules| unused_rules.contains(replace.range.start, replace.range.end))
+      {
+        continue;
+      }
       source.replace(
         replace.range.start,
         replace.range.end,
@@ -7,7 +7,10 @@ use rspack_core::{
   TemplateContext, TemplateReplaceSource,
 };
 
-use crate::utils::{AUTO_PUBLIC_PATH_PLACEHOLDER, css_escape_string};
+use crate::{
+  parser_and_generator::CodeGenerationDataUnusedStyleRules,
+  utils::{AUTO_PUBLIC_PATH_PLACEHOLDER, css_escape_string},
+};
 
 #[cacheable]
 #[derive(Debug, Clone)]
@@ -126,7 +129,15 @@ impl DependencyTemplate for CssUrlDependencyTemplate {
       .downcast_ref::<CssUrlDependency>()
       .expect("CssUrlDependencyTemplate should be used for CssUrlDependency");
 
-    let TemplateContext { compilation, .. } = code_generatable_context;
+    let TemplateContext {
+      compilation, data, ..
+    } = code_generatable_context;
+    if data
+      .get::<CodeGenerationDataUnusedStyleRules>()
+      .is_some_and(|unused_rules| unused_rules.contains(dep.range.start, dep.range.end))
+    {
+      return;
+    }
     if let Some(mgm) = compilation
       .get_module_graph()
       .module_graph_module_by_dependency_id(dep.id())
@@ -1,3 +1,5 @@
+mod style_rule;
+
 use std::{
   borrow::Cow,
   sync::{Arc, LazyLock},
@@ -12,25 +14,27 @@ use rspack_cacheable::{
 };
 use rspack_core::{
   BoxDependencyTemplate, BoxModuleDependency, BuildMetaDefaultObject, BuildMetaExportsType,
-  ChunkGraph, Compilation, ConstDependency, CssExportsConvention, Dependency, DependencyId,
-  DependencyRange, DependencyType, GenerateContext, LocalIdentName, Module, ModuleGraph,
-  ModuleIdentifier, ModuleInitFragments, ModuleType, NormalModule, ParseContext, ParseResult,
-  ParserAndGenerator, PrefetchExportsInfoMode, RuntimeGlobals, RuntimeSpec, SourceType,
-  TemplateContext, UsageState,
+  ChunkGraph, Compilation, ConstDependency, CssExportsConvention, CssTypeDeclaration, Dependency,
+  DependencyId, DependencyRange, DependencyType, GenerateContext, LocalIdentName, Module,
+  ModuleGraph, ModuleIdentifier, ModuleInitFragments, ModuleType, NormalModule, ParseContext,
+  ParseResult, ParserAndGenerator, PrefetchExportsInfoMode, RuntimeGlobals, RuntimeSpec,
+  SourceType, TemplateContext, UsageState,
   diagnostics::map_box_diagnostics_to_module_parse_diagnostics,
   remove_bom,
   rspack_sources::{BoxSource, ConcatSource, RawStringSource, ReplaceSource, Source, SourceExt},
 };
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, Severity, TWithDiagnosticArray};
 use rspack_hash::{RspackHash, RspackHashDigest};
+use rspack_paths::Utf8Path;
 use rspack_util::{atom::Atom, ext::DynHash};
 use rustc_hash::{FxHashMap, FxHashSet};
+pub use style_rule::{CssStyleRule, CssStyleRuleSelector};
 
 use crate::{
   dependency::{
-    CssComposeDependency, CssExportDependency, CssImportDependency, CssLayer,
-    CssLocalIdentDependency, CssMedia, CssSelfReferenceLocalIdentDependency,
-    CssSelfReferenceLocalIdentReplacement, CssSupports, CssUrlDependency,
+    CssComposeDependency, CssExportDependency, CssImportConditions, CssImportDependency, CssLayer,
+    CssLocalIdentDependency, CssSelfReferenceLocalIdentDependency,
+    CssSelfReferenceLocalIdentReplacement, CssUrlDependency,
   },
   utils::{
     LocalIdentOptions, css_modules_exports_to_concatenate_module_string,
@@ -45,6 +49,18 @@ static REGEX_IS_MODULES: LazyLock<Regex> =
 static REGEX_IS_COMMENTS: LazyLock<Regex> =
   LazyLock::new(|| Regex::new(r"/\*[\s\S]*?\*/").expect("Invalid regex"));
 
+/// Whether the module is parsed as CSS Modules, `css/auto` modules are CSS Modules when the
+/// resource matches `*.module.*` or `*.modules.*`.
+pub(crate) fn is_css_modules(module_type: &ModuleType, resource_path: Option<&Utf8Path>) -> bool {
+  match module_type {
+    ModuleType::CssModule => true,
+    ModuleType::CssAuto => {
+      resource_path.is_some_and(|path| REGEX_IS_MODULES.is_match(path.as_str()))
+    }
+    _ => false,
+  }
+}
+
 pub(crate) static CSS_MODULE_SOURCE_TYPE_LIST: &[SourceType; 1] = &[SourceType::Css];
 
 pub(crate) static CSS_MODULE_AND_JS_SOURCE_TYPE_LIST: &[SourceType; 2] =
@@ -82,10 +98,13 @@ pub struct CssParserAndGenerator {
   pub exports_only: bool,
   pub named_exports: bool,
   pub es_module: bool,
+  pub type_declaration: CssTypeDeclaration,
+  pub remove_unused_rules: bool,
   pub url: bool,
   #[cacheable(with=AsOption<AsMap<AsCacheable, AsVec>>)]
   pub exports: Option<CssExports>,
   pub local_names: Option<FxHashMap<String, String>>,
+  pub style_rules: Option<Vec<CssStyleRule>>,
   pub hot: bool,
 }
 
@@ -162,26 +181,19 @@ impl ParserAndGenerator for CssParserAndGenerator {
       s.clone()
     };
 
-    let mode = match module_type {
-      ModuleType::CssModule => css_module_lexer::Mode::Local,
-      ModuleType::CssAuto
-        if resource_path.is_some()
-          && REGEX_IS_MODULES.is_match(
-            resource_path
-              .as_ref()
-              .expect("should have resource_path for module_type css/auto")
-              .as_str(),
-          ) =>
-      {
-        css_module_lexer::Mode::Local
-      }
-      _ => css_module_lexer::Mode::Css,
+    let mode = if is_css_modules(module_type, resource_path) {
+      css_module_lexer::Mode::Local
+    } else {
+      css_module_lexer::Mode::Css
     };
 
     let mut diagnostics: Vec<Diagnostic> = vec![];
     let mut dependencies: Vec<Box<dyn Dependency>> = vec![];
     let mut presentational_dependencies: Vec<BoxDependencyTemplate> = vec![];
     let mut code_generation_dependencies: Vec<BoxModuleDependency> = vec![];
+    let collect_style_rules =
+      self.remove_unused_rules && matches!(mode, css_module_lexer::Mode::Local);
+    let mut selector_locals = vec![];
 
     let (deps, warnings) = css_module_lexer::collect_dependencies(&source_code, mode);
     for dependency in deps {
@@ -260,6 +272,9 @@ impl ParserAndGenerator for CssParserAndGenerator {
         | css_module_lexer::Dependency::LocalId { name, range, .. } => {
           let (_prefix, name) = name.split_at(1); // split '#' or '.'
           let name = unescape(name);
+          if collect_style_rules {
+            selector_locals.push((range.start, range.end, name.to_string()));
+          }
 
           let local_ident = LocalIdentOptions::new(
             resource_data,
@@ -496,6 +511,22 @@ impl ParserAndGenerator for CssParserAndGenerator {
       diagnostics.push(error.into());
     }
 
+    self.style_rules = collect_style_rules.then(|| {
+      let mut style_rules = style_rule::collect_style_rules(&source_code);
+      for (start, end, name) in selector_locals {
+        let index = style_rules.partition_point(|rule| rule.range.end <= start);
+        if let Some(selector) = style_rules
+          .get_mut(index)
+          .into_iter()
+          .flat_map(|rule| rule.selectors.iter_mut())
+          .find(|selector| selector.range.start <= start && end <= selector.range.end)
+        {
+          selector.add_local(&source_code, start, name);
+        }
+      }
+      style_rules
+    });
+
     Ok(
       ParseResult {
         dependencies,
@@ -539,6 +570,14 @@ impl ParserAndGenerator for CssParserAndGenerator {
         };
 
         let module_graph = compilation.get_module_graph();
+        let unused_rules =
+          self.get_unused_style_rules(module.identifier(), context.runtime, &module_graph);
+        if !unused_rules.ranges.is_empty() {
+          for range in unused_rules.ranges.iter() {
+            source.replace(range.start, range.end, "", None);
+          }
+          context.data.insert(unused_rules);
+        }
         module.get_dependencies().iter().for_each(|id| {
           let dep = module_graph
             .dependency_by_id(id)
@@ -556,36 +595,28 @@ impl ParserAndGenerator for CssParserAndGenerator {
           }
         });
 
-        for conn in module_graph.get_incoming_connections(&module.identifier()) {
-          let Some(dep) = module_graph.dependency_by_id(&conn.dependency_id) else {
-            continue;
-          };
-
-          if matches!(dep.dependency_type(), DependencyType::CssImport) {
-            let Some(css_import_dep) = dep.downcast_ref::<CssImportDependency>() else {
-              panic!(
-                "dependency with type DependencyType::CssImport should only be CssImportDependency"
-              );
-            };
-
-            if let Some(media) = css_import_dep.media() {
-              let media = CssMedia(media.to_string());
-              context.data.insert(media);
-            }
-
-            if let Some(supports) = css_import_dep.supports() {
-              let supports = CssSupports(supports.to_string());
-              context.data.insert(supports);
-            }
-
-            if let Some(layer) = css_import_dep.layer() {
-              context.data.insert(layer.clone());
-            }
-          }
+        let conditions =
+          CssImportConditions::from_module_graph(&module_graph, &module.identifier());
+        if !conditions.is_empty() {
+          context.data.insert(conditions);
         }
 
         if let Some(dependencies) = module.get_presentational_dependencies() {
+          let unused_rules = context
+            .data
+            .get::<CodeGenerationDataUnusedStyleRules>()
+            .cloned();
           dependencies.iter().for_each(|dependency| {
+            // the replacements in the removed rules would be rendered into the output
+            if let Some(unused_rules) = &unused_rules
+              && let Some(dependency) = dependency
+                .as_ref()
+                .as_any()
+                .downcast_ref::<ConstDependency>()
+              && unused_rules.contains(dependency.range.start, dependency.range.end)
+            {
+              return;
+            }
             if let Some(template) = compilation.get_dependency_template(dependency.as_ref()) {
               template.render(dependency.as_ref(), &mut source, &mut context)
             } else {
@@ -733,6 +764,51 @@ impl ParserAndGenerator for CssParserAndGenerator {
   }
 }
 
+impl CssParserAndGenerator {
+  fn get_unused_style_rules(
+    &self,
+    identifier: ModuleIdentifier,
+    runtime: Option<&RuntimeSpec>,
+    mg: &ModuleGraph,
+  ) -> CodeGenerationDataUnusedStyleRules {
+    let mut ranges = vec![];
+    if let Some(style_rules) = &self.style_rules
+      && let Some(exports) = &self.exports
+      && let Some(local_names) = &self.local_names
+      && let Some(exports_info) =
+        mg.get_prefetched_exports_info_optional(&identifier, PrefetchExportsInfoMode::Default)
+    {
+      // a local is used when any used export contains its ident, including the composed ones
+      let used_idents = exports
+        .iter()
+        .filter(|(name, _)| {
+          exports_info
+            .get_read_only_export_info(&Atom::from(name.as_str()))
+            .get_used(runtime)
+            != UsageState::Unused
+        })
+        .flat_map(|(_, css_exports)| css_exports.iter())
+        .filter(|css_export| css_export.from.is_none())
+        .map(|css_export| css_export.ident.as_str())
+        .collect::<FxHashSet<_>>();
+
+      ranges.extend(
+        style_rules
+          .iter()
+          .filter(|rule| {
+            rule.is_unused(|local| {
+              local_names
+                .get(local)
+                .is_some_and(|ident| !used_idents.contains(ident.as_str()))
+            })
+          })
+          .map(|rule| rule.range),
+      );
+    }
+    CodeGenerationDataUnusedStyleRules { ranges }
+  }
+}
+
 fn get_used_exports<'a>(
   exports: &'a CssExports,
   identifier: ModuleIdentifier,
@@ -764,6 +840,22 @@ pub struct CodeGenerationDataUnusedLocalIdent {
   pub(crate) idents: FxHashSet<String>,
 }
 
+/// The style rules removed from the output as their local classes and ids are unused.
+#[derive(Debug, Clone)]
+pub struct CodeGenerationDataUnusedStyleRules {
+  pub(crate) ranges: Vec<DependencyRange>,
+}
+
+impl CodeGenerationDataUnusedStyleRules {
+  /// Whether the range is in a removed rule, the dependencies in it should not be rendered.
+  pub(crate) fn contains(&self, start: u32, end: u32) -> bool {
+    self
+      .ranges
+      .iter()
+      .any(|range| range.start <= start && end <= range.end)
+  }
+}
+
 fn get_unused_local_ident(
   exports: &CssExports,
   local_names: &FxHashMap<String, String>,
@@ -0,0 +1,224 @@
+use cow_utils::CowUtils;
+use rspack_cacheable::cacheable;
+use rspack_core::DependencyRange;
+use rspack_util::css_scanner::{
+  GROUP_AT_RULES, skip_block, skip_comment, skip_string, split_selectors,
+};
+
+/// A style rule of a CSS module, with the local classes and ids of each complex selector in
+/// its selector list.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct CssStyleRule {
+  /// From the start of the selector list to the end of the block.
+  pub range: DependencyRange,
+  pub selectors: Vec<CssStyleRuleSelector>,
+}
+
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct CssStyleRuleSelector {
+  pub range: DependencyRange,
+  /// The original names of the local classes and ids in the selector.
+  pub locals: Vec<String>,
+}
+
+impl CssStyleRuleSelector {
+  /// Add the local class or id starting at `start` of the source.
+  ///
+  /// The locals nested in functional pseudo-classes like `:not()` and `:is()` are skipped: an
+  /// unused local in `.a:not(.b)` doesn't make the selector unmatchable, only the locals of the
+  /// compound selectors decide that.
+  pub fn add_local(&mut self, source: &str, start: u32, name: String) {
+    if paren_depth(source.as_bytes(), self.range.start as usize, start as usize) == 0 {
+      self.locals.push(name);
+    }
+  }
+}
+
+impl CssStyleRule {
+  /// Whether the rule can never match: every complex selector has a local class or id, and all
+  /// of them are unused. Selectors mixing used and unused locals keep the rule.
+  pub fn is_unused(&self, is_unused_local: impl Fn(&str) -> bool) -> bool {
+    !self.selectors.is_empty()
+      && self.selectors.iter().all(|selector| {
+        !selector.locals.is_empty() && selector.locals.iter().all(|local| is_unused_local(local))
+      })
+  }
+}
+
+/// Collect the style rules at the top level and in the group at-rules like `@media`.
+///
+/// The rules nested in a style rule are not collected, they are removed along with their parent.
+pub fn collect_style_rules(source: &str) -> Vec<CssStyleRule> {
+  let bytes = source.as_bytes();
+  let mut rules = vec![];
+  // whether each of the enclosing blocks is a group at-rule
+  let mut blocks: Vec<bool> = vec![];
+  let mut prelude_start = None;
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        i = skip_comment(bytes, i);
+        continue;
+      }
+      b'"' | b'\'' => {
+        prelude_start.get_or_insert(i);
+        i = skip_string(bytes, i);
+        continue;
+      }
+      b'\\' => {
+        prelude_start.get_or_insert(i);
+        i += 2;
+        continue;
+      }
+      b'{' => {
+        let start = prelude_start.take().unwrap_or(i);
+        let prelude = &source[start..i];
+        let scannable = blocks.iter().all(|group| *group);
+        if let Some(at_rule) = prelude.strip_prefix('@') {
+          let name = at_rule
+            .split(|c: char| c.is_whitespace() || c == '(' || c == '{')
+            .next()
+            .unwrap_or_default()
+            .cow_to_ascii_lowercase();
+          if scannable && GROUP_AT_RULES.contains(&name.as_ref()) {
+            blocks.push(true);
+            i += 1;
+          } else {
+            i = skip_block(bytes, i);
+          }
+          continue;
+        }
+        let end = skip_block(bytes, i);
+        if scannable {
+          rules.push(CssStyleRule {
+            range: DependencyRange::new(start as u32, end as u32),
+            selectors: split_selectors(source, start, i)
+              .into_iter()
+              .map(|range| CssStyleRuleSelector {
+                range: DependencyRange::new(range.start as u32, range.end as u32),
+                locals: vec![],
+              })
+              .collect(),
+          });
+        }
+        i = end;
+        continue;
+      }
+      b'}' => {
+        blocks.pop();
+        prelude_start = None;
+      }
+      b';' => {
+        prelude_start = None;
+      }
+      c if c.is_ascii_whitespace() => {}
+      _ => {
+        prelude_start.get_or_insert(i);
+      }
+    }
+    i += 1;
+  }
+  rules
+}
+
+/// Return the depth of the parentheses at `end`, counted from `start`.
+fn paren_depth(bytes: &[u8], start: usize, end: usize) -> usize {
+  let mut depth = 0usize;
+  let mut i = start;
+  while i < end {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        i = skip_comment(bytes, i);
+        continue;
+      }
+      b'"' | b'\'' => {
+        i = skip_string(bytes, i);
+        continue;
+      }
+      b'\\' => i += 1,
+      b'(' => depth += 1,
+      b')' => depth = depth.saturating_sub(1),
+      _ => {}
+    }
+    i += 1;
+  }
+  depth
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn text(source: &str, range: DependencyRange) -> &str {
+    &source[range.start as usize..range.end as usize]
+  }
+
+  fn selectors<'a>(source: &'a str, rule: &CssStyleRule) -> Vec<&'a str> {
+    rule
+      .selectors
+      .iter()
+      .map(|selector| text(source, selector.range))
+      .collect()
+  }
+
+  #[test]
+  fn test_collect_style_rules() {
+    let source = r#"/* .comment { } */
+.a, .b { color: red; }
+@import "foo.css";
+@media (min-width: 100px) {
+  @supports (display: grid) {
+    .c { content: "}"; }
+  }
+}
+@font-face { font-family: x; }
+@keyframes k { from { opacity: 0; } }
+.d { .e { color: blue; } }
+"#;
+    let rules = collect_style_rules(source);
+    assert_eq!(rules.len(), 3);
+    assert_eq!(text(source, rules[0].range), ".a, .b { color: red; }");
+    assert_eq!(selectors(source, &rules[0]), vec![".a", ".b"]);
+    assert_eq!(text(source, rules[1].range), r#".c { content: "}"; }"#);
+    assert_eq!(selectors(source, &rules[1]), vec![".c"]);
+    // the nested rule is removed along with its parent
+    assert_eq!(text(source, rules[2].range), ".d { .e { color: blue; } }");
+    assert_eq!(selectors(source, &rules[2]), vec![".d"]);
+  }
+
+  #[test]
+  fn test_is_unused() {
+    let source = ".a:not(.b), :is(.c) .d, :where(.e) { color: red; }";
+    let mut rules = collect_style_rules(source);
+    assert_eq!(rules.len(), 1);
+    let rule = &mut rules[0];
+    for name in ["a", "b", "c", "d", "e"] {
+      let start = source.find(&format!(".{name}")).unwrap() as u32;
+      let selector = rule
+        .selectors
+        .iter_mut()
+        .find(|selector| selector.range.start <= start && start < selector.range.end)
+        .unwrap();
+      selector.add_local(source, start, name.to_string());
+    }
+    // only the locals of the compound selectors are counted
+    let locals = rule
+      .selectors
+      .iter()
+      .map(|selector| selector.locals.clone())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      locals,
+      vec![vec!["a".to_string()], vec!["d".to_string()], vec![]]
+    );
+
+    // `:where(.e)` matches regardless of `.e`
+    assert!(!rule.is_unused(|_| true));
+    rule.selectors.pop();
+    assert!(rule.is_unused(|local| local != "b"));
+    assert!(!rule.is_unused(|local| local != "a"));
+  }
+}
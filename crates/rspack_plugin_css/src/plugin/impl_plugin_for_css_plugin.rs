@@ -1,27 +1,29 @@
 #![allow(clippy::comparison_chain)]
 
 use std::{
-  borrow::Cow,
   hash::Hash,
   sync::{Arc, LazyLock},
 };
 
 use atomic_refcell::AtomicRefCell;
-use rspack_collections::{DatabaseItem, ItemUkey};
+use cow_utils::CowUtils;
+use rspack_collections::{DatabaseItem, IdentifierSet, ItemUkey};
 use rspack_core::{
-  AssetInfo, Chunk, ChunkGraph, ChunkKind, ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation,
-  CompilationContentHash, CompilationId, CompilationParams, CompilationRenderManifest,
-  CompilationRuntimeRequirementInTree, CompilerCompilation, DependencyType, ManifestAssetType,
-  Module, ModuleGraph, ModuleType, ParserAndGenerator, PathData, Plugin, PublicPath,
-  RenderManifestEntry, RuntimeGlobals, RuntimeModuleExt, SelfModuleFactory, SourceType,
-  get_css_chunk_filename_template,
+  AssetInfo, BoxModule, Chunk, ChunkGraph, ChunkKind, ChunkLoading, ChunkLoadingType, ChunkUkey,
+  Compilation, CompilationAsset, CompilationContentHash, CompilationId, CompilationParams,
+  CompilationProcessAssets, CompilationRenderManifest, CompilationRuntimeRequirementInTree,
+  CompilerCompilation, CssTypeDeclaration, DependencyType, ManifestAssetType, Module, ModuleGraph,
+  ModuleType, ParserAndGenerator, PathData, Plugin, PublicPath, RenderManifestEntry,
+  RuntimeGlobals, RuntimeModuleExt, SelfModuleFactory, SourceType, get_css_chunk_filename_template,
   rspack_sources::{
     BoxSource, CachedSource, ConcatSource, RawStringSource, ReplaceSource, Source, SourceExt,
   },
 };
 use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt};
+use rspack_fs::{NativeFileSystem, WritableFileSystem};
 use rspack_hash::RspackHash;
 use rspack_hook::plugin_hook;
+use rspack_paths::Utf8PathBuf;
 use rspack_plugin_runtime::is_enabled_for_chunk;
 use rspack_util::fx_hash::FxDashMap;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
@@ -29,13 +31,15 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use crate::{
   CssPlugin,
   dependency::{
-    CssImportDependencyTemplate, CssLayer, CssLocalIdentDependencyTemplate, CssMedia,
-    CssSelfReferenceLocalIdentDependencyTemplate, CssSupports, CssUrlDependencyTemplate,
+    CssImportConditions, CssImportDependencyTemplate, CssLocalIdentDependencyTemplate,
+    CssSelfReferenceLocalIdentDependencyTemplate, CssUrlDependencyTemplate,
   },
-  parser_and_generator::{CodeGenerationDataUnusedLocalIdent, CssParserAndGenerator},
-  plugin::{CssModulesPluginHooks, CssModulesRenderSource, CssPluginInner},
+  parser_and_generator::{
+    CodeGenerationDataUnusedLocalIdent, CssParserAndGenerator, is_css_modules,
+  },
+  plugin::{CssModulesPluginHooks, CssModulesRenderSource, CssPluginInner, TypeDeclaration},
   runtime::CssLoadingRuntimeModule,
-  utils::AUTO_PUBLIC_PATH_PLACEHOLDER,
+  utils::{AUTO_PUBLIC_PATH_PLACEHOLDER, css_modules_type_declaration},
 };
 
 /// Safety with [atomic_refcell::AtomicRefCell]:
@@ -99,9 +103,21 @@ impl CssPlugin {
     let hooks = css_plugin_hooks.borrow();
     let (ordered_css_modules, conflicts) =
       Self::get_ordered_chunk_css_modules(chunk, compilation, css_import_modules, css_modules);
-    let source =
+    let mut source =
       Self::render_chunk_to_source(compilation, chunk, &ordered_css_modules, &hooks).await?;
 
+    // declare the layers up front, the first loaded chunk decides the cascade order of the
+    // layers, so it's kept even if the modules are reordered by splitting chunks
+    let layers = Self::get_chunk_layer_order(chunk, compilation);
+    if !layers.is_empty() {
+      let mut declared = ConcatSource::new([RawStringSource::from(format!(
+        "@layer {};\n",
+        layers.join(", ")
+      ))]);
+      declared.add(source);
+      source = declared;
+    }
+
     let content = source.source().into_string_lossy();
     let len = AUTO_PUBLIC_PATH_PLACEHOLDER.len();
     let auto_public_path_matches: Vec<_> = content
@@ -196,28 +212,28 @@ impl CssPlugin {
                 let mut num_close_bracket = 0;
 
                 // TODO: use PrefixSource to create indent
-                if let Some(media) = data.get::<CssMedia>() {
-                  num_close_bracket += 1;
-                  container_source.add(RawStringSource::from(format!("@media {media}{{\n")));
-                }
+                // the outermost `@import` wraps the others, e.g. for `a.css` imported with
+                // `layer(a)` and importing `b.css` with `layer(b)`, b is rendered in
+                // `@layer a { @layer b { ... } }`
+                if let Some(conditions) = data.get::<CssImportConditions>() {
+                  for condition in conditions.0.iter().rev() {
+                    if let Some(media) = &condition.media {
+                      num_close_bracket += 1;
+                      container_source.add(RawStringSource::from(format!("@media {media}{{\n")));
+                    }
 
-                if let Some(supports) = data.get::<CssSupports>() {
-                  num_close_bracket += 1;
-                  container_source.add(RawStringSource::from(format!(
-                    "@supports ({supports}) {{\n"
-                  )));
-                }
+                    if let Some(supports) = &condition.supports {
+                      num_close_bracket += 1;
+                      container_source.add(RawStringSource::from(format!(
+                        "@supports ({supports}) {{\n"
+                      )));
+                    }
 
-                if let Some(layer) = data.get::<CssLayer>() {
-                  num_close_bracket += 1;
-                  container_source.add(RawStringSource::from(format!(
-                    "@layer{} {{\n",
-                    if let CssLayer::Named(layer) = &layer {
-                      Cow::Owned(format!(" {layer}"))
-                    } else {
-                      Cow::Borrowed("")
+                    if let Some(layer) = &condition.layer {
+                      num_close_bracket += 1;
+                      container_source.add(RawStringSource::from(format!("{layer} {{\n")));
                     }
-                  )));
+                  }
                 }
 
                 container_source.add(cur_source.clone());
@@ -393,6 +409,7 @@ async fn content_hash(
         id.hash(&mut hasher);
       }
     });
+  Self::get_chunk_layer_order(chunk, compilation).hash(&mut hasher);
 
   Ok(())
 }
@@ -480,6 +497,172 @@ async fn render_manifest(
   Ok(())
 }
 
+#[plugin_hook(CompilationProcessAssets for CssPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  // take the declarations out, the lock must not be held while reading and writing the files
+  let mut type_declarations = std::mem::take(
+    &mut *self
+      .type_declarations
+      .lock()
+      .expect("should lock type declarations"),
+  );
+  let module_graph = compilation.get_module_graph();
+  let modules = module_graph.modules();
+  let built_modules = compilation
+    .build_module_graph_artifact
+    .built_modules()
+    .collect::<IdentifierSet>();
+  type_declarations.retain(|identifier, _| {
+    modules.contains_key(identifier) && !built_modules.contains(identifier)
+  });
+  let generated = modules
+    .iter()
+    .filter(|(identifier, _)| !type_declarations.contains_key(*identifier))
+    .map(|(identifier, module)| (*identifier, generate_type_declaration(module)))
+    .collect::<Vec<_>>();
+
+  // the unchanged declarations are checked again only if the files are modified
+  for declaration in type_declarations.values_mut().flatten() {
+    if matches!(declaration.r#type, CssTypeDeclaration::Check)
+      && compilation
+        .modified_files
+        .iter()
+        .chain(compilation.removed_files.iter())
+        .any(|file| file.as_ref() == declaration.path.as_std_path())
+    {
+      declaration.mismatch = check_type_declaration(compilation, declaration).await;
+    }
+  }
+
+  // the declarations are written next to the sources, which live on the real disk even if the
+  // input and output filesystems are virtual
+  let source_filesystem = NativeFileSystem::new(false);
+  for (identifier, mut declaration) in generated {
+    if let Some(declaration) = &mut declaration {
+      match declaration.r#type {
+        CssTypeDeclaration::None | CssTypeDeclaration::Asset => {}
+        CssTypeDeclaration::Write => {
+          let current = source_filesystem.read_file(&declaration.path).await.ok();
+          if current.as_deref() != Some(declaration.content.as_bytes()) {
+            source_filesystem
+              .write(&declaration.path, declaration.content.as_bytes())
+              .await?;
+          }
+        }
+        CssTypeDeclaration::Check => {
+          declaration.mismatch = check_type_declaration(compilation, declaration).await;
+        }
+      }
+    }
+    type_declarations.insert(identifier, declaration);
+  }
+
+  for (identifier, declaration) in &type_declarations {
+    let Some(declaration) = declaration else {
+      continue;
+    };
+    match declaration.r#type {
+      CssTypeDeclaration::None | CssTypeDeclaration::Write => {}
+      CssTypeDeclaration::Asset => {
+        let Ok(filename) = declaration
+          .path
+          .strip_prefix(compilation.options.context.as_path())
+        else {
+          let mut diagnostic = Diagnostic::warn(
+            "CSS Modules type declaration".into(),
+            format!(
+              "The type declaration {} is not emitted, as its source is outside the context.",
+              declaration.path
+            ),
+          );
+          diagnostic.module_identifier = Some(*identifier);
+          compilation.push_diagnostic(diagnostic);
+          continue;
+        };
+        let filename = filename.as_str().cow_replace('\\', "/").into_owned();
+        compilation.emit_asset(
+          filename,
+          CompilationAsset::new(
+            Some(RawStringSource::from(declaration.content.clone()).boxed()),
+            AssetInfo::default(),
+          ),
+        );
+      }
+      CssTypeDeclaration::Check => {
+        compilation
+          .file_dependencies
+          .insert(declaration.path.clone().into_std_path_buf().into());
+        let Some(mismatch) = &declaration.mismatch else {
+          continue;
+        };
+        let mut diagnostic = Diagnostic::error(
+          "CSS Modules type declaration".into(),
+          format!(
+            "{mismatch}\nSet `typeDeclaration: \"write\"` in the generator options to update it."
+          ),
+        );
+        diagnostic.module_identifier = Some(*identifier);
+        compilation.push_diagnostic(diagnostic);
+      }
+    }
+  }
+
+  *self
+    .type_declarations
+    .lock()
+    .expect("should lock type declarations") = type_declarations;
+  Ok(())
+}
+
+/// Generate the CSS Modules type declaration of the module, `None` if it doesn't need one.
+fn generate_type_declaration(module: &BoxModule) -> Option<TypeDeclaration> {
+  let module = module.as_normal_module()?;
+  let parser_and_generator = module
+    .parser_and_generator()
+    .as_any()
+    .downcast_ref::<CssParserAndGenerator>()?;
+  if matches!(
+    parser_and_generator.type_declaration,
+    CssTypeDeclaration::None
+  ) {
+    return None;
+  }
+  let resource_data = module
+    .match_resource()
+    .unwrap_or_else(|| module.resource_resolved_data().as_ref());
+  let resource_path = resource_data.path()?;
+  if !is_css_modules(module.module_type(), Some(resource_path)) {
+    return None;
+  }
+  Some(TypeDeclaration {
+    r#type: parser_and_generator.type_declaration,
+    path: Utf8PathBuf::from(format!("{resource_path}.d.ts")),
+    content: css_modules_type_declaration(
+      parser_and_generator.exports.as_ref(),
+      parser_and_generator.named_exports,
+    ),
+    mismatch: None,
+  })
+}
+
+/// Compare the declaration with the file next to the source, return why it doesn't match.
+async fn check_type_declaration(
+  compilation: &Compilation,
+  declaration: &TypeDeclaration,
+) -> Option<String> {
+  match compilation.input_filesystem.read(&declaration.path).await {
+    Ok(current) if current == declaration.content.as_bytes() => None,
+    Ok(_) => Some(format!(
+      "The type declaration {} is outdated.",
+      declaration.path
+    )),
+    Err(_) => Some(format!(
+      "The type declaration {} is missing.",
+      declaration.path
+    )),
+  }
+}
+
 impl Plugin for CssPlugin {
   fn name(&self) -> &'static str {
     "css"
@@ -499,6 +682,10 @@ impl Plugin for CssPlugin {
       .compilation_hooks
       .render_manifest
       .tap(render_manifest::new(self));
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
 
     ctx.register_parser_and_generator_builder(
       ModuleType::Css,
@@ -512,11 +699,14 @@ impl Plugin for CssPlugin {
         Box::new(CssParserAndGenerator {
           exports: None,
           local_names: None,
+          style_rules: None,
           convention: None,
           local_ident_name: None,
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
           es_module: g.es_module.expect("should have es_module"),
+          type_declaration: CssTypeDeclaration::None,
+          remove_unused_rules: false,
           hot: false,
           url: p.url.expect("should have url"),
         }) as Box<dyn ParserAndGenerator>
@@ -534,6 +724,7 @@ impl Plugin for CssPlugin {
         Box::new(CssParserAndGenerator {
           exports: None,
           local_names: None,
+          style_rules: None,
           convention: Some(
             g.exports_convention
               .expect("should have exports_convention"),
@@ -546,6 +737,10 @@ impl Plugin for CssPlugin {
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
           es_module: g.es_module.expect("should have es_module"),
+          type_declaration: g.type_declaration.expect("should have type_declaration"),
+          remove_unused_rules: g
+            .remove_unused_rules
+            .expect("should have remove_unused_rules"),
           hot: false,
           url: p.url.expect("should have url"),
         }) as Box<dyn ParserAndGenerator>
@@ -563,6 +758,7 @@ impl Plugin for CssPlugin {
         Box::new(CssParserAndGenerator {
           exports: None,
           local_names: None,
+          style_rules: None,
           convention: Some(
             g.exports_convention
               .expect("should have exports_convention"),
@@ -575,6 +771,10 @@ impl Plugin for CssPlugin {
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
           es_module: g.es_module.expect("should have es_module"),
+          type_declaration: g.type_declaration.expect("should have type_declaration"),
+          remove_unused_rules: g
+            .remove_unused_rules
+            .expect("should have remove_unused_rules"),
           hot: false,
           url: p.url.expect("should have url"),
         }) as Box<dyn ParserAndGenerator>
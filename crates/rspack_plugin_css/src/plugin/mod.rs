@@ -1,18 +1,40 @@
 #![allow(clippy::comparison_chain)]
 mod drive;
 mod impl_plugin_for_css_plugin;
-use std::cmp::{self, Reverse};
+use std::{
+  cmp::{self, Reverse},
+  sync::Mutex,
+};
 
 pub use drive::*;
-use rspack_collections::{DatabaseItem, IdentifierSet};
+use indexmap::IndexSet;
+use rspack_collections::{DatabaseItem, IdentifierMap, IdentifierSet};
 use rspack_core::{
-  Chunk, ChunkUkey, Compilation, Module, ModuleIdentifier, compare_modules_by_identifier,
+  Chunk, ChunkUkey, Compilation, CssTypeDeclaration, Module, ModuleIdentifier, SourceType,
+  compare_modules_by_identifier,
 };
 use rspack_hook::plugin;
+use rspack_paths::Utf8PathBuf;
+
+use crate::dependency::CssImportConditions;
 
 #[plugin]
 #[derive(Debug, Default)]
-pub struct CssPlugin;
+pub struct CssPlugin {
+  /// The CSS Modules type declarations of the modules in the previous compilation, `None` for
+  /// the modules without a declaration. Only the rebuilt modules are generated again.
+  type_declarations: Mutex<IdentifierMap<Option<TypeDeclaration>>>,
+}
+
+#[derive(Debug)]
+struct TypeDeclaration {
+  r#type: CssTypeDeclaration,
+  /// The path next to the source, as `{resource}.d.ts`.
+  path: Utf8PathBuf,
+  content: String,
+  /// Why the declaration next to the source doesn't match, only for the check mode.
+  mismatch: Option<String>,
+}
 
 #[derive(Debug)]
 pub struct CssOrderConflicts {
@@ -50,6 +72,43 @@ impl CssPlugin {
     (external_css_modules, conflicts)
   }
 
+  /// Get the names of the layers used by the CSS modules of the chunk groups the chunk
+  /// belongs to, in the order they are imported.
+  ///
+  /// All the chunks of a chunk group declare the same layers in the same order, so the
+  /// cascade order doesn't depend on which chunk is loaded first.
+  pub(crate) fn get_chunk_layer_order(chunk: &Chunk, compilation: &Compilation) -> Vec<String> {
+    let module_graph = compilation.get_module_graph();
+    let mut groups = chunk.groups().iter().collect::<Vec<_>>();
+    groups.sort_unstable();
+
+    let mut layers: IndexSet<String> = Default::default();
+    for group in groups {
+      let group = compilation.chunk_group_by_ukey.expect_get(group);
+      let mut modules = group
+        .chunks
+        .iter()
+        .flat_map(|chunk| {
+          compilation
+            .chunk_graph
+            .get_chunk_modules_identifier_by_source_type(chunk, SourceType::Css, &module_graph)
+        })
+        .filter_map(|module| {
+          group
+            .module_post_order_index(&module)
+            .map(|index| (index, module))
+        })
+        .collect::<Vec<_>>();
+      // the imported modules are rendered before the importers
+      modules.sort_unstable();
+      modules.dedup();
+      for (_, module) in modules {
+        layers.extend(CssImportConditions::from_module_graph(&module_graph, &module).layer_names());
+      }
+    }
+    layers.into_iter().collect()
+  }
+
   pub fn get_modules_in_order<'module>(
     chunk: &Chunk,
     modules: Vec<&'module dyn Module>,
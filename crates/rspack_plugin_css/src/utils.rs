@@ -21,7 +21,7 @@ use rspack_hash::RspackHash;
 use rspack_util::{identifier::make_paths_relative, itoa, json_stringify};
 use rustc_hash::FxHashSet as HashSet;
 
-use crate::parser_and_generator::CssExport;
+use crate::parser_and_generator::{CssExport, CssExports};
 
 pub const AUTO_PUBLIC_PATH_PLACEHOLDER: &str = "__RSPACK_PLUGIN_CSS_AUTO_PUBLIC_PATH__";
 pub static LEADING_DIGIT_REGEX: LazyLock<Regex> =
@@ -253,6 +253,51 @@ pub fn stringified_exports<'a>(
   ))
 }
 
+/// Render the TypeScript declaration of the exports of a CSS module, the names are sorted to
+/// keep the declaration stable when the rules are reordered.
+pub fn css_modules_type_declaration(exports: Option<&CssExports>, named_exports: bool) -> String {
+  let mut names = exports
+    .map(|exports| exports.keys().map(String::as_str).collect::<Vec<_>>())
+    .unwrap_or_default();
+  names.sort_unstable();
+
+  let mut code = String::from("// This file is generated by Rspack, do not edit it manually.\n");
+  if named_exports {
+    let mut used_identifiers = HashSet::default();
+    let mut aliased = vec![];
+    for name in names {
+      if to_identifier(name) == name && !RESERVED_IDENTIFIER.contains(name) {
+        used_identifiers.insert(Cow::Borrowed(name));
+        code += &format!("export declare const {name}: string;\n");
+      } else {
+        aliased.push(name);
+      }
+    }
+    // names which are not valid identifiers are exported with string literal names
+    for name in aliased {
+      let mut identifier = Cow::Owned(format!("_{}", to_identifier(name)));
+      let mut i = 0;
+      while used_identifiers.contains(&identifier) {
+        let mut i_buffer = itoa::Buffer::new();
+        identifier = Cow::Owned(format!("_{}{}", to_identifier(name), i_buffer.format(i)));
+        i += 1;
+      }
+      code += &format!(
+        "declare const {identifier}: string;\nexport {{ {identifier} as {} }};\n",
+        json_stringify(&name)
+      );
+      used_identifiers.insert(identifier);
+    }
+  } else {
+    code += "declare const styles: {\n";
+    for name in names {
+      code += &format!("  readonly {}: string;\n", json_stringify(&name));
+    }
+    code += "};\nexport default styles;\n";
+  }
+  code
+}
+
 pub fn css_modules_exports_to_concatenate_module_string<'a>(
   exports: IndexMap<&'a str, &'a IndexSet<CssExport>>,
   module: &dyn rspack_core::Module,
@@ -0,0 +1,879 @@
+use std::{
+  cmp::Reverse,
+  ops::Range,
+  sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+  },
+};
+
+use aho_corasick::{AhoCorasick, MatchKind};
+use dashmap::DashMap;
+use rspack_collections::IdentifierMap;
+use rspack_core::{
+  Compilation, ConcatenatedModule, ModuleIdentifier,
+  rspack_sources::{
+    BoxSource, MapOptions, Mapping, ObjectPool, OriginalLocation, SourceMap, encode_mappings,
+  },
+};
+use rspack_error::{Result, error};
+use rspack_hash::RspackHashDigest;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::{SourceReference, modules_by_resource};
+
+/// How the mappings of an asset are split into the sections of an index source map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceMapSections {
+  /// A section for each source.
+  Module,
+  /// A section for each module, the modules concatenated into a module share its section.
+  ConcatenatedModule,
+}
+
+/// Groups the sources of the source map of an asset into sections, it's used when the code of
+/// the modules can't be found in the asset.
+pub(crate) struct SectionGrouping {
+  sections: SourceMapSections,
+  resource_to_module: HashMap<String, ModuleIdentifier>,
+  /// The concatenated modules by their inner modules.
+  concatenated_modules: IdentifierMap<ModuleIdentifier>,
+}
+
+impl SectionGrouping {
+  pub(crate) fn new(compilation: &Compilation, sections: SourceMapSections) -> Self {
+    let mut concatenated_modules = IdentifierMap::default();
+    if sections == SourceMapSections::ConcatenatedModule {
+      for (identifier, module) in compilation.get_module_graph().modules() {
+        if let Some(concatenated_module) = module.as_ref().downcast_ref::<ConcatenatedModule>() {
+          for inner_module in concatenated_module.get_modules() {
+            concatenated_modules.insert(inner_module.id, identifier);
+          }
+        }
+      }
+    }
+    Self {
+      sections,
+      resource_to_module: modules_by_resource(compilation),
+      concatenated_modules,
+    }
+  }
+
+  /// The section of each source in `source_references`, as the index of the first source of the
+  /// section, the sources of the same section share it.
+  pub(crate) fn group(
+    &self,
+    compilation: &Compilation,
+    source_references: &[SourceReference],
+  ) -> Vec<usize> {
+    let module_graph = compilation.get_module_graph();
+    let mut groups: HashMap<ModuleIdentifier, usize> = HashMap::default();
+    source_references
+      .iter()
+      .enumerate()
+      .map(|(index, source_reference)| {
+        if self.sections == SourceMapSections::Module {
+          return index;
+        }
+        let module = match source_reference {
+          SourceReference::Module(identifier) => Some(*identifier),
+          SourceReference::Source(source_name) => {
+            let identifier = ModuleIdentifier::from(source_name.as_ref());
+            if module_graph.module_by_identifier(&identifier).is_some() {
+              Some(identifier)
+            } else {
+              self.resource_to_module.get(source_name.as_ref()).copied()
+            }
+          }
+        };
+        let Some(module) = module else {
+          return index;
+        };
+        let root = self
+          .concatenated_modules
+          .get(&module)
+          .copied()
+          .unwrap_or(module);
+        *groups.entry(root).or_insert(index)
+      })
+      .collect()
+  }
+}
+
+/// A section of an index source map, the `sources` and `names` are the indexes of them in a
+/// source map ordered by the indexes of the section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Section {
+  /// The 1-based line and 0-based column where the section starts.
+  line: u32,
+  column: u32,
+  mappings: String,
+  sources: Vec<usize>,
+  names: Vec<usize>,
+}
+
+impl Section {
+  fn start(&self) -> (u32, u32) {
+    (self.line, self.column)
+  }
+}
+
+/// Split the mappings into sections of the same `group`, the mappings without a source stay in
+/// the current section since they only end the previous segment. The first section starts at the
+/// beginning to cover the code before the first mapping.
+fn split_sections(mappings: &[Mapping], group: impl Fn(u32) -> usize) -> Vec<Section> {
+  let mut ranges: Vec<Range<usize>> = vec![];
+  let mut start = 0;
+  let mut current_group = None;
+  for (index, mapping) in mappings.iter().enumerate() {
+    let Some(original) = &mapping.original else {
+      continue;
+    };
+    let group = group(original.source_index);
+    if current_group.is_some_and(|current| current != group) {
+      ranges.push(start..index);
+      start = index;
+    }
+    current_group = Some(group);
+  }
+  if start < mappings.len() || ranges.is_empty() {
+    ranges.push(start..mappings.len());
+  }
+
+  ranges
+    .into_iter()
+    .enumerate()
+    .map(|(index, range)| {
+      let section_mappings = &mappings[range];
+      let (line, column) = match section_mappings.first() {
+        Some(mapping) if index > 0 => (mapping.generated_line, mapping.generated_column),
+        _ => (1, 0),
+      };
+      to_relative_section(section_mappings, line, column)
+    })
+    .collect()
+}
+
+/// The section starting at `line` and `column` with the mappings relative to its start.
+fn to_relative_section(mappings: &[Mapping], line: u32, column: u32) -> Section {
+  let mut sources: HashMap<u32, u32> = HashMap::default();
+  let mut names: HashMap<u32, u32> = HashMap::default();
+  let relative_mappings = mappings
+    .iter()
+    .map(|mapping| Mapping {
+      generated_line: mapping.generated_line - line + 1,
+      generated_column: if mapping.generated_line == line {
+        mapping.generated_column - column
+      } else {
+        mapping.generated_column
+      },
+      original: mapping.original.as_ref().map(|original| {
+        let next_source = sources.len() as u32;
+        let next_name = names.len() as u32;
+        OriginalLocation {
+          source_index: *sources.entry(original.source_index).or_insert(next_source),
+          original_line: original.original_line,
+          original_column: original.original_column,
+          name_index: original
+            .name_index
+            .map(|name_index| *names.entry(name_index).or_insert(next_name)),
+        }
+      }),
+    })
+    .collect::<Vec<_>>();
+  Section {
+    line,
+    column,
+    mappings: encode_mappings(relative_mappings.into_iter()),
+    sources: by_local_index(sources),
+    names: by_local_index(names),
+  }
+}
+
+/// The sections of an asset split from the mappings of its source map by the `groups` of
+/// `SectionGrouping::group`.
+pub(crate) fn asset_sections(source_map: &SourceMap, groups: &[usize]) -> Vec<Section> {
+  let mappings = source_map.decoded_mappings().collect::<Vec<_>>();
+  split_sections(&mappings, |source_index| {
+    groups
+      .get(source_index as usize)
+      .copied()
+      .unwrap_or(source_index as usize)
+  })
+}
+
+/// The generated code of a module with its mappings, which are split into sections relative to
+/// the start of the code.
+#[derive(Debug)]
+pub(crate) struct ModuleSection {
+  code: String,
+  /// The mappings of the code, used to validate the source map of an asset.
+  mappings: Vec<Mapping>,
+  sections: Vec<Section>,
+  sources: Vec<String>,
+  sources_content: Vec<Arc<str>>,
+  names: Vec<String>,
+}
+
+impl ModuleSection {
+  /// Returns `None` if the code of the module has no mappings.
+  pub(crate) fn new(
+    source: &BoxSource,
+    map_options: &MapOptions,
+    sections: SourceMapSections,
+  ) -> Option<Self> {
+    let source_map = source.map(&ObjectPool::default(), map_options)?;
+    let mappings = source_map.decoded_mappings().collect::<Vec<_>>();
+    let mut has_original = false;
+    for original in mappings
+      .iter()
+      .filter_map(|mapping| mapping.original.as_ref())
+    {
+      // the indexes are used to find the sources and names of the asset
+      if original.source_index as usize >= source_map.sources().len()
+        || original
+          .name_index
+          .is_some_and(|name_index| name_index as usize >= source_map.names().len())
+      {
+        return None;
+      }
+      has_original = true;
+    }
+    let code = source.source().into_string_lossy().into_owned();
+    if !has_original || code.is_empty() {
+      return None;
+    }
+
+    let sections = match sections {
+      SourceMapSections::Module => split_sections(&mappings, |source_index| source_index as usize),
+      // the code of a concatenated module includes the code of its inner modules
+      SourceMapSections::ConcatenatedModule => split_sections(&mappings, |_| 0),
+    };
+    Some(Self {
+      code,
+      mappings,
+      sections,
+      sources: source_map.sources().to_vec(),
+      sources_content: source_map.sources_content().to_vec(),
+      names: source_map.names().to_vec(),
+    })
+  }
+}
+
+#[derive(Debug)]
+struct CachedSection {
+  section: Arc<ModuleSection>,
+  used: AtomicBool,
+}
+
+/// The mappings of the modules by their identifiers and code generation hashes, so the sections
+/// of the unchanged modules are not encoded again on rebuilds.
+#[derive(Debug, Default)]
+pub(crate) struct SourceMapSectionsCache(
+  DashMap<(ModuleIdentifier, RspackHashDigest), CachedSection>,
+);
+
+impl SourceMapSectionsCache {
+  /// Remove the sections not used since the last call.
+  pub(crate) fn retain_used(&self) {
+    self
+      .0
+      .retain(|_, section| section.used.swap(false, Ordering::Relaxed));
+  }
+
+  pub(crate) fn get_or_insert_with(
+    &self,
+    key: (ModuleIdentifier, RspackHashDigest),
+    f: impl FnOnce() -> Option<ModuleSection>,
+  ) -> Option<Arc<ModuleSection>> {
+    if let Some(cached) = self.0.get(&key) {
+      cached.used.store(true, Ordering::Relaxed);
+      return Some(cached.section.clone());
+    }
+    let section = Arc::new(f()?);
+    self.0.insert(
+      key,
+      CachedSection {
+        section: section.clone(),
+        used: AtomicBool::new(true),
+      },
+    );
+    Some(section)
+  }
+}
+
+/// A module whose code is found in an asset.
+#[derive(Debug)]
+struct PlacedModule {
+  /// The 1-based line and 0-based column where the code of the module starts.
+  line: u32,
+  column: u32,
+  section: Arc<ModuleSection>,
+  /// The indexes of the sources and names of the module in the source map of the asset.
+  sources: Vec<usize>,
+  names: Vec<usize>,
+}
+
+impl PlacedModule {
+  fn generated_position(&self, line: u32, column: u32) -> (u32, u32) {
+    if line == 1 {
+      (self.line, self.column + column)
+    } else {
+      (self.line + line - 1, column)
+    }
+  }
+}
+
+/// The modules found in the code of an asset, the index source map of the asset is built from
+/// the mappings of the modules without generating the source map of the asset.
+#[derive(Debug)]
+pub(crate) struct ModuleSections {
+  modules: Vec<PlacedModule>,
+  sections: Vec<Section>,
+}
+
+impl ModuleSections {
+  pub(crate) fn sections(&self) -> &[Section] {
+    &self.sections
+  }
+
+  /// The mappings of the asset, with the indexes of the sources and names of its source map.
+  pub(crate) fn mappings(&self) -> impl Iterator<Item = Mapping> + '_ {
+    self.modules.iter().flat_map(|module| {
+      module.section.mappings.iter().map(move |mapping| {
+        let (generated_line, generated_column) =
+          module.generated_position(mapping.generated_line, mapping.generated_column);
+        Mapping {
+          generated_line,
+          generated_column,
+          original: mapping.original.as_ref().map(|original| OriginalLocation {
+            source_index: module.sources[original.source_index as usize] as u32,
+            original_line: original.original_line,
+            original_column: original.original_column,
+            name_index: original
+              .name_index
+              .map(|name_index| module.names[name_index as usize] as u32),
+          }),
+        }
+      })
+    })
+  }
+}
+
+/// The max length of the code used to search a module in an asset.
+const ANCHOR_LEN: usize = 64;
+
+/// Find the code of the `modules` in the `code` of an asset in a single pass, and merge their
+/// sources and names into the source map of the asset, which has no mappings.
+///
+/// Returns `None` if the code of a module is not found or found at other places than the code
+/// of the modules, e.g. when the code is changed by a minimizer after the code generation.
+pub(crate) fn locate_modules(
+  code: &str,
+  modules: Vec<Arc<ModuleSection>>,
+) -> Option<(SourceMap, ModuleSections)> {
+  if modules.is_empty() {
+    return None;
+  }
+  // the modules usually start and end with the same code, so the middle of the code is searched
+  let anchors = modules
+    .iter()
+    .map(|module| {
+      let module_code = module.code.as_bytes();
+      let start = module_code.len().saturating_sub(ANCHOR_LEN) / 2;
+      (
+        start,
+        &module_code[start..(start + ANCHOR_LEN).min(module_code.len())],
+      )
+    })
+    .collect::<Vec<_>>();
+  let anchors_ac = AhoCorasick::builder()
+    .match_kind(MatchKind::Standard)
+    .build(anchors.iter().map(|(_, anchor)| *anchor))
+    .ok()?;
+
+  let bytes = code.as_bytes();
+  let mut found = anchors_ac
+    .find_overlapping_iter(bytes)
+    .filter_map(|anchor_match| {
+      let index = anchor_match.pattern().as_usize();
+      let start = anchor_match.start().checked_sub(anchors[index].0)?;
+      let range = start..start + modules[index].code.len();
+      (bytes.get(range.clone()) == Some(modules[index].code.as_bytes())).then_some((range, index))
+    })
+    .collect::<Vec<_>>();
+  found.sort_unstable_by_key(|(range, _)| (range.start, Reverse(range.end)));
+
+  // the code of a module may contain the code of other modules, place the outer ones first
+  let mut placed = vec![None; modules.len()];
+  let mut placed_ranges: Vec<(Range<usize>, usize)> = vec![];
+  for (range, index) in &found {
+    if placed[*index].is_none()
+      && placed_ranges
+        .last()
+        .is_none_or(|(last, _)| last.end <= range.start)
+    {
+      placed[*index] = Some(range.start);
+      placed_ranges.push((range.clone(), *index));
+    }
+  }
+  if placed.iter().any(Option::is_none) {
+    return None;
+  }
+  let overlaps_placed = |range: &Range<usize>| {
+    let index = placed_ranges.partition_point(|(placed, _)| placed.start < range.end);
+    index > 0 && placed_ranges[index - 1].0.end > range.start
+  };
+  if found
+    .iter()
+    .any(|(range, index)| placed[*index] != Some(range.start) && !overlaps_placed(range))
+  {
+    return None;
+  }
+
+  let mut sources = vec![];
+  let mut sources_content = vec![];
+  let mut names = vec![];
+  let mut source_indexes: HashMap<&str, usize> = HashMap::default();
+  let mut name_indexes: HashMap<&str, usize> = HashMap::default();
+  let mut placed_modules = Vec::with_capacity(modules.len());
+  let (mut line, mut line_start, mut position) = (1, 0, 0);
+  for (range, index) in &placed_ranges {
+    for newline in memchr::memchr_iter(b'\n', &bytes[position..range.start]) {
+      line += 1;
+      line_start = position + newline + 1;
+    }
+    position = range.start;
+    // the columns of source maps are in UTF-16 code units
+    let column = code.get(line_start..range.start)?.encode_utf16().count() as u32;
+
+    let module = &modules[*index];
+    let module_sources = module
+      .sources
+      .iter()
+      .enumerate()
+      .map(|(source_index, source)| {
+        *source_indexes.entry(source.as_str()).or_insert_with(|| {
+          sources.push(source.clone());
+          sources_content.push(
+            module
+              .sources_content
+              .get(source_index)
+              .cloned()
+              .unwrap_or_else(|| Arc::from("")),
+          );
+          sources.len() - 1
+        })
+      })
+      .collect();
+    let module_names = module
+      .names
+      .iter()
+      .map(|name| {
+        *name_indexes.entry(name.as_str()).or_insert_with(|| {
+          names.push(name.clone());
+          names.len() - 1
+        })
+      })
+      .collect();
+    placed_modules.push(PlacedModule {
+      line,
+      column,
+      section: module.clone(),
+      sources: module_sources,
+      names: module_names,
+    });
+  }
+
+  let sections = placed_modules
+    .iter()
+    .flat_map(|module| {
+      module.section.sections.iter().map(|section| {
+        let (line, column) = module.generated_position(section.line, section.column);
+        Section {
+          line,
+          column,
+          mappings: section.mappings.clone(),
+          sources: section.sources.iter().map(|&i| module.sources[i]).collect(),
+          names: section.names.iter().map(|&i| module.names[i]).collect(),
+        }
+      })
+    })
+    .collect::<Vec<_>>();
+  // the sections must be ordered and can't overlap
+  if !sections
+    .windows(2)
+    .all(|sections| sections[0].start() < sections[1].start())
+  {
+    return None;
+  }
+
+  Some((
+    SourceMap::new(String::new(), sources, sources_content, names),
+    ModuleSections {
+      modules: placed_modules,
+      sections,
+    },
+  ))
+}
+
+/// Serialize the source map as an index source map with the `sections`, which refer to the
+/// `sources` and `names` of the source map. `finish_section` applies the options like
+/// `sourceRoot` to the map of each section.
+pub(crate) fn to_index_map_json(
+  source_map: &SourceMap,
+  sections: &[Section],
+  file: &str,
+  debug_id: Option<&str>,
+  finish_section: impl Fn(&mut SourceMap),
+) -> Result<String> {
+  let sections = sections
+    .iter()
+    .map(|section| {
+      let mut section_map = to_section_map(
+        source_map,
+        section.mappings.clone(),
+        &section.sources,
+        &section.names,
+      );
+      finish_section(&mut section_map);
+      let json = section_map.to_json().map_err(|e| error!(e.to_string()))?;
+      Ok(format!(
+        r#"{{"offset":{{"line":{},"column":{}}},"map":{json}}}"#,
+        section.line - 1,
+        section.column
+      ))
+    })
+    .collect::<Result<Vec<_>>>()?;
+
+  let file = simd_json::to_string(file).map_err(|e| error!(e.to_string()))?;
+  let debug_id = debug_id
+    .map(|debug_id| format!(r#","debugId":"{debug_id}""#))
+    .unwrap_or_default();
+  Ok(format!(
+    r#"{{"version":3,"file":{file},"sections":[{}]{debug_id}}}"#,
+    sections.join(",")
+  ))
+}
+
+/// The map of a section with the `sources` and `names` of the asset ordered by their local
+/// indexes.
+fn to_section_map(
+  source_map: &SourceMap,
+  mappings: String,
+  sources: &[usize],
+  names: &[usize],
+) -> SourceMap {
+  SourceMap::new(
+    mappings,
+    sources
+      .iter()
+      .map(|&index| source_map.sources().get(index).cloned().unwrap_or_default())
+      .collect::<Vec<_>>(),
+    sources
+      .iter()
+      .map(|&index| {
+        source_map
+          .sources_content()
+          .get(index)
+          .cloned()
+          .unwrap_or_else(|| Arc::from(""))
+      })
+      .collect::<Vec<_>>(),
+    names
+      .iter()
+      .map(|&index| source_map.names().get(index).cloned().unwrap_or_default())
+      .collect::<Vec<_>>(),
+  )
+}
+
+/// The global indexes of a section ordered by their local indexes.
+fn by_local_index(indexes: HashMap<u32, u32>) -> Vec<usize> {
+  let mut indexes = indexes.into_iter().collect::<Vec<_>>();
+  indexes.sort_unstable_by_key(|(_, local)| *local);
+  indexes
+    .into_iter()
+    .map(|(global, _)| global as usize)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use rspack_core::rspack_sources::{SourceExt, SourceMapSource, WithoutOriginalOptions};
+
+  use super::*;
+
+  fn mapping(
+    generated_line: u32,
+    generated_column: u32,
+    source_index: u32,
+    original_line: u32,
+    original_column: u32,
+  ) -> Mapping {
+    Mapping {
+      generated_line,
+      generated_column,
+      original: Some(OriginalLocation {
+        source_index,
+        original_line,
+        original_column,
+        name_index: None,
+      }),
+    }
+  }
+
+  fn source_map(mappings: Vec<Mapping>, sources: &[&str]) -> SourceMap {
+    SourceMap::new(
+      encode_mappings(mappings.into_iter()),
+      sources
+        .iter()
+        .map(|source| source.to_string())
+        .collect::<Vec<_>>(),
+      sources
+        .iter()
+        .map(|source| Arc::<str>::from(format!("// {source}")))
+        .collect::<Vec<_>>(),
+      Vec::<String>::new(),
+    )
+  }
+
+  fn module_source(code: &str, mappings: Vec<Mapping>, sources: &[&str]) -> BoxSource {
+    SourceMapSource::new(WithoutOriginalOptions {
+      value: code,
+      name: sources[0],
+      source_map: source_map(mappings, sources),
+    })
+    .boxed()
+  }
+
+  fn module_section(source: &BoxSource, sections: SourceMapSections) -> Arc<ModuleSection> {
+    Arc::new(
+      ModuleSection::new(source, &MapOptions::new(true), sections).expect("should have mappings"),
+    )
+  }
+
+  fn a_source() -> BoxSource {
+    module_source(
+      "a();\n  a();\n",
+      vec![mapping(1, 0, 0, 1, 0), mapping(2, 2, 0, 2, 2)],
+      &["a.js"],
+    )
+  }
+
+  fn b_source() -> BoxSource {
+    module_source(
+      "b();\nb();\n",
+      vec![mapping(1, 0, 0, 1, 0), mapping(2, 0, 0, 2, 0)],
+      &["b.js"],
+    )
+  }
+
+  /// An asset with the runtime on the first line, the module `a` on the 2nd and 3rd lines and
+  /// the module `b` starting at the 5th column of the 4th line.
+  const ASSET_CODE: &str = "init();\na();\n  a();\n    b();\nb();\n";
+
+  fn asset_source_map() -> SourceMap {
+    source_map(
+      vec![
+        mapping(2, 0, 0, 1, 0),
+        mapping(3, 2, 0, 2, 2),
+        mapping(4, 4, 1, 1, 0),
+        mapping(5, 0, 1, 2, 0),
+      ],
+      &["webpack:///a.js", "webpack:///b.js"],
+    )
+  }
+
+  fn index_map_json(source_map: &SourceMap, sections: &[Section]) -> Vec<String> {
+    let json = to_index_map_json(source_map, sections, "main.js", None, |_| {}).unwrap();
+    assert!(json.starts_with(r#"{"version":3,"file":"main.js","sections":["#));
+    json
+      .split(r#"{"offset":"#)
+      .skip(1)
+      .map(|section| section.to_string())
+      .collect()
+  }
+
+  #[test]
+  fn test_module_sections() {
+    let modules = vec![
+      module_section(&b_source(), SourceMapSections::Module),
+      module_section(&a_source(), SourceMapSections::Module),
+    ];
+    let (source_map, module_sections) =
+      locate_modules(ASSET_CODE, modules).expect("should find the modules");
+    assert_eq!(source_map.sources(), ["a.js", "b.js"]);
+
+    let sections = index_map_json(&source_map, module_sections.sections());
+    assert_eq!(sections.len(), 2);
+    // the sections start where the code of the modules starts
+    assert!(sections[0].starts_with(r#"{"line":1,"column":0}"#));
+    assert!(sections[0].contains(r#""mappings":"AAAA;EACE""#));
+    assert!(sections[0].contains(r#""sources":["a.js"]"#));
+    assert!(sections[1].starts_with(r#"{"line":3,"column":4}"#));
+    assert!(sections[1].contains(r#""mappings":"AAAA;AACA""#));
+    assert!(sections[1].contains(r#""sources":["b.js"]"#));
+
+    // the mappings are the same as the ones of the asset
+    assert_eq!(
+      positions(module_sections.mappings()),
+      positions(asset_source_map().decoded_mappings())
+    );
+  }
+
+  fn positions(mappings: impl Iterator<Item = Mapping>) -> Vec<(u32, u32, u32, u32, u32)> {
+    mappings
+      .filter_map(|mapping| {
+        let original = mapping.original?;
+        Some((
+          mapping.generated_line,
+          mapping.generated_column,
+          original.source_index,
+          original.original_line,
+          original.original_column,
+        ))
+      })
+      .collect()
+  }
+
+  #[test]
+  fn test_module_sections_by_source() {
+    // the code of a concatenated module with the mappings of two sources
+    let source = module_source(
+      "a();\nb();\n",
+      vec![mapping(1, 0, 0, 1, 0), mapping(2, 0, 1, 1, 0)],
+      &["a.js", "b.js"],
+    );
+    let code = "init(); a();\nb();\n";
+
+    let (_, module_sections) = locate_modules(
+      code,
+      vec![module_section(&source, SourceMapSections::Module)],
+    )
+    .expect("should find the module");
+    let starts = module_sections
+      .sections()
+      .iter()
+      .map(Section::start)
+      .collect::<Vec<_>>();
+    assert_eq!(starts, vec![(1, 8), (2, 0)]);
+
+    let (_, module_sections) = locate_modules(
+      code,
+      vec![module_section(
+        &source,
+        SourceMapSections::ConcatenatedModule,
+      )],
+    )
+    .expect("should find the module");
+    assert_eq!(module_sections.sections().len(), 1);
+    assert_eq!(module_sections.sections()[0].sources, vec![0, 1]);
+  }
+
+  #[test]
+  fn test_locate_modules() {
+    let a = module_section(&a_source(), SourceMapSections::Module);
+    // the columns are in UTF-16 code units
+    let (_, module_sections) =
+      locate_modules("init('😀'); a();\n  a();\n", vec![a.clone()]).expect("should find a");
+    assert_eq!(module_sections.sections()[0].start(), (1, 12));
+
+    // the modules with the same code are both placed
+    let (source_map, module_sections) =
+      locate_modules("a();\n  a();\na();\n  a();\n", vec![a.clone(), a.clone()])
+        .expect("should find both modules");
+    assert_eq!(source_map.sources(), ["a.js"]);
+    let starts = module_sections
+      .sections()
+      .iter()
+      .map(Section::start)
+      .collect::<Vec<_>>();
+    assert_eq!(starts, vec![(1, 0), (3, 0)]);
+
+    // the code is changed, e.g. by a minimizer
+    assert!(locate_modules("a();\n    a();\n", vec![a.clone()]).is_none());
+    // the code is found at several places
+    assert!(locate_modules("a();\n  a();\na();\n  a();\n", vec![a.clone()]).is_none());
+    assert!(locate_modules("", vec![a]).is_none());
+    assert!(locate_modules(ASSET_CODE, vec![]).is_none());
+  }
+
+  #[test]
+  fn test_locate_long_modules() {
+    let code = format!("{}\n{}\n", "x".repeat(100), "y".repeat(100));
+    let source = module_source(
+      &code,
+      vec![mapping(1, 0, 0, 1, 0), mapping(2, 0, 0, 2, 0)],
+      &["long.js"],
+    );
+    let (_, module_sections) = locate_modules(
+      &format!("init();\n{code}"),
+      vec![module_section(&source, SourceMapSections::Module)],
+    )
+    .expect("should find the module");
+    assert_eq!(module_sections.sections()[0].start(), (2, 0));
+  }
+
+  #[test]
+  fn test_asset_sections() {
+    let source_map = asset_source_map();
+    let sections = index_map_json(&source_map, &asset_sections(&source_map, &[0, 1]));
+    assert_eq!(sections.len(), 2);
+    // the first section covers the code before the first mapping
+    assert!(sections[0].starts_with(r#"{"line":0,"column":0}"#));
+    assert!(sections[0].contains(r#""mappings":";AAAA;EACE""#));
+    assert!(sections[0].contains(r#""sources":["webpack:///a.js"]"#));
+    assert!(sections[1].starts_with(r#"{"line":3,"column":4}"#));
+    assert!(sections[1].contains(r#""mappings":"AAAA;AACA""#));
+    assert!(sections[1].contains(r#""sources":["webpack:///b.js"]"#));
+
+    // the sources of the same group share a section
+    assert_eq!(asset_sections(&source_map, &[0, 0]).len(), 1);
+  }
+
+  #[test]
+  fn test_sections_cache() {
+    let cache = SourceMapSectionsCache::default();
+    let a1 = (ModuleIdentifier::from("a"), RspackHashDigest::from("a1"));
+    let a2 = (ModuleIdentifier::from("a"), RspackHashDigest::from("a2"));
+    let b1 = (ModuleIdentifier::from("b"), RspackHashDigest::from("b1"));
+    let get = |key: &(ModuleIdentifier, RspackHashDigest), source: BoxSource| {
+      cache.get_or_insert_with(key.clone(), || {
+        ModuleSection::new(&source, &MapOptions::new(true), SourceMapSections::Module)
+      })
+    };
+
+    let a = get(&a1, a_source()).expect("should have mappings");
+    get(&b1, b_source());
+    cache.retain_used();
+    assert!(cache.0.contains_key(&a1) && cache.0.contains_key(&b1));
+    // the section of an unchanged module is reused
+    assert!(Arc::ptr_eq(
+      &a,
+      &get(&a1, b_source()).expect("should be cached")
+    ));
+
+    cache.retain_used();
+    get(&a2, a_source());
+    get(&b1, b_source());
+    cache.retain_used();
+    assert!(!cache.0.contains_key(&a1));
+    assert!(cache.0.contains_key(&a2) && cache.0.contains_key(&b1));
+
+    // the code without mappings is not cached
+    let raw = module_source("c();\n", vec![], &["c.js"]);
+    assert!(
+      get(
+        &(ModuleIdentifier::from("c"), RspackHashDigest::from("c1")),
+        raw
+      )
+      .is_none()
+    );
+  }
+
+  #[test]
+  fn test_by_local_index() {
+    let indexes = HashMap::from_iter([(5, 1), (2, 0), (7, 2)]);
+    assert_eq!(by_local_index(indexes), vec![2, 5, 7]);
+  }
+}
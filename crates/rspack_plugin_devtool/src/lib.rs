@@ -1,18 +1,22 @@
 mod eval_dev_tool_module_plugin;
 mod eval_source_map_dev_tool_plugin;
 mod generate_debug_id;
+mod index_source_map;
 mod mapped_assets_cache;
 mod module_filename_helpers;
 mod source_map_dev_tool_module_options_plugin;
 mod source_map_dev_tool_plugin;
+mod source_map_validation;
 
 use std::sync::Arc;
 
 pub use eval_dev_tool_module_plugin::*;
 pub use eval_source_map_dev_tool_plugin::*;
 use futures::future::BoxFuture;
-use rspack_core::ModuleIdentifier;
+pub use index_source_map::SourceMapSections;
+use rspack_core::{Compilation, Module, ModuleIdentifier};
 use rspack_error::Result;
+use rustc_hash::FxHashMap as HashMap;
 pub use source_map_dev_tool_module_options_plugin::*;
 pub use source_map_dev_tool_plugin::*;
 
@@ -39,3 +43,18 @@ enum SourceReference {
   Source(Arc<str>),
   Module(ModuleIdentifier),
 }
+
+/// The modules by their resource paths, to find the module of a source named by its resource
+/// path, which is usually the case for the source maps returned by loaders.
+pub(crate) fn modules_by_resource(compilation: &Compilation) -> HashMap<String, ModuleIdentifier> {
+  let mut modules = HashMap::default();
+  for (identifier, module) in compilation.get_module_graph().modules() {
+    if let Some(path) = module
+      .as_normal_module()
+      .and_then(|module| module.resource_resolved_data().path())
+    {
+      modules.entry(path.to_string()).or_insert(identifier);
+    }
+  }
+  modules
+}
@@ -14,12 +14,12 @@ use regex::Regex;
 use rspack_collections::DatabaseItem;
 use rspack_core::{
   AssetInfo, Chunk, ChunkUkey, Compilation, CompilationAsset, CompilationProcessAssets, Filename,
-  Logger, ModuleIdentifier, PathData, Plugin,
+  Logger, ModuleIdentifier, PathData, Plugin, SourceType,
   rspack_sources::{
     BoxSource, ConcatSource, MapOptions, ObjectPool, RawStringSource, Source, SourceExt, SourceMap,
   },
 };
-use rspack_error::{Result, ToStringResultToRspackResultExt, error};
+use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt, error};
 use rspack_hash::RspackHash;
 use rspack_hook::{plugin, plugin_hook};
 use rspack_paths::{Utf8Path, Utf8PathBuf};
@@ -34,8 +34,15 @@ use sugar_path::SugarPath;
 use thread_local::ThreadLocal;
 
 use crate::{
-  ModuleFilenameTemplateFn, SourceReference, generate_debug_id::generate_debug_id,
-  mapped_assets_cache::MappedAssetsCache, module_filename_helpers::ModuleFilenameHelpers,
+  ModuleFilenameTemplateFn, SourceMapSections, SourceReference,
+  generate_debug_id::generate_debug_id,
+  index_source_map::{
+    ModuleSection, ModuleSections, SectionGrouping, SourceMapSectionsCache, asset_sections,
+    locate_modules, to_index_map_json,
+  },
+  mapped_assets_cache::MappedAssetsCache,
+  module_filename_helpers::ModuleFilenameHelpers,
+  source_map_validation::SourceMapValidator,
 };
 
 static SCHEMA_SOURCE_REGEXP: LazyLock<Regex> =
@@ -95,6 +102,10 @@ pub struct SourceMapDevToolPluginOptions {
   pub include: Option<AssetConditions>,
   pub exclude: Option<AssetConditions>,
   pub debug_ids: bool,
+  // Check the emitted source maps and report the invalid mappings and sources as warnings.
+  pub validate: bool,
+  // Emit index source maps with a section for each module or concatenated module instead of flat source maps.
+  pub sections: Option<SourceMapSections>,
 }
 
 enum SourceMappingUrlComment {
@@ -112,12 +123,16 @@ struct SourceMapTask {
   pub source: BoxSource,
   pub source_map: SourceMap,
   pub source_references: Vec<SourceReference>,
+  pub diagnostics: Vec<Diagnostic>,
+  pub module_sections: Option<ModuleSections>,
+  pub section_groups: Option<Vec<usize>>,
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct MappedAsset {
   pub(crate) asset: (Arc<str>, CompilationAsset),
   pub(crate) source_map: Option<(String, CompilationAsset)>,
+  pub(crate) diagnostics: Vec<Diagnostic>,
 }
 
 #[plugin]
@@ -143,8 +158,11 @@ pub struct SourceMapDevToolPlugin {
   include: Option<AssetConditions>,
   exclude: Option<AssetConditions>,
   debug_ids: bool,
+  validate: bool,
+  sections: Option<SourceMapSections>,
 
   mapped_assets_cache: MappedAssetsCache,
+  sections_cache: SourceMapSectionsCache,
 }
 
 impl SourceMapDevToolPlugin {
@@ -191,7 +209,10 @@ impl SourceMapDevToolPlugin {
       options.include,
       options.exclude,
       options.debug_ids,
+      options.validate,
+      options.sections,
       MappedAssetsCache::new(),
+      SourceMapSectionsCache::default(),
     )
   }
 
@@ -224,9 +245,71 @@ impl SourceMapDevToolPlugin {
     }
   }
 
+  /// Find the modules of the chunk in the code of an asset, so the index source map is built from
+  /// the cached mappings of the modules instead of the source map of the asset.
+  fn locate_module_sections(
+    &self,
+    compilation: &Compilation,
+    chunk: &Chunk,
+    asset_filename: &str,
+    source: &BoxSource,
+  ) -> Option<(SourceMap, ModuleSections)> {
+    let sections = self.sections?;
+    let map_options = MapOptions::new(self.columns);
+    let source_type = if CSS_EXTENSION_DETECT_REGEXP.is_match(asset_filename) {
+      SourceType::Css
+    } else {
+      SourceType::JavaScript
+    };
+    let (code_generation_results, results) = compilation.code_generation_results.inner();
+    let mut modules = compilation
+      .chunk_graph
+      .get_chunk_modules_identifier_by_source_type(
+        &chunk.ukey(),
+        source_type,
+        &compilation.get_module_graph(),
+      )
+      .into_iter()
+      .filter_map(|module| {
+        let result = code_generation_results
+          .get(&module)?
+          .get(chunk.runtime())
+          .and_then(|id| results.get(id))?;
+        Some((module, result.hash.clone()?, result.get(&source_type)?))
+      })
+      .collect::<Vec<_>>();
+    if source_type == SourceType::JavaScript {
+      modules.extend(
+        compilation
+          .chunk_graph
+          .get_chunk_runtime_modules_iterable(&chunk.ukey())
+          .filter_map(|module| {
+            Some((
+              *module,
+              compilation.runtime_modules_hash.get(module)?.clone(),
+              compilation
+                .runtime_modules_code_generation_source
+                .get(module)?,
+            ))
+          }),
+      );
+    }
+
+    let module_sections = modules
+      .into_iter()
+      .filter_map(|(module, hash, module_source)| {
+        self.sections_cache.get_or_insert_with((module, hash), || {
+          ModuleSection::new(module_source, &map_options, sections)
+        })
+      })
+      .collect::<Vec<_>>();
+    locate_modules(&source.source().into_string_lossy(), module_sections)
+  }
+
   async fn collect_tasks(
     &self,
     compilation: &Compilation,
+    file_to_chunk: &HashMap<&str, &Chunk>,
     compilation_assets: Vec<(String, &CompilationAsset)>,
   ) -> Result<Vec<SourceMapTask>> {
     let map_options = MapOptions::new(self.columns);
@@ -252,8 +335,19 @@ impl SourceMapDevToolPlugin {
         }
 
         asset.get_source().and_then(|source| {
-          let object_pool = tls.get_or(ObjectPool::default);
-          let source_map = source.map(object_pool, &map_options)?;
+          let module_sections = file_to_chunk
+            .get(asset_filename.as_str())
+            .and_then(|chunk| {
+              self.locate_module_sections(compilation, chunk, &asset_filename, source)
+            });
+          // the source map of the asset is only generated when the modules can't be found in it
+          let (source_map, module_sections) = match module_sections {
+            Some((source_map, module_sections)) => (source_map, Some(module_sections)),
+            None => {
+              let object_pool = tls.get_or(ObjectPool::default);
+              (source.map(object_pool, &map_options)?, None)
+            }
+          };
 
           let source_references = source_map
             .sources()
@@ -281,6 +375,9 @@ impl SourceMapDevToolPlugin {
             source: source.clone(),
             source_map,
             source_references,
+            diagnostics: vec![],
+            module_sections,
+            section_groups: None,
           }))
         })
       })
@@ -526,33 +623,39 @@ impl SourceMapDevToolPlugin {
           .collect::<Vec<_>>(),
       );
 
-      if let Some(asset_conditions) = &self.ignore_list {
-        let ignore_list = source_map
-          .sources()
-          .iter()
-          .enumerate()
-          .filter_map(|(idx, source)| {
-            if asset_conditions.try_match(source) {
-              Some(idx as u32)
-            } else {
-              None
-            }
-          })
-          .collect::<Vec<_>>();
-        source_map.set_ignore_list(Some(ignore_list));
-      }
-
-      if self.no_sources {
-        source_map.set_sources_content([]);
-      }
-      if let Some(source_root) = &self.source_root {
-        source_map.set_source_root(Some(source_root.clone()));
-      }
+      self.apply_source_map_options(source_map);
     }
 
     Ok(())
   }
 
+  // Apply `ignoreList`, `noSources` and `sourceRoot`, which are applied to the map of each section
+  // again for the index source maps.
+  fn apply_source_map_options(&self, source_map: &mut SourceMap) {
+    if let Some(asset_conditions) = &self.ignore_list {
+      let ignore_list = source_map
+        .sources()
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, source)| {
+          if asset_conditions.try_match(source) {
+            Some(idx as u32)
+          } else {
+            None
+          }
+        })
+        .collect::<Vec<_>>();
+      source_map.set_ignore_list(Some(ignore_list));
+    }
+
+    if self.no_sources {
+      source_map.set_sources_content([]);
+    }
+    if let Some(source_root) = &self.source_root {
+      source_map.set_source_root(Some(source_root.clone()));
+    }
+  }
+
   async fn map_assets(
     &self,
     compilation: &Compilation,
@@ -560,29 +663,81 @@ impl SourceMapDevToolPlugin {
     output_path: &Utf8Path,
     compilation_assets: Vec<(String, &CompilationAsset)>,
   ) -> Result<Vec<MappedAsset>> {
-    let mut tasks = self.collect_tasks(compilation, compilation_assets).await?;
+    let mut tasks = self
+      .collect_tasks(compilation, file_to_chunk, compilation_assets)
+      .await?;
+
+    if self.validate {
+      let validator = SourceMapValidator::new(compilation);
+      tasks.par_iter_mut().for_each(|task| {
+        task.diagnostics = match &task.module_sections {
+          Some(module_sections) => validator.validate(
+            &task.asset_filename,
+            &task.source_map,
+            module_sections.mappings(),
+            &task.source_references,
+          ),
+          None => validator.validate(
+            &task.asset_filename,
+            &task.source_map,
+            task.source_map.decoded_mappings(),
+            &task.source_references,
+          ),
+        };
+      });
+    }
 
     self
       .finalize_source_maps(compilation, file_to_chunk, output_path, &mut tasks)
       .await?;
 
+    if let Some(sections) = self.sections
+      && tasks.iter().any(|task| task.module_sections.is_none())
+    {
+      let grouping = SectionGrouping::new(compilation, sections);
+      for task in tasks
+        .iter_mut()
+        .filter(|task| task.module_sections.is_none())
+      {
+        task.section_groups = Some(grouping.group(compilation, &task.source_references));
+      }
+    }
+
     let mapped_assets = rspack_futures::scope::<_, Result<_>>(|token| {
       tasks.into_iter().for_each(
         |SourceMapTask {
            asset_filename,
            source,
            source_map,
+           diagnostics,
+           module_sections,
+           section_groups,
            ..
          }| {
-          let s = unsafe { token.used((&self, compilation, file_to_chunk, asset_filename, source, source_map)) };
+          let s = unsafe { token.used((&self, compilation, file_to_chunk, asset_filename, source, source_map, diagnostics, module_sections, section_groups)) };
           s.spawn(
-            |(plugin, compilation, file_to_chunk, asset_filename, source, mut source_map)| async move {
+            |(plugin, compilation, file_to_chunk, asset_filename, source, mut source_map, diagnostics, module_sections, section_groups)| async move {
               let debug_id = plugin.debug_ids.then(|| {
                 let debug_id = generate_debug_id(&asset_filename, &source.buffer());
                 source_map.set_debug_id(Some(debug_id.clone()));
                 debug_id
               });
-              let source_map_json = source_map.to_json().map_err(|e| error!(e.to_string()))?;
+              let css_extension_detected = CSS_EXTENSION_DETECT_REGEXP.is_match(&asset_filename);
+              let sections = match (&module_sections, &section_groups) {
+                (Some(module_sections), _) => Some(Cow::Borrowed(module_sections.sections())),
+                (None, Some(groups)) => Some(Cow::Owned(asset_sections(&source_map, groups))),
+                (None, None) => None,
+              };
+              let source_map_json = match sections {
+                Some(sections) => to_index_map_json(
+                  &source_map,
+                  &sections,
+                  &asset_filename,
+                  debug_id.as_deref(),
+                  |section_map| plugin.apply_source_map_options(section_map),
+                )?,
+                None => source_map.to_json().map_err(|e| error!(e.to_string()))?,
+              };
 
               let mut asset = compilation
                 .assets()
@@ -595,7 +750,6 @@ impl SourceMapDevToolPlugin {
                 })
                 .clone();
 
-              let css_extension_detected = CSS_EXTENSION_DETECT_REGEXP.is_match(&asset_filename);
               let current_source_mapping_url_comment = match &plugin.source_mapping_url_comment {
                 Some(SourceMappingUrlComment::String(s)) => {
                   let s = if css_extension_detected {
@@ -713,6 +867,7 @@ impl SourceMapDevToolPlugin {
                 Ok(MappedAsset {
                   asset: (asset_filename, asset),
                   source_map: Some((source_map_filename.to_string(), source_map_asset)),
+                  diagnostics,
                 })
               } else {
                 let current_source_mapping_url_comment = current_source_mapping_url_comment.expect(
@@ -745,6 +900,7 @@ impl SourceMapDevToolPlugin {
                 Ok(MappedAsset {
                   asset: (asset_filename, asset),
                   source_map: None,
+                  diagnostics,
                 })
               }
             },
@@ -809,7 +965,9 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
     let MappedAsset {
       asset: (source_filename, mut source_asset),
       source_map,
+      diagnostics,
     } = mapped_asset;
+    compilation.extend_diagnostics(diagnostics);
     if let Some(asset) = compilation.assets_mut().remove(source_filename.as_ref()) {
       source_asset.info = asset.info;
       if let Some((ref source_map_filename, _)) = source_map {
@@ -828,6 +986,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       }
     }
   }
+  self.sections_cache.retain_used();
   logger.time_end(start);
 
   Ok(())
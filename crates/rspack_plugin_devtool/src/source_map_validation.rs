@@ -0,0 +1,172 @@
+use rspack_core::{
+  Compilation, Module, ModuleIdentifier,
+  rspack_sources::{Mapping, SourceMap},
+};
+use rspack_error::Diagnostic;
+use rspack_paths::Utf8PathBuf;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::{SourceReference, modules_by_resource};
+
+/// The findings of a source in the source map of an asset.
+#[derive(Default)]
+struct SourceFindings {
+  /// The number of the segments mapping into the source.
+  segments: usize,
+  /// The number of the segments pointing past the end of the source, and the first one as
+  /// `(generated line, generated column, original line, original column)`.
+  out_of_range: usize,
+  first_out_of_range: Option<(u32, u32, u32, u32)>,
+}
+
+/// Checks the source maps of the assets for the problems usually caused by the loaders: segments
+/// pointing past the end of a source, sources without `sourcesContent` and segments mapping into
+/// the code generated by the loaders instead of the original source.
+pub(crate) struct SourceMapValidator<'a> {
+  compilation: &'a Compilation,
+  resource_to_module: HashMap<String, ModuleIdentifier>,
+}
+
+impl<'a> SourceMapValidator<'a> {
+  pub(crate) fn new(compilation: &'a Compilation) -> Self {
+    Self {
+      compilation,
+      resource_to_module: modules_by_resource(compilation),
+    }
+  }
+
+  /// It must run before the sources are renamed by the `moduleFilenameTemplate`, since the
+  /// module and the loader chain of a source are found by its original name. The `mappings` are
+  /// passed separately since the source map has none when it's built from the modules.
+  pub(crate) fn validate(
+    &self,
+    asset_filename: &str,
+    source_map: &SourceMap,
+    mappings: impl Iterator<Item = Mapping>,
+    source_references: &[SourceReference],
+  ) -> Vec<Diagnostic> {
+    let sources_content = source_map.sources_content();
+    // the line lengths in UTF-16 code units as the columns of source maps, `None` if the content
+    // is missing
+    let line_lengths = sources_content
+      .iter()
+      .map(|content| {
+        (!content.is_empty()).then(|| {
+          content
+            .split('\n')
+            .map(|line| line.trim_end_matches('\r').encode_utf16().count())
+            .collect::<Vec<_>>()
+        })
+      })
+      .collect::<Vec<_>>();
+
+    let mut findings = source_map
+      .sources()
+      .iter()
+      .map(|_| SourceFindings::default())
+      .collect::<Vec<_>>();
+    for mapping in mappings {
+      let Some(original) = mapping.original else {
+        continue;
+      };
+      let Some(finding) = findings.get_mut(original.source_index as usize) else {
+        continue;
+      };
+      finding.segments += 1;
+      let Some(Some(lines)) = line_lengths.get(original.source_index as usize) else {
+        continue;
+      };
+      // lines are 1-based and columns are 0-based
+      let in_range = original.original_line >= 1
+        && lines
+          .get(original.original_line as usize - 1)
+          .is_some_and(|len| original.original_column as usize <= *len);
+      if !in_range {
+        finding.out_of_range += 1;
+        finding.first_out_of_range.get_or_insert((
+          mapping.generated_line,
+          mapping.generated_column,
+          original.original_line,
+          original.original_column,
+        ));
+      }
+    }
+
+    let context = &self.compilation.options.context;
+    let module_graph = self.compilation.get_module_graph();
+    let mut diagnostics = vec![];
+    for (index, (source_name, finding)) in source_map.sources().iter().zip(&findings).enumerate() {
+      if finding.segments == 0 {
+        continue;
+      }
+      let identifier = match source_references.get(index) {
+        Some(SourceReference::Module(identifier)) => Some(*identifier),
+        _ => {
+          let identifier = ModuleIdentifier::from(source_name.as_str());
+          if module_graph.module_by_identifier(&identifier).is_some() {
+            Some(identifier)
+          } else {
+            self.resource_to_module.get(source_name.as_str()).copied()
+          }
+        }
+      };
+      let readable_name = identifier
+        .and_then(|identifier| module_graph.module_by_identifier(&identifier))
+        .map_or_else(
+          || source_name.to_string(),
+          |module| module.readable_identifier(context).to_string(),
+        );
+      let (loaders, resource) = match readable_name.rsplit_once('!') {
+        Some((loaders, resource)) => (Some(loaders), resource),
+        None => (None, readable_name.as_str()),
+      };
+      let loader_chain = loaders
+        .map(|loaders| {
+          format!(
+            " (loaders: {})",
+            loaders.split('!').collect::<Vec<_>>().join(" -> ")
+          )
+        })
+        .unwrap_or_default();
+
+      let mut messages = vec![];
+      if finding.out_of_range > 0
+        && let Some((generated_line, generated_column, original_line, original_column)) =
+          finding.first_out_of_range
+      {
+        messages.push(format!(
+          "{} of {} segments map past the end of \"{resource}\"{loader_chain}, e.g. {generated_line}:{generated_column} maps to {original_line}:{original_column}, but the source has {} lines.",
+          finding.out_of_range,
+          finding.segments,
+          line_lengths.get(index).and_then(Option::as_ref).map_or(0, Vec::len)
+        ));
+      }
+      if sources_content
+        .get(index)
+        .is_none_or(|content| content.is_empty())
+      {
+        messages.push(format!(
+          "The source \"{resource}\"{loader_chain} has no \"sourcesContent\", the original code can't be shown in the devtools."
+        ));
+      }
+      // the module source is named by its request when the loaders didn't return a source map
+      if loaders.is_some() && identifier.is_some() && source_name.contains('!') {
+        messages.push(format!(
+          "{} segments map into the code generated by the loaders instead of \"{resource}\"{loader_chain}, a loader in the chain didn't return a source map.",
+          finding.segments
+        ));
+      }
+
+      for message in messages {
+        let mut diagnostic = Diagnostic::warn(
+          "SourceMapValidation".into(),
+          format!("Invalid source map of \"{asset_filename}\": {message}"),
+        );
+        diagnostic.module_identifier = identifier;
+        diagnostic.file = Some(Utf8PathBuf::from(asset_filename));
+        diagnostics.push(diagnostic);
+      }
+    }
+    diagnostics
+  }
+}
@@ -41,6 +41,7 @@ pub struct HtmlPluginAssets {
 impl HtmlPluginAssets {
   pub async fn create_assets<'a>(
     config: &HtmlRspackPluginOptions,
+    chunks: Option<&Vec<String>>,
     compilation: &'a Compilation,
     public_path: &str,
     output_path: &Utf8PathBuf,
@@ -52,7 +53,7 @@ impl HtmlPluginAssets {
 
     let sorted_entry_names: Vec<&String> =
       if matches!(config.chunks_sort_mode, HtmlChunkSortMode::Manual)
-        && let Some(chunks) = &config.chunks
+        && let Some(chunks) = chunks
       {
         chunks
           .iter()
@@ -64,7 +65,7 @@ impl HtmlPluginAssets {
           .keys()
           .filter(|&entry_name| {
             let mut included = true;
-            if let Some(included_chunks) = &config.chunks {
+            if let Some(included_chunks) = chunks {
               included = included_chunks.iter().any(|c| c.eq(entry_name));
             }
             if let Some(exclude_chunks) = &config.exclude_chunks {
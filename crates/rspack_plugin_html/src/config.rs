@@ -151,6 +151,8 @@ pub struct HtmlRspackPluginOptions {
   #[serde(skip)]
   pub template_fn: Option<TemplateRenderFn>,
   pub template_content: Option<String>,
+  /// glob or directory of page templates, each generates a html file
+  pub pages: Option<String>,
   #[serde(skip)]
   pub template_parameters: TemplateParameters,
   /// `head`, `body`, `false`
@@ -176,6 +178,9 @@ pub struct HtmlRspackPluginOptions {
   pub favicon: Option<String>,
   pub meta: Option<FxHashMap<String, FxHashMap<String, String>>>,
   pub hash: Option<bool>,
+  /// inline the css rules matching the template in a `<style>` tag,
+  /// and load the stylesheets asynchronously
+  pub critical_css: Option<bool>,
   pub base: Option<HtmlRspackPluginBaseOptions>,
   /// uid is used to identify the plugin instance on javascript side
   pub uid: Option<u32>,
@@ -204,6 +209,7 @@ impl Default for HtmlRspackPluginOptions {
       template: None,
       template_fn: None,
       template_content: None,
+      pages: None,
       template_parameters: TemplateParameters::Map(Default::default()),
       inject: default_inject(),
       public_path: None,
@@ -217,6 +223,7 @@ impl Default for HtmlRspackPluginOptions {
       favicon: None,
       meta: None,
       hash: None,
+      critical_css: None,
       base: None,
       uid: None,
     }
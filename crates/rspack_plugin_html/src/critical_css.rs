@@ -0,0 +1,542 @@
+use cow_utils::CowUtils;
+use rspack_util::{
+  css_scanner::{GROUP_AT_RULES, skip_block, skip_comment, skip_string, split_selectors},
+  fx_hash::{FxHashMap, FxHashSet},
+};
+use swc_html::{
+  ast::{Document, Element},
+  visit::{Visit, VisitWith},
+};
+
+use crate::tag::{HtmlPluginAttribute, HtmlPluginTag};
+
+/// The tag names, ids, classes and attribute names used in the static markup of a document.
+#[derive(Debug, Default)]
+pub struct HtmlSelectorIndex {
+  tags: FxHashSet<String>,
+  ids: FxHashSet<String>,
+  classes: FxHashSet<String>,
+  attributes: FxHashSet<String>,
+}
+
+impl HtmlSelectorIndex {
+  pub fn new(document: &Document) -> Self {
+    let mut index = Self::default();
+    document.visit_with(&mut index);
+    index
+  }
+
+  /// Whether the complex selector may match an element of the document.
+  ///
+  /// The combinators and pseudo-classes are ignored, so a selector matches as long as each of
+  /// its type, class, id and attribute selectors is used somewhere in the document.
+  pub fn may_match(&self, selector: &str) -> bool {
+    let chars = selector.chars().collect::<Vec<_>>();
+    let mut i = 0;
+    while i < chars.len() {
+      match chars[i] {
+        '.' => {
+          let (class, next) = read_ident(&chars, i + 1);
+          if !self.classes.contains(&class) {
+            return false;
+          }
+          i = next;
+        }
+        '#' => {
+          let (id, next) = read_ident(&chars, i + 1);
+          if !self.ids.contains(&id) {
+            return false;
+          }
+          i = next;
+        }
+        '[' => {
+          let end = skip_brackets(&chars, i, '[', ']');
+          let inner_end = if chars.get(end - 1) == Some(&']') {
+            end - 1
+          } else {
+            end
+          };
+          let inner = chars[i + 1..inner_end].iter().collect::<String>();
+          let inner = inner.trim();
+          // strip the namespace prefix of `[ns|attr]`, but not the `|=` operator
+          let inner = match inner.find('|') {
+            Some(p) if !inner[p + 1..].starts_with('=') => &inner[p + 1..],
+            _ => inner,
+          };
+          let name_end = inner
+            .find(|c: char| matches!(c, '=' | '~' | '|' | '^' | '$' | '*') || c.is_whitespace())
+            .unwrap_or(inner.len());
+          let name = inner[..name_end].cow_to_ascii_lowercase();
+          if !name.is_empty() && !self.attributes.contains(name.as_ref()) {
+            return false;
+          }
+          i = end;
+        }
+        ':' => {
+          while chars.get(i) == Some(&':') {
+            i += 1;
+          }
+          let (_, next) = read_ident(&chars, i);
+          i = next;
+          if chars.get(i) == Some(&'(') {
+            i = skip_brackets(&chars, i, '(', ')');
+          }
+        }
+        c if is_ident_start(c) => {
+          let (tag, next) = read_ident(&chars, i);
+          // the namespace prefix of `ns|tag`
+          if chars.get(next) == Some(&'|') && chars.get(next + 1) != Some(&'=') {
+            i = next + 1;
+            continue;
+          }
+          if !self.tags.contains(tag.cow_to_ascii_lowercase().as_ref()) {
+            return false;
+          }
+          i = next;
+        }
+        _ => i += 1,
+      }
+    }
+    true
+  }
+}
+
+impl Visit for HtmlSelectorIndex {
+  fn visit_element(&mut self, n: &Element) {
+    self.tags.insert(n.tag_name.to_string());
+    for attribute in &n.attributes {
+      let name = attribute.name.cow_to_ascii_lowercase();
+      match (name.as_ref(), &attribute.value) {
+        ("id", Some(value)) => {
+          self.ids.insert(value.to_string());
+        }
+        ("class", Some(value)) => {
+          self
+            .classes
+            .extend(value.split_ascii_whitespace().map(str::to_string));
+        }
+        _ => {}
+      }
+      self.attributes.insert(name.into_owned());
+    }
+    n.visit_children_with(self);
+  }
+}
+
+/// Extract the rules of the stylesheet which may match the document.
+///
+/// The style rules are kept when any selector in their selector list may match, and the group
+/// at-rules like `@media` are kept with their critical rules. The other at-rules like
+/// `@font-face` and `@keyframes` are always kept, except `@import` and `@charset`.
+pub fn extract_critical_css(css: &str, index: &HtmlSelectorIndex) -> String {
+  let mut critical = String::new();
+  extract_rules(css, 0, css.len(), index, &mut critical);
+  critical
+}
+
+fn extract_rules(css: &str, start: usize, end: usize, index: &HtmlSelectorIndex, out: &mut String) {
+  let bytes = css.as_bytes();
+  let mut i = start;
+  while i < end {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        i = skip_comment(bytes, i);
+        continue;
+      }
+      b'}' | b';' => {
+        i += 1;
+        continue;
+      }
+      c if c.is_ascii_whitespace() => {
+        i += 1;
+        continue;
+      }
+      _ => {}
+    }
+
+    let prelude_end = find_prelude_end(bytes, i, end);
+    let prelude = css[i..prelude_end].trim();
+    let at_rule_name = prelude.strip_prefix('@').map(|at_rule| {
+      at_rule
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ';')
+        .next()
+        .unwrap_or_default()
+        .cow_to_ascii_lowercase()
+    });
+
+    if bytes.get(prelude_end) != Some(&b'{') {
+      // the statement at-rules, `@layer` and `@namespace` affect the other rules
+      let statement_end = (prelude_end + 1).min(end);
+      if matches!(at_rule_name.as_deref(), Some("layer" | "namespace")) {
+        push_rule(out, &css[i..statement_end]);
+      }
+      i = statement_end;
+      continue;
+    }
+
+    let block_end = skip_block(bytes, prelude_end).min(end);
+    match at_rule_name.as_deref() {
+      Some(name) if GROUP_AT_RULES.contains(&name) => {
+        let inner_end = if bytes[block_end - 1] == b'}' {
+          block_end - 1
+        } else {
+          block_end
+        };
+        let mut inner = String::new();
+        extract_rules(css, prelude_end + 1, inner_end, index, &mut inner);
+        if !inner.is_empty() {
+          push_rule(out, &format!("{prelude}{{{inner}}}"));
+        }
+      }
+      Some(_) => push_rule(out, &css[i..block_end]),
+      None => {
+        if split_selectors(prelude, 0, prelude.len())
+          .into_iter()
+          .any(|selector| index.may_match(&prelude[selector]))
+        {
+          push_rule(out, &css[i..block_end]);
+        }
+      }
+    }
+    i = block_end;
+  }
+}
+
+fn push_rule(out: &mut String, rule: &str) {
+  if !out.is_empty() {
+    out.push('\n');
+  }
+  out.push_str(rule.trim());
+}
+
+/// Rebase the relative urls of the stylesheet at `href`, so they can be used in the document
+/// which links the stylesheet.
+pub fn rebase_urls(css: &str, href: &str) -> String {
+  let href = href.split(['?', '#']).next().unwrap_or_default();
+  let Some(base) = href.rfind('/').map(|index| &href[..=index]) else {
+    return css.to_string();
+  };
+
+  let lowercase = css.cow_to_ascii_lowercase();
+  let mut rebased = String::with_capacity(css.len());
+  let mut last = 0;
+  let mut search = 0;
+  while let Some(offset) = lowercase[search..].find("url(") {
+    let start = search + offset + 4;
+    search = start;
+    let rest = &css[start..];
+    let value_start = start + (rest.len() - rest.trim_start().len());
+    let quote = css[value_start..]
+      .chars()
+      .next()
+      .filter(|c| *c == '"' || *c == '\'');
+    let url_start = value_start + quote.map_or(0, char::len_utf8);
+    let url_end = match quote {
+      Some(quote) => css[url_start..].find(quote),
+      None => css[url_start..].find(|c: char| c == ')' || c.is_whitespace()),
+    }
+    .map_or(css.len(), |len| url_start + len);
+    let url = &css[url_start..url_end];
+    if is_relative_url(url) {
+      rebased.push_str(&css[last..url_start]);
+      rebased.push_str(base);
+      rebased.push_str(url);
+      last = url_start + url.len();
+    }
+  }
+  rebased.push_str(&css[last..]);
+  rebased
+}
+
+fn is_relative_url(url: &str) -> bool {
+  let has_scheme = url.split_once(':').is_some_and(|(scheme, _)| {
+    scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+      && scheme
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+  });
+  !url.is_empty() && !url.starts_with(['/', '#']) && !has_scheme
+}
+
+/// Inline the critical css of the stylesheets in a `<style>` tag, and load the stylesheets
+/// asynchronously with `<link rel="preload">`, which is swapped to a stylesheet once loaded.
+/// The original `<link>` is kept in a `<noscript>` for the browsers without javascript.
+///
+/// `stylesheets` is the content of the stylesheets by their urls, the stylesheets without
+/// critical css are kept as they are.
+pub fn inline_critical_css(
+  tags: &mut Vec<HtmlPluginTag>,
+  stylesheets: &FxHashMap<String, String>,
+  index: &HtmlSelectorIndex,
+) {
+  let mut critical = String::new();
+  let mut style_position = None;
+  let mut fallbacks = vec![];
+  for (position, tag) in tags.iter_mut().enumerate() {
+    if tag.tag_name != "link" || !is_stylesheet(tag) {
+      continue;
+    }
+    let Some(href) = tag.asset.as_ref() else {
+      continue;
+    };
+    let Some(css) = stylesheets.get(href) else {
+      continue;
+    };
+    let css = extract_critical_css(css, index);
+    if css.is_empty() {
+      continue;
+    }
+    push_rule(&mut critical, &rebase_urls(&css, href));
+    style_position.get_or_insert(position);
+    fallbacks.push((
+      position,
+      HtmlPluginTag {
+        tag_name: "noscript".to_string(),
+        inner_html: Some(tag.to_string()),
+        ..Default::default()
+      },
+    ));
+
+    for attribute in &mut tag.attributes {
+      if attribute.attr_name == "rel" {
+        attribute.attr_value = Some("preload".to_string());
+      }
+    }
+    tag.attributes.push(HtmlPluginAttribute {
+      attr_name: "as".to_string(),
+      attr_value: Some("style".to_string()),
+    });
+    tag.attributes.push(HtmlPluginAttribute {
+      attr_name: "onload".to_string(),
+      attr_value: Some("this.onload=null;this.rel='stylesheet'".to_string()),
+    });
+  }
+
+  // insert from the end so the positions of the links are not shifted
+  for (position, fallback) in fallbacks.into_iter().rev() {
+    tags.insert(position + 1, fallback);
+  }
+  if let Some(position) = style_position {
+    tags.insert(
+      position,
+      HtmlPluginTag {
+        tag_name: "style".to_string(),
+        inner_html: Some(critical),
+        ..Default::default()
+      },
+    );
+  }
+}
+
+fn is_stylesheet(tag: &HtmlPluginTag) -> bool {
+  tag.attributes.iter().any(|attribute| {
+    attribute.attr_name == "rel" && attribute.attr_value.as_deref() == Some("stylesheet")
+  })
+}
+
+fn is_ident_start(c: char) -> bool {
+  c.is_ascii_alphabetic() || c == '_' || c == '-' || c == '\\' || !c.is_ascii()
+}
+
+/// Read the identifier starting at `start` with the escapes decoded, return it and the index
+/// after it.
+fn read_ident(chars: &[char], start: usize) -> (String, usize) {
+  let mut ident = String::new();
+  let mut i = start;
+  while i < chars.len() {
+    let c = chars[i];
+    if c == '\\' {
+      let hex_end = (i + 1..chars.len().min(i + 7))
+        .find(|j| !chars[*j].is_ascii_hexdigit())
+        .unwrap_or(chars.len().min(i + 7));
+      if hex_end > i + 1 {
+        let hex = chars[i + 1..hex_end].iter().collect::<String>();
+        if let Some(c) = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+          ident.push(c);
+        }
+        i = hex_end;
+        if chars.get(i).is_some_and(|c| c.is_whitespace()) {
+          i += 1;
+        }
+      } else if let Some(c) = chars.get(i + 1) {
+        ident.push(*c);
+        i += 2;
+      } else {
+        i += 1;
+      }
+    } else if c.is_ascii_alphanumeric() || c == '_' || c == '-' || !c.is_ascii() {
+      ident.push(c);
+      i += 1;
+    } else {
+      break;
+    }
+  }
+  (ident, i)
+}
+
+/// Return the index after the brackets starting at `start`.
+fn skip_brackets(chars: &[char], start: usize, open: char, close: char) -> usize {
+  let mut depth = 0usize;
+  let mut quote = None;
+  let mut i = start;
+  while i < chars.len() {
+    let c = chars[i];
+    match quote {
+      Some(q) if c == q => quote = None,
+      Some(_) if c == '\\' => i += 1,
+      Some(_) => {}
+      None if c == '"' || c == '\'' => quote = Some(c),
+      None if c == '\\' => i += 1,
+      None if c == open => depth += 1,
+      None if c == close => {
+        depth -= 1;
+        if depth == 0 {
+          return i + 1;
+        }
+      }
+      None => {}
+    }
+    i += 1;
+  }
+  chars.len()
+}
+
+/// Return the index of the `{` or `;` ending the prelude starting at `start`, or `end`.
+fn find_prelude_end(bytes: &[u8], start: usize, end: usize) -> usize {
+  let mut depth = 0usize;
+  let mut i = start;
+  while i < end {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        i = skip_comment(bytes, i);
+        continue;
+      }
+      b'"' | b'\'' => {
+        i = skip_string(bytes, i);
+        continue;
+      }
+      b'\\' => i += 1,
+      b'(' | b'[' => depth += 1,
+      b')' | b']' => depth = depth.saturating_sub(1),
+      b'{' | b';' if depth == 0 => return i,
+      b'}' => return i,
+      _ => {}
+    }
+    i += 1;
+  }
+  end
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn index() -> HtmlSelectorIndex {
+    let set = |items: &[&str]| items.iter().map(|item| item.to_string()).collect();
+    HtmlSelectorIndex {
+      tags: set(&["html", "head", "body", "header", "h1"]),
+      ids: set(&["top"]),
+      classes: set(&["hero", "hero--dark"]),
+      attributes: set(&["class", "id", "data-title"]),
+    }
+  }
+
+  #[test]
+  fn test_may_match() {
+    let index = index();
+    for selector in [
+      "body",
+      "H1",
+      ".hero",
+      r".hero\-\-dark",
+      "#top:hover",
+      ".hero--dark h1[data-title]",
+      r#"[data-title="a,b"]"#,
+      "header:not(.modal)",
+      "h1::before",
+      "*|h1",
+      "header > h1 + *",
+    ] {
+      assert!(index.may_match(selector), "{selector} should match");
+    }
+    for selector in [
+      "div",
+      ".modal",
+      "#nav",
+      "h1[data-x]",
+      ".hero .modal",
+      "header.hero.sidebar",
+    ] {
+      assert!(!index.may_match(selector), "{selector} should not match");
+    }
+  }
+
+  #[test]
+  fn test_extract_critical_css() {
+    let css = r#"@charset "utf-8";
+@import url(a.css);
+@layer base;
+@font-face { font-family: x; }
+.hero { color: red; }
+.modal { display: none; }
+@media (min-width: 800px) { .hero { height: 1px; } .modal { width: 1px; } }
+@media print { .modal { display: none; } }
+/* .hero { } */
+.footer, #top { padding: 0; }
+"#;
+    assert_eq!(
+      extract_critical_css(css, &index()),
+      r#"@layer base;
+@font-face { font-family: x; }
+.hero { color: red; }
+@media (min-width: 800px){.hero { height: 1px; }}
+.footer, #top { padding: 0; }"#
+    );
+    assert_eq!(extract_critical_css(".modal { color: red; }", &index()), "");
+  }
+
+  #[test]
+  fn test_rebase_urls() {
+    let css = r#".a { background: url(img/a.png); }
+.b { background: URL( '../b.png' ); }
+.c { background: url("/c.png"); }
+.d { background: url(data:image/png;base64,AA); }
+.e { background: url(https://example.com/e.png); }
+.f { mask: url(#mask); }"#;
+    assert_eq!(
+      rebase_urls(css, "/assets/css/main.css?v=1"),
+      r#".a { background: url(/assets/css/img/a.png); }
+.b { background: URL( '/assets/css/../b.png' ); }
+.c { background: url("/c.png"); }
+.d { background: url(data:image/png;base64,AA); }
+.e { background: url(https://example.com/e.png); }
+.f { mask: url(#mask); }"#
+    );
+    // the stylesheet is at the same directory as the document
+    assert_eq!(rebase_urls(css, "main.css"), css);
+  }
+
+  #[test]
+  fn test_inline_critical_css() {
+    let mut tags = vec![
+      HtmlPluginTag::create_style("/a.css"),
+      HtmlPluginTag::create_style("/b.css"),
+    ];
+    let stylesheets = FxHashMap::from_iter([
+      ("/a.css".to_string(), ".hero { color: red; }".to_string()),
+      ("/b.css".to_string(), ".modal { color: red; }".to_string()),
+    ]);
+    inline_critical_css(&mut tags, &stylesheets, &index());
+
+    let tags = tags.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(
+      tags,
+      vec![
+        "<style >.hero { color: red; }</style>",
+        r#"<link as="style" href="/a.css" onload="this.onload=null;this.rel='stylesheet'" rel="preload"/>"#,
+        r#"<noscript ><link href="/a.css" rel="stylesheet"/></noscript>"#,
+        r#"<link href="/b.css" rel="stylesheet"/>"#,
+      ]
+    );
+  }
+}
@@ -1,6 +1,8 @@
 pub mod asset;
 pub mod config;
+pub mod critical_css;
 pub mod injector;
+pub mod pages;
 pub mod parser;
 pub mod sri;
 pub mod tag;
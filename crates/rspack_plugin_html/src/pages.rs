@@ -0,0 +1,277 @@
+use cow_utils::CowUtils;
+use rspack_core::Compilation;
+use rspack_error::{Result, error};
+use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
+
+/// The extensions of the templates found in a pages directory.
+const PAGE_TEMPLATE_EXTENSIONS: &[&str] = &["html", "htm", "ejs"];
+
+/// A page generated from a template matched by the `pages` option.
+#[derive(Debug, Clone)]
+pub struct HtmlPage {
+  /// The path of the template relative to the context.
+  pub template: String,
+  /// The resolved path of the template.
+  pub resolved_template: Utf8PathBuf,
+  /// The template content with the front matter removed.
+  pub content: String,
+  /// The emitted file name.
+  pub filename: String,
+  /// The entry chunks included in the page.
+  pub chunks: Vec<String>,
+}
+
+/// The templates found by the `pages` option and the directory watched for new templates.
+#[derive(Debug)]
+pub struct HtmlPages {
+  pub pages: Vec<HtmlPage>,
+  pub context: Utf8PathBuf,
+}
+
+/// Find the page templates matched by `pattern`, which is a glob or a directory relative to
+/// the context.
+///
+/// A page is named by the path of its template relative to the directory without the extension,
+/// e.g. `src/pages/blog/index.html` is named `blog/index` for the `src/pages` pattern. The page
+/// includes the entry with the same name and is emitted as `[name].html`, these can be changed by
+/// `entry`, `chunks` and `filename` in the front matter of the template:
+///
+/// ```html
+/// ---
+/// entry: blog
+/// filename: blog.html
+/// ---
+/// <!DOCTYPE html>
+/// ```
+pub async fn find_pages(pattern: &str, compilation: &Compilation) -> Result<HtmlPages> {
+  let context = compilation.options.context.as_path();
+  let pattern = pattern.trim_start_matches("./");
+  let is_glob = pattern.contains(['*', '?', '[', '{']);
+  // the leading segments without glob characters
+  let base = if is_glob {
+    pattern
+      .split('/')
+      .take_while(|segment| !segment.contains(['*', '?', '[', '{']))
+      .collect::<Vec<_>>()
+      .join("/")
+  } else {
+    pattern.trim_end_matches('/').to_string()
+  };
+  let pages_context = path_clean::clean(context.join(&base)).assert_utf8();
+
+  let mut templates = vec![];
+  collect_files(compilation, &pages_context, &mut templates).await?;
+  templates.sort();
+
+  let mut pages = vec![];
+  for resolved_template in templates {
+    let Ok(relative) = resolved_template.strip_prefix(&pages_context) else {
+      continue;
+    };
+    let relative = relative.as_str().cow_replace('\\', "/").into_owned();
+    let matched = if is_glob {
+      let template = if base.is_empty() {
+        relative.clone()
+      } else {
+        format!("{base}/{relative}")
+      };
+      fast_glob::glob_match(pattern, &template)
+    } else {
+      resolved_template
+        .extension()
+        .is_some_and(|extension| PAGE_TEMPLATE_EXTENSIONS.contains(&extension))
+    };
+    if !matched {
+      continue;
+    }
+
+    let source = compilation
+      .input_filesystem
+      .read_to_string(&resolved_template)
+      .await
+      .map_err(|err| {
+        error!("HtmlRspackPlugin: could not load page template `{resolved_template}`: {err}")
+      })?;
+    let (front_matter, content) = split_front_matter(&source);
+
+    let name = relative
+      .rsplit_once('.')
+      .map_or(relative.as_str(), |(name, _)| name)
+      .to_string();
+    let mut entry = name.clone();
+    let mut chunks = None;
+    let mut filename = format!("{name}.html");
+    for (key, value) in front_matter {
+      match key {
+        "entry" => entry = value.to_string(),
+        "filename" => filename = value.to_string(),
+        "chunks" => chunks = Some(parse_list(value)),
+        _ => {}
+      }
+    }
+
+    let template = resolved_template
+      .strip_prefix(context)
+      .map_or_else(|_| resolved_template.to_string(), |path| path.to_string());
+    pages.push(HtmlPage {
+      template,
+      resolved_template,
+      content: content.to_string(),
+      filename,
+      chunks: chunks.unwrap_or_else(|| vec![entry]),
+    });
+  }
+
+  Ok(HtmlPages {
+    pages,
+    context: pages_context,
+  })
+}
+
+/// Collect the files in `dir` recursively, `node_modules` and hidden directories are skipped.
+async fn collect_files(
+  compilation: &Compilation,
+  dir: &Utf8Path,
+  files: &mut Vec<Utf8PathBuf>,
+) -> Result<()> {
+  let mut dirs = vec![dir.to_path_buf()];
+  while let Some(dir) = dirs.pop() {
+    let Ok(entries) = compilation.input_filesystem.read_dir(&dir).await else {
+      continue;
+    };
+    for entry in entries {
+      if entry == "node_modules" || entry.starts_with('.') {
+        continue;
+      }
+      let path = dir.join(&entry);
+      let Ok(metadata) = compilation.input_filesystem.metadata(&path).await else {
+        continue;
+      };
+      if metadata.is_directory {
+        dirs.push(path);
+      } else if metadata.is_file {
+        files.push(path);
+      }
+    }
+  }
+  Ok(())
+}
+
+/// Split the `---` delimited front matter of `key: value` lines from the template.
+fn split_front_matter(source: &str) -> (Vec<(&str, &str)>, &str) {
+  let source_without_bom = source.trim_start_matches('\u{feff}');
+  let Some(rest) = source_without_bom
+    .strip_prefix("---\n")
+    .or_else(|| source_without_bom.strip_prefix("---\r\n"))
+  else {
+    return (vec![], source);
+  };
+  let mut offset = 0;
+  for line in rest.split_inclusive('\n') {
+    if line.trim_end() == "---" {
+      let front_matter = rest[..offset]
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, value)| (key.trim(), unquote(value.trim())))
+        .collect();
+      return (front_matter, &rest[offset + line.len()..]);
+    }
+    offset += line.len();
+  }
+  (vec![], source)
+}
+
+/// Parse `a, b` or `[a, b]` into a list, the commas in the quoted items don't split them.
+fn parse_list(value: &str) -> Vec<String> {
+  let value = value.trim();
+  let value = value
+    .strip_prefix('[')
+    .and_then(|value| value.strip_suffix(']'))
+    .unwrap_or(value);
+  let mut items = vec![];
+  let mut quote = None;
+  let mut start = 0;
+  for (index, c) in value.char_indices() {
+    match c {
+      '"' | '\'' if quote.is_none() && value[start..index].trim().is_empty() => quote = Some(c),
+      _ if quote == Some(c) => quote = None,
+      ',' if quote.is_none() => {
+        items.push(&value[start..index]);
+        start = index + 1;
+      }
+      _ => {}
+    }
+  }
+  items.push(&value[start..]);
+  items
+    .into_iter()
+    .map(|item| unquote(item.trim()).to_string())
+    .filter(|item| !item.is_empty())
+    .collect()
+}
+
+fn unquote(value: &str) -> &str {
+  value
+    .strip_prefix('"')
+    .and_then(|value| value.strip_suffix('"'))
+    .or_else(|| {
+      value
+        .strip_prefix('\'')
+        .and_then(|value| value.strip_suffix('\''))
+    })
+    .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_split_front_matter() {
+    assert_eq!(
+      split_front_matter("---\nentry: main\nfilename: \"home.html\"\ntitle: a: b\n---\n<html>"),
+      (
+        vec![
+          ("entry", "main"),
+          ("filename", "home.html"),
+          ("title", "a: b")
+        ],
+        "<html>"
+      )
+    );
+    assert_eq!(
+      split_front_matter("\u{feff}---\nentry: main\n---"),
+      (vec![("entry", "main")], "")
+    );
+    assert_eq!(split_front_matter("<html>"), (vec![], "<html>"));
+  }
+
+  #[test]
+  fn test_split_front_matter_without_closing() {
+    let source = "---\nentry: main\n<html>";
+    assert_eq!(split_front_matter(source), (vec![], source));
+  }
+
+  #[test]
+  fn test_split_front_matter_crlf() {
+    assert_eq!(
+      split_front_matter("---\r\nentry: main\r\nchunks: [a, b]\r\n---\r\n<html>\r\n"),
+      (vec![("entry", "main"), ("chunks", "[a, b]")], "<html>\r\n")
+    );
+  }
+
+  #[test]
+  fn test_parse_list() {
+    assert_eq!(parse_list("a, b"), vec!["a", "b"]);
+    assert_eq!(parse_list("[main]"), vec!["main"]);
+    assert_eq!(parse_list("it's, b"), vec!["it's", "b"]);
+    assert_eq!(parse_list("[a,, b]"), vec!["a", "b"]);
+    assert!(parse_list("").is_empty());
+    assert!(parse_list("[]").is_empty());
+    assert!(parse_list("[ ]").is_empty());
+    assert_eq!(
+      parse_list(r#"["a", 'b', c, "d, e"]"#),
+      vec!["a", "b", "c", "d, e"]
+    );
+  }
+}
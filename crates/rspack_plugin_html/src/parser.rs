@@ -54,6 +54,8 @@ impl<'a> HtmlCompiler<'a> {
       minify,
       quotes: Some(true),
       tag_omission: Some(false),
+      // keep the markup of the injected `<noscript>` tags, e.g. the critical css fallbacks
+      scripting_enabled: true,
       ..Default::default()
     };
     if minify {
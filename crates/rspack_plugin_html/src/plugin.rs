@@ -11,7 +11,7 @@ use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 #[cfg(allocative)]
 use rspack_util::allocative;
-use rspack_util::fx_hash::FxDashMap;
+use rspack_util::fx_hash::{FxDashMap, FxHashMap};
 use sugar_path::SugarPath;
 use swc_html::visit::VisitMutWith;
 
@@ -20,7 +20,9 @@ use crate::{
   BeforeAssetTagGenerationData, BeforeEmitData, HtmlPluginHooks,
   asset::{HtmlPluginAssetTags, HtmlPluginAssets, create_favicon_asset, create_html_asset},
   config::{HtmlInject, HtmlRspackPluginOptions},
+  critical_css::{HtmlSelectorIndex, inline_critical_css},
   injector::AssetInjector,
+  pages::{HtmlPage, find_pages},
   parser::HtmlCompiler,
   template::HtmlTemplate,
 };
@@ -64,12 +66,16 @@ async fn generate_html(
   filename: &str,
   html_file_name: &Filename,
   config: &HtmlRspackPluginOptions,
+  page: Option<&HtmlPage>,
   compilation: &mut Compilation,
   hooks: ArcHtmlPluginHooks,
 ) -> Result<(String, String, Vec<PathBuf>)> {
   let public_path = config.get_public_path(compilation, filename).await;
 
-  let mut template = HtmlTemplate::new(config, compilation).await?;
+  let mut template = match page {
+    Some(page) => HtmlTemplate::from_page(page),
+    None => HtmlTemplate::new(config, compilation).await?,
+  };
 
   let template_file_name = compilation
     .options
@@ -79,6 +85,7 @@ async fn generate_html(
 
   let assets_info = HtmlPluginAssets::create_assets(
     config,
+    page.map_or(config.chunks.as_ref(), |page| Some(&page.chunks)),
     compilation,
     &public_path,
     &template_file_name,
@@ -100,6 +107,24 @@ async fn generate_html(
   let asset_tags: HtmlPluginAssetTags =
     HtmlPluginAssetTags::from_assets(config, &before_generation_data.assets, &assets_info.1);
 
+  // the content of the initial stylesheets, used to extract the critical css
+  let stylesheets: FxHashMap<String, String> = if config.critical_css.unwrap_or_default() {
+    before_generation_data
+      .assets
+      .css
+      .iter()
+      .filter_map(|href| {
+        let source = assets_info.1.get(href)?.get_source()?;
+        Some((
+          href.clone(),
+          source.source().into_string_lossy().into_owned(),
+        ))
+      })
+      .collect()
+  } else {
+    Default::default()
+  };
+
   let alter_asset_tags_data = hooks
     .borrow()
     .alter_asset_tags
@@ -172,6 +197,20 @@ async fn generate_html(
   }
 
   if !matches!(config.inject, HtmlInject::False) {
+    if !stylesheets.is_empty() {
+      let index = HtmlSelectorIndex::new(&current_ast);
+      inline_critical_css(
+        &mut after_template_execution_data.head_tags,
+        &stylesheets,
+        &index,
+      );
+      inline_critical_css(
+        &mut after_template_execution_data.body_tags,
+        &stylesheets,
+        &index,
+      );
+    }
+
     let mut visitor = AssetInjector::new(
       &after_template_execution_data.head_tags,
       &after_template_execution_data.body_tags,
@@ -200,8 +239,41 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   let config: &HtmlRspackPluginOptions = &self.config;
   let hooks = HtmlRspackPlugin::get_compilation_hooks(compilation.id());
 
+  // each page template generates a html file, otherwise the template generates all the filenames
+  let pages = if let Some(pattern) = &config.pages {
+    let pages = find_pages(pattern, compilation).await?;
+    // watch the directory for the added or removed templates
+    compilation
+      .context_dependencies
+      .insert(pages.context.into_std_path_buf().into());
+    for page in &pages.pages {
+      for chunk in &page.chunks {
+        if !compilation.entrypoints.contains_key(chunk) {
+          compilation.push_diagnostic(Diagnostic::warn(
+            "HtmlRspackPlugin".into(),
+            format!(
+              "The page template `{}` includes the entry `{chunk}`, which does not exist.",
+              page.template
+            ),
+          ));
+        }
+      }
+    }
+    pages
+      .pages
+      .into_iter()
+      .map(|page| (page.filename.clone(), Some(page)))
+      .collect::<Vec<_>>()
+  } else {
+    config
+      .filename
+      .iter()
+      .map(|filename| (filename.clone(), None))
+      .collect::<Vec<_>>()
+  };
+
   // TODO: parallel generate html
-  for filename in &config.filename {
+  for (filename, page) in &pages {
     let filename = filename.cow_replace("[templatehash]", "[contenthash]");
 
     // convert absolute filename into relative so that webpack can
@@ -227,6 +299,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       filename.as_ref(),
       &output_file_name,
       config,
+      page.as_ref(),
       compilation,
       hooks.clone(),
     )
@@ -11,6 +11,7 @@ use serde_json::Value;
 use crate::{
   asset::HtmlPluginAssets,
   config::{HtmlRspackPluginOptions, TemplateParameters},
+  pages::HtmlPage,
   tag::HtmlPluginTag,
 };
 
@@ -111,6 +112,16 @@ impl HtmlTemplate {
     }
   }
 
+  pub fn from_page(page: &HtmlPage) -> Self {
+    Self {
+      render: TemplateRender::Template(page.content.clone()),
+      url: page.resolved_template.to_string(),
+      filename: page.template.clone(),
+      file_dependencies: vec![page.resolved_template.clone().into_std_path_buf()],
+      parameters: None,
+    }
+  }
+
   pub async fn create_parameters(
     &mut self,
     filename: &str,
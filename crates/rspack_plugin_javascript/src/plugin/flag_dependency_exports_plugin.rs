@@ -201,14 +201,27 @@ async fn finish_modules(
       modules.len(),
       compilation.get_module_graph().modules().len()
     ));
+    compilation.incremental.record_pass(
+      IncrementalPasses::PROVIDED_EXPORTS,
+      true,
+      modules.len(),
+      compilation.get_module_graph().modules().len(),
+    );
     modules
   } else {
-    compilation
+    let modules: IdentifierSet = compilation
       .get_module_graph()
       .modules()
       .keys()
       .copied()
-      .collect()
+      .collect();
+    compilation.incremental.record_pass(
+      IncrementalPasses::PROVIDED_EXPORTS,
+      false,
+      modules.len(),
+      modules.len(),
+    );
+    modules
   };
   let module_graph_cache = compilation.module_graph_cache_artifact.clone();
 
@@ -79,6 +79,23 @@ async fn finish_modules(
     ));
   }
 
+  let total = compilation.get_module_graph().modules().len();
+  let updated = if compilation
+    .incremental
+    .mutations_readable(IncrementalPasses::INFER_ASYNC_MODULES)
+    && let Some(mutations) = &mutations
+  {
+    Some(mutations.len())
+  } else {
+    None
+  };
+  compilation.incremental.record_pass(
+    IncrementalPasses::INFER_ASYNC_MODULES,
+    updated.is_some(),
+    updated.unwrap_or(total),
+    total,
+  );
+
   if let Some(mut compilation_mutations) = compilation.incremental.mutations_write()
     && let Some(mutations) = mutations
   {
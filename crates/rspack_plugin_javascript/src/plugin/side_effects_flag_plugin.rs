@@ -238,9 +238,21 @@ async fn optimize_dependencies(
       modules.len(),
       all_modules.len()
     ));
+    compilation.incremental.record_pass(
+      IncrementalPasses::SIDE_EFFECTS,
+      true,
+      modules.len(),
+      all_modules.len(),
+    );
 
     modules
   } else {
+    compilation.incremental.record_pass(
+      IncrementalPasses::SIDE_EFFECTS,
+      false,
+      all_modules.len(),
+      all_modules.len(),
+    );
     all_modules.keys().copied().collect()
   };
   logger.time_end(inner_start);
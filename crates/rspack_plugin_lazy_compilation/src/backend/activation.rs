@@ -0,0 +1,174 @@
+use std::time::{Duration, Instant};
+
+use rspack_collections::{IdentifierMap, IdentifierSet};
+use rspack_core::ModuleIdentifier;
+
+use super::ActivationEvent;
+
+#[derive(Debug, Default)]
+struct ModuleState {
+  /// The number of the open requests for the module.
+  requests: usize,
+  /// When the last request for the module was closed.
+  idle_since: Option<Instant>,
+}
+
+/// `ActivationTracker` counts the open requests of each module, and turns them into
+/// activation events for a [`super::Backend`].
+///
+/// A module is activated by its first request, and deactivated once it has no open
+/// request for longer than the keep-alive duration.
+#[derive(Debug)]
+pub struct ActivationTracker {
+  keep_alive: Option<Duration>,
+  modules: IdentifierMap<ModuleState>,
+  events: Vec<ActivationEvent>,
+}
+
+impl ActivationTracker {
+  /// Create a tracker, the modules are never deactivated if `keep_alive` is `None`.
+  pub fn new(keep_alive: Option<Duration>) -> Self {
+    Self {
+      keep_alive,
+      modules: Default::default(),
+      events: vec![],
+    }
+  }
+
+  /// Record a request for the module, return `true` if the module is activated by it.
+  pub fn request(&mut self, module: ModuleIdentifier) -> bool {
+    let state = self.modules.entry(module).or_default();
+    let activated = state.requests == 0 && state.idle_since.is_none();
+    state.requests += 1;
+    state.idle_since = None;
+    if activated {
+      self.events.push(ActivationEvent::Activate(module));
+    }
+    activated
+  }
+
+  /// Record that a request for the module is closed.
+  pub fn release(&mut self, module: ModuleIdentifier, now: Instant) {
+    if let Some(state) = self.modules.get_mut(&module)
+      && state.requests > 0
+    {
+      state.requests -= 1;
+      if state.requests == 0 {
+        state.idle_since = Some(now);
+      }
+    }
+  }
+
+  /// The active modules, an expired module stays active until its deactivation is taken.
+  pub fn active_modules(&self) -> IdentifierSet {
+    self.modules.keys().copied().collect()
+  }
+
+  /// Whether any module is idle for longer than the keep-alive duration.
+  pub fn has_expired(&self, now: Instant) -> bool {
+    self
+      .modules
+      .values()
+      .any(|state| self.is_expired(state, now))
+  }
+
+  /// Take the pending events, including the deactivation of the expired modules.
+  pub fn take_events(&mut self, now: Instant) -> Vec<ActivationEvent> {
+    let expired = self
+      .modules
+      .iter()
+      .filter(|(_, state)| self.is_expired(state, now))
+      .map(|(module, _)| *module)
+      .collect::<Vec<_>>();
+    for module in expired {
+      self.modules.remove(&module);
+      self.events.push(ActivationEvent::Deactivate(module));
+    }
+    std::mem::take(&mut self.events)
+  }
+
+  fn is_expired(&self, state: &ModuleState, now: Instant) -> bool {
+    match (self.keep_alive, state.idle_since) {
+      (Some(keep_alive), Some(idle_since)) => {
+        now.saturating_duration_since(idle_since) >= keep_alive
+      }
+      _ => false,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_activate_once() {
+    let mut tracker = ActivationTracker::new(None);
+    let module = ModuleIdentifier::from("lazy-compilation-proxy|./a.js");
+
+    assert!(tracker.request(module));
+    assert!(!tracker.request(module));
+    tracker.release(module, Instant::now());
+
+    assert_eq!(
+      tracker.take_events(Instant::now()),
+      vec![ActivationEvent::Activate(module)]
+    );
+    // never deactivated without keep-alive
+    assert!(
+      tracker
+        .take_events(Instant::now() + Duration::from_secs(3600))
+        .is_empty()
+    );
+  }
+
+  #[test]
+  fn test_keep_alive_expiry() {
+    let mut tracker = ActivationTracker::new(Some(Duration::from_secs(10)));
+    let module = ModuleIdentifier::from("lazy-compilation-proxy|./a.js");
+    let now = Instant::now();
+
+    tracker.request(module);
+    tracker.release(module, now);
+    assert_eq!(
+      tracker.take_events(now + Duration::from_secs(5)),
+      vec![ActivationEvent::Activate(module)]
+    );
+
+    // requested again before it expires, it stays active
+    assert!(!tracker.request(module));
+    tracker.release(module, now + Duration::from_secs(6));
+    assert!(!tracker.has_expired(now + Duration::from_secs(12)));
+    assert!(tracker.has_expired(now + Duration::from_secs(16)));
+
+    assert_eq!(
+      tracker.take_events(now + Duration::from_secs(16)),
+      vec![ActivationEvent::Deactivate(module)]
+    );
+    assert!(tracker.request(module));
+  }
+
+  #[test]
+  fn test_active_modules() {
+    let mut tracker = ActivationTracker::new(Some(Duration::from_secs(10)));
+    let a = ModuleIdentifier::from("lazy-compilation-proxy|./a.js");
+    let b = ModuleIdentifier::from("lazy-compilation-proxy|./b.js");
+    let now = Instant::now();
+
+    tracker.request(a);
+    tracker.request(b);
+    tracker.release(b, now);
+    // reading the active modules doesn't consume the events
+    assert_eq!(tracker.active_modules(), IdentifierSet::from_iter([a, b]));
+    assert_eq!(tracker.active_modules(), IdentifierSet::from_iter([a, b]));
+    assert_eq!(
+      tracker.take_events(now + Duration::from_secs(10)),
+      vec![
+        ActivationEvent::Activate(a),
+        ActivationEvent::Activate(b),
+        ActivationEvent::Deactivate(b)
+      ]
+    );
+    assert_eq!(tracker.active_modules(), IdentifierSet::from_iter([a]));
+  }
+}
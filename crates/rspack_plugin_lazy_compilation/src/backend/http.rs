@@ -0,0 +1,342 @@
+use std::{
+  net::SocketAddr,
+  sync::{Arc, Mutex},
+  time::Duration,
+};
+
+use rspack_collections::IdentifierSet;
+use rspack_core::ModuleIdentifier;
+use rspack_error::{Result, error};
+use tokio::{
+  io::{AsyncReadExt, AsyncWriteExt},
+  net::{TcpListener, TcpStream},
+  sync::Notify,
+  task::JoinHandle,
+  time::Instant,
+};
+
+use super::{ActivationEvent, ActivationTracker, Backend};
+
+const MAX_REQUEST_HEAD_SIZE: usize = 16 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct HttpBackendOptions {
+  /// The address to listen on, use port `0` to pick a free port.
+  pub addr: SocketAddr,
+  /// The path prefix of the activation requests.
+  pub prefix: String,
+  /// How long a module stays active after its last request is closed,
+  /// the modules are never deactivated if it's `None`.
+  pub keep_alive: Option<Duration>,
+  /// The interval to send heartbeats to the clients, which also detects the closed requests
+  /// and the expired modules.
+  pub heartbeat_interval: Duration,
+}
+
+impl Default for HttpBackendOptions {
+  fn default() -> Self {
+    Self {
+      addr: SocketAddr::from(([127, 0, 0, 1], 0)),
+      prefix: "/lazy-compilation-using-".to_string(),
+      keep_alive: None,
+      heartbeat_interval: Duration::from_secs(10),
+    }
+  }
+}
+
+/// Http backend
+///
+/// A built-in server speaking the same protocol as the lazy compilation client: the client
+/// opens an event stream at `{prefix}{module}@{module}...` for the modules it's waiting for,
+/// and keeps it open while the modules are in use.
+///
+/// Await [`HttpBackend::changed`] to rebuild when a module is activated or expired, it makes
+/// lazy compilation work without a JS dev server middleware.
+#[derive(Debug)]
+pub struct HttpBackend {
+  local_addr: SocketAddr,
+  prefix: String,
+  tracker: Arc<Mutex<ActivationTracker>>,
+  changed: Arc<Notify>,
+  server: JoinHandle<()>,
+  expiry: JoinHandle<()>,
+}
+
+impl HttpBackend {
+  /// Start the server in the current tokio runtime.
+  pub async fn bind(options: HttpBackendOptions) -> Result<Self> {
+    let listener = TcpListener::bind(options.addr).await.map_err(|e| {
+      error!(
+        "Failed to start lazy compilation server at {}: {e}",
+        options.addr
+      )
+    })?;
+    let local_addr = listener
+      .local_addr()
+      .map_err(|e| error!("Failed to start lazy compilation server: {e}"))?;
+
+    let tracker = Arc::new(Mutex::new(ActivationTracker::new(options.keep_alive)));
+    let changed = Arc::new(Notify::new());
+
+    let server = tokio::spawn({
+      let tracker = tracker.clone();
+      let changed = changed.clone();
+      let prefix = options.prefix.clone();
+      let heartbeat_interval = options.heartbeat_interval;
+      async move {
+        while let Ok((stream, _)) = listener.accept().await {
+          let _ = stream.set_nodelay(true);
+          tokio::spawn(handle_connection(
+            stream,
+            prefix.clone(),
+            tracker.clone(),
+            changed.clone(),
+            heartbeat_interval,
+          ));
+        }
+      }
+    });
+
+    // wake up the embedder when a module expires, the deactivation is taken on the next build
+    let expiry = tokio::spawn({
+      let tracker = tracker.clone();
+      let changed = changed.clone();
+      let heartbeat_interval = options.heartbeat_interval;
+      async move {
+        loop {
+          tokio::time::sleep(heartbeat_interval).await;
+          if lock(&tracker).has_expired(Instant::now().into_std()) {
+            changed.notify_one();
+          }
+        }
+      }
+    });
+
+    Ok(Self {
+      local_addr,
+      prefix: options.prefix,
+      tracker,
+      changed,
+      server,
+      expiry,
+    })
+  }
+
+  pub fn local_addr(&self) -> SocketAddr {
+    self.local_addr
+  }
+
+  /// The url to pass to the lazy compilation client.
+  pub fn url(&self) -> String {
+    format!("http://{}{}", self.local_addr, self.prefix)
+  }
+
+  /// Notified when a module is activated or expired, the compiler should rebuild then.
+  pub fn changed(&self) -> Arc<Notify> {
+    self.changed.clone()
+  }
+}
+
+impl Drop for HttpBackend {
+  fn drop(&mut self) {
+    self.server.abort();
+    self.expiry.abort();
+  }
+}
+
+#[async_trait::async_trait]
+impl Backend for HttpBackend {
+  async fn current_active_modules(&mut self) -> Result<IdentifierSet> {
+    Ok(lock(&self.tracker).active_modules())
+  }
+
+  async fn activation_events(
+    &mut self,
+    _active_modules: &IdentifierSet,
+  ) -> Result<Vec<ActivationEvent>> {
+    Ok(lock(&self.tracker).take_events(Instant::now().into_std()))
+  }
+}
+
+fn lock(tracker: &Mutex<ActivationTracker>) -> std::sync::MutexGuard<'_, ActivationTracker> {
+  tracker
+    .lock()
+    .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+async fn handle_connection(
+  mut stream: TcpStream,
+  prefix: String,
+  tracker: Arc<Mutex<ActivationTracker>>,
+  changed: Arc<Notify>,
+  heartbeat_interval: Duration,
+) {
+  let Some(path) = read_request_path(&mut stream).await else {
+    let _ = stream
+      .write_all(b"HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+      .await;
+    return;
+  };
+  let Some(modules) = path.strip_prefix(&prefix).map(parse_modules) else {
+    let _ = stream
+      .write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+      .await;
+    return;
+  };
+
+  if stream
+    .write_all(
+      b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: keep-alive\r\nAccess-Control-Allow-Origin: *\r\n\r\n\n",
+    )
+    .await
+    .is_err()
+  {
+    return;
+  }
+
+  let activated = {
+    let mut tracker = lock(&tracker);
+    modules.iter().fold(false, |activated, module| {
+      tracker.request(*module) || activated
+    })
+  };
+  if activated {
+    changed.notify_one();
+  }
+
+  // keep the request open until the client closes it
+  let mut buf = [0; 1024];
+  loop {
+    tokio::select! {
+      read = stream.read(&mut buf) => {
+        if matches!(read, Ok(0) | Err(_)) {
+          break;
+        }
+      }
+      _ = tokio::time::sleep(heartbeat_interval) => {
+        if stream.write_all(b":\n\n").await.is_err() {
+          break;
+        }
+      }
+    }
+  }
+
+  let now = Instant::now().into_std();
+  let mut tracker = lock(&tracker);
+  for module in modules {
+    tracker.release(module, now);
+  }
+}
+
+/// Read the request head and return the path of a `GET` request.
+async fn read_request_path(stream: &mut TcpStream) -> Option<String> {
+  let mut head = Vec::new();
+  let mut buf = [0; 1024];
+  while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+    if head.len() > MAX_REQUEST_HEAD_SIZE {
+      return None;
+    }
+    let n = stream.read(&mut buf).await.ok()?;
+    if n == 0 {
+      return None;
+    }
+    head.extend_from_slice(&buf[..n]);
+  }
+  let head = String::from_utf8_lossy(&head);
+  let mut request_line = head.lines().next()?.split(' ');
+  let (method, path) = (request_line.next()?, request_line.next()?);
+  (method == "GET").then(|| path.to_string())
+}
+
+fn parse_modules(keys: &str) -> Vec<ModuleIdentifier> {
+  keys
+    .split('@')
+    .filter(|key| !key.is_empty())
+    .map(|key| {
+      let key = urlencoding::decode(key).map_or_else(|_| key.to_string(), |key| key.into_owned());
+      ModuleIdentifier::from(key)
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  async fn open(backend: &HttpBackend, modules: &[&str]) -> TcpStream {
+    let mut stream = TcpStream::connect(backend.local_addr()).await.unwrap();
+    let path = modules
+      .iter()
+      .map(|module| urlencoding::encode(module).into_owned())
+      .collect::<Vec<_>>()
+      .join("@");
+    stream
+      .write_all(
+        format!(
+          "GET /lazy-compilation-using-{path} HTTP/1.1\r\nHost: {}\r\n\r\n",
+          backend.local_addr()
+        )
+        .as_bytes(),
+      )
+      .await
+      .unwrap();
+    let mut buf = [0; 1024];
+    let n = stream.read(&mut buf).await.unwrap();
+    assert!(String::from_utf8_lossy(&buf[..n]).starts_with("HTTP/1.1 200 OK"));
+    stream
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_activate_and_expire() {
+    let mut backend = HttpBackend::bind(HttpBackendOptions {
+      keep_alive: Some(Duration::from_millis(50)),
+      heartbeat_interval: Duration::from_millis(20),
+      ..Default::default()
+    })
+    .await
+    .unwrap();
+    let changed = backend.changed();
+    let a = ModuleIdentifier::from("lazy-compilation-proxy|./a.js");
+    let b = ModuleIdentifier::from("lazy-compilation-proxy|./b.js");
+
+    let stream = open(&backend, &[a.as_str(), b.as_str()]).await;
+    changed.notified().await;
+    // the active modules are read without taking the events
+    assert_eq!(
+      backend.current_active_modules().await.unwrap(),
+      IdentifierSet::from_iter([a, b])
+    );
+    let events = backend
+      .activation_events(&IdentifierSet::default())
+      .await
+      .unwrap();
+    assert_eq!(events.len(), 2);
+    assert!(events.contains(&ActivationEvent::Activate(a)));
+    assert!(events.contains(&ActivationEvent::Activate(b)));
+
+    // the modules expire after the client closes the request
+    drop(stream);
+    changed.notified().await;
+    let events = backend
+      .activation_events(&IdentifierSet::default())
+      .await
+      .unwrap();
+    assert_eq!(events.len(), 2);
+    assert!(events.contains(&ActivationEvent::Deactivate(a)));
+    assert!(events.contains(&ActivationEvent::Deactivate(b)));
+    assert!(backend.current_active_modules().await.unwrap().is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_unknown_path() {
+    let backend = HttpBackend::bind(Default::default()).await.unwrap();
+    let mut stream = TcpStream::connect(backend.local_addr()).await.unwrap();
+    stream
+      .write_all(b"GET /unknown HTTP/1.1\r\nHost: localhost\r\n\r\n")
+      .await
+      .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    assert!(response.starts_with("HTTP/1.1 404 Not Found"));
+  }
+}
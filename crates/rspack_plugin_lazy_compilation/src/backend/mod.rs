@@ -0,0 +1,78 @@
+mod activation;
+mod http;
+
+pub use activation::ActivationTracker;
+pub use http::{HttpBackend, HttpBackendOptions};
+use rspack_collections::IdentifierSet;
+use rspack_core::ModuleIdentifier;
+use rspack_error::Result;
+
+/// `ActivationEvent` is reported by a [`Backend`] when the client starts or stops
+/// requesting a lazy compiled module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActivationEvent {
+  /// The module is requested, the proxy module should import the real module.
+  Activate(ModuleIdentifier),
+  /// The module is no longer requested, the real module can be evicted from the graph.
+  Deactivate(ModuleIdentifier),
+}
+
+#[async_trait::async_trait]
+pub trait Backend: std::fmt::Debug + Send + Sync {
+  async fn current_active_modules(&mut self) -> Result<IdentifierSet>;
+
+  /// Take the activation events since the last call, `active_modules` are the modules
+  /// activated by the previous events.
+  ///
+  /// The default implementation replaces `active_modules` with the modules returned by
+  /// [`Backend::current_active_modules`]: the modules not returned anymore are deactivated.
+  async fn activation_events(
+    &mut self,
+    active_modules: &IdentifierSet,
+  ) -> Result<Vec<ActivationEvent>> {
+    let current_active_modules = self.current_active_modules().await?;
+    Ok(
+      active_modules
+        .iter()
+        .filter(|module| !current_active_modules.contains(*module))
+        .map(|module| ActivationEvent::Deactivate(*module))
+        .chain(
+          current_active_modules
+            .into_iter()
+            .map(ActivationEvent::Activate),
+        )
+        .collect(),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Debug)]
+  struct FixedBackend(IdentifierSet);
+
+  #[async_trait::async_trait]
+  impl Backend for FixedBackend {
+    async fn current_active_modules(&mut self) -> Result<IdentifierSet> {
+      Ok(self.0.clone())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_default_activation_events() {
+    let a = ModuleIdentifier::from("lazy-compilation-proxy|./a.js");
+    let b = ModuleIdentifier::from("lazy-compilation-proxy|./b.js");
+    let mut backend = FixedBackend(IdentifierSet::from_iter([b]));
+
+    let events = backend
+      .activation_events(&IdentifierSet::from_iter([a, b]))
+      .await
+      .unwrap();
+    assert_eq!(
+      events,
+      vec![ActivationEvent::Deactivate(a), ActivationEvent::Activate(b)]
+    );
+  }
+}
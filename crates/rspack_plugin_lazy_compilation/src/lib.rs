@@ -5,5 +5,5 @@ mod module;
 mod plugin;
 mod utils;
 
-pub use backend::Backend;
+pub use backend::{ActivationEvent, ActivationTracker, Backend, HttpBackend, HttpBackendOptions};
 pub use plugin::{LazyCompilationPlugin, LazyCompilationTest, LazyCompilationTestCheck};
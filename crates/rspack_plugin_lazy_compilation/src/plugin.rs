@@ -16,7 +16,9 @@ use rspack_regex::RspackRegex;
 use tokio::sync::{Mutex, RwLock};
 
 use crate::{
-  backend::Backend, factory::LazyCompilationDependencyFactory, module::LazyCompilationProxyModule,
+  backend::{ActivationEvent, Backend},
+  factory::LazyCompilationDependencyFactory,
+  module::LazyCompilationProxyModule,
   utils::calc_value_dependency_key,
 };
 
@@ -228,26 +230,41 @@ async fn normal_module_factory_module(
 
 #[plugin_hook(CompilerMake for LazyCompilationPlugin<T: Backend, F: LazyCompilationTestCheck>)]
 async fn compiler_make(&self, compilation: &mut Compilation) -> Result<()> {
-  let active_modules = self.backend.lock().await.current_active_modules().await?;
+  let mut active_modules = self.active_modules.write().await;
+  let events = self
+    .backend
+    .lock()
+    .await
+    .activation_events(&active_modules)
+    .await?;
   let mut module_graph =
     Compilation::get_make_module_graph_mut(&mut compilation.build_module_graph_artifact);
   let mut errors = vec![];
-  for module_id in &active_modules {
-    let Some(active_module) = module_graph.module_by_identifier_mut(module_id) else {
-      errors.push(rspack_error::error!("cannot find module instance for id {module_id}").into());
-      continue;
-    };
-
-    let Some(active_module) = active_module.downcast_mut::<LazyCompilationProxyModule>() else {
-      errors.push(rspack_error::error!("cannot find module instance for id {module_id}").into());
-      continue;
+  for event in events {
+    let (module_id, active) = match event {
+      ActivationEvent::Activate(module_id) => (module_id, true),
+      ActivationEvent::Deactivate(module_id) => (module_id, false),
     };
-
-    active_module.invalid();
+    let proxy_module = module_graph
+      .module_by_identifier_mut(&module_id)
+      .and_then(|module| module.downcast_mut::<LazyCompilationProxyModule>());
+
+    if active {
+      let Some(proxy_module) = proxy_module else {
+        errors.push(rspack_error::error!("cannot find module instance for id {module_id}").into());
+        continue;
+      };
+      proxy_module.invalid();
+      active_modules.insert(module_id);
+    } else {
+      // the proxy module may be removed from the graph already, nothing to evict then
+      if let Some(proxy_module) = proxy_module {
+        proxy_module.invalid();
+      }
+      active_modules.remove(&module_id);
+    }
   }
 
-  *self.active_modules.write().await = active_modules.into_iter().collect();
-
   compilation.extend_diagnostics(errors);
   Ok(())
 }
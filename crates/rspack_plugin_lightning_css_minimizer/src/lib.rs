@@ -1,7 +1,9 @@
+mod report;
+
 use std::{
-  collections::HashSet,
+  collections::{BTreeSet, HashSet},
   hash::Hash,
-  sync::{Arc, LazyLock, RwLock},
+  sync::{Arc, LazyLock, Mutex, RwLock},
 };
 
 pub use lightningcss::targets::Browsers;
@@ -13,7 +15,8 @@ use lightningcss::{
 use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::{
-  ChunkUkey, Compilation, CompilationChunkHash, CompilationProcessAssets, Plugin,
+  AssetInfo, ChunkUkey, Compilation, CompilationAsset, CompilationChunkHash,
+  CompilationProcessAssets, Plugin,
   diagnostics::MinifyError,
   rspack_sources::{
     MapOptions, ObjectPool, RawStringSource, SourceExt, SourceMap, SourceMapSource,
@@ -26,6 +29,9 @@ use rspack_hook::{plugin, plugin_hook};
 use rspack_util::asset_condition::{AssetConditions, AssetConditionsObject, match_object};
 use thread_local::ThreadLocal;
 
+pub use self::report::ReportOptions;
+use self::report::{AssetReport, TranspilationReporter};
+
 static CSS_ASSET_REGEXP: LazyLock<Regex> =
   LazyLock::new(|| Regex::new(r"\.css(\?.*)?$").expect("Invalid RegExp"));
 
@@ -35,6 +41,7 @@ pub struct PluginOptions {
   pub include: Option<AssetConditions>,
   pub exclude: Option<AssetConditions>,
   pub remove_unused_local_idents: bool,
+  pub report: Option<ReportOptions>,
   pub minimizer_options: MinimizerOptions,
 }
 
@@ -102,11 +109,12 @@ impl Hash for MinimizerOptions {
 #[derive(Debug)]
 pub struct LightningCssMinimizerRspackPlugin {
   options: PluginOptions,
+  reporter: TranspilationReporter,
 }
 
 impl LightningCssMinimizerRspackPlugin {
   pub fn new(options: PluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, Default::default())
   }
 }
 
@@ -126,6 +134,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   let options = &self.options;
   let minimizer_options = &self.options.minimizer_options;
   let all_warnings: RwLock<Vec<Diagnostic>> = Default::default();
+  let all_reports: Mutex<Vec<(String, AssetReport)>> = Default::default();
   let condition_object = AssetConditionsObject {
     test: options.test.as_ref(),
     include: options.include.as_ref(),
@@ -179,6 +188,19 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
             Ok(sm)
           })
           .transpose()?;
+        let targets = Targets {
+          browsers: minimizer_options.targets,
+          include: minimizer_options
+            .include
+            .as_ref()
+            .map(|include| Features::from_bits_truncate(*include))
+            .unwrap_or(Features::empty()),
+          exclude: minimizer_options
+            .exclude
+            .as_ref()
+            .map(|exclude| Features::from_bits_truncate(*exclude))
+            .unwrap_or(Features::empty()),
+        };
         let result = {
           let warnings: Arc<RwLock<Vec<_>>> = Default::default();
           let mut stylesheet = StyleSheet::parse(
@@ -194,19 +216,6 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           )
           .to_rspack_result()?;
 
-          let targets = Targets {
-            browsers: minimizer_options.targets,
-            include: minimizer_options
-              .include
-              .as_ref()
-              .map(|include| Features::from_bits_truncate(*include))
-              .unwrap_or(Features::empty()),
-            exclude: minimizer_options
-              .exclude
-              .as_ref()
-              .map(|exclude| Features::from_bits_truncate(*exclude))
-              .unwrap_or(Features::empty()),
-          };
           let mut unused_symbols = HashSet::from_iter(minimizer_options.unused_symbols.clone());
           if self.options.remove_unused_local_idents
             && let Some(css_unused_idents) = original.info.css_unused_idents.take()
@@ -260,6 +269,16 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
             .to_rspack_result()?
         };
 
+        if options.report.is_some() {
+          let report = self.reporter.report(&input, &result.code, targets);
+          if !report.is_empty() {
+            all_reports
+              .lock()
+              .expect("should lock")
+              .push((filename.clone(), report));
+          }
+        }
+
         let minimized_source = if let Some(mut source_map) = source_map {
           SourceMapSource::new(SourceMapSourceOptions {
             value: result.code,
@@ -287,9 +306,93 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
 
   compilation.extend_diagnostics(all_warnings.into_inner().expect("should lock"));
 
+  if let Some(report_options) = &options.report {
+    let mut reports = all_reports.into_inner().expect("should lock");
+    reports.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    emit_reports(compilation, report_options, &reports)?;
+  }
+
+  Ok(())
+}
+
+fn emit_reports(
+  compilation: &mut Compilation,
+  options: &ReportOptions,
+  reports: &[(String, AssetReport)],
+) -> Result<()> {
+  let mut diagnostics = vec![];
+  for (filename, report) in reports {
+    diagnostics.extend(report.unsupported_warnings(filename));
+  }
+
+  if let Some(report_filename) = &options.filename {
+    let json = reports
+      .iter()
+      .map(|(filename, report)| {
+        (
+          filename.clone(),
+          serde_json::json!({
+            "transpiled": report.transpiled,
+            "prefixesAdded": report.prefixes_added,
+            "prefixesRemoved": report.prefixes_removed,
+            "unsupported": report
+              .unsupported
+              .iter()
+              .map(|(feature, declaration)| {
+                serde_json::json!({ "feature": feature, "declaration": declaration })
+              })
+              .collect::<Vec<_>>(),
+          }),
+        )
+      })
+      .collect::<serde_json::Map<_, _>>();
+    let content = serde_json::to_string_pretty(&json).to_rspack_result()?;
+    compilation.emit_asset(
+      report_filename.clone(),
+      CompilationAsset::new(
+        Some(RawStringSource::from(content).boxed()),
+        AssetInfo::default(),
+      ),
+    );
+  } else {
+    for (filename, report) in reports {
+      let mut parts = vec![];
+      if !report.transpiled.is_empty() {
+        parts.push(format!("lowered {}", join(&report.transpiled)));
+      }
+      if !report.prefixes_added.is_empty() {
+        parts.push(format!("added {}", join(&report.prefixes_added)));
+      }
+      if !report.prefixes_removed.is_empty() {
+        parts.push(format!("removed {}", join(&report.prefixes_removed)));
+      }
+      if parts.is_empty() {
+        continue;
+      }
+      let mut diagnostic = Diagnostic::warn(
+        "LightningCSS transpilation report".to_string(),
+        format!(
+          "Transpiled \"{filename}\" for the targets: {}.",
+          parts.join("; ")
+        ),
+      );
+      diagnostic.file = Some(filename.as_str().into());
+      diagnostics.push(diagnostic);
+    }
+  }
+
+  compilation.extend_diagnostics(diagnostics);
   Ok(())
 }
 
+fn join(items: &BTreeSet<String>) -> String {
+  items
+    .iter()
+    .map(String::as_str)
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
 impl Plugin for LightningCssMinimizerRspackPlugin {
   fn name(&self) -> &'static str {
     "rspack.LightningCssMinimizerRspackPlugin"
@@ -0,0 +1,512 @@
+use std::{
+  collections::{BTreeSet, HashMap},
+  sync::{LazyLock, Mutex},
+};
+
+use lightningcss::{
+  printer::PrinterOptions,
+  stylesheet::{MinifyOptions, ParserOptions, StyleSheet},
+  targets::Targets,
+};
+use regex::Regex;
+use rspack_error::Diagnostic;
+use rspack_util::css_scanner::{skip_comment, skip_string};
+
+static COMMENT_REGEXP: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"/\*[\s\S]*?\*/").expect("Invalid RegExp"));
+static COLOR_FUNCTION_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"(?:^|[^\w-])(lab|lch|oklab|oklch|color|color-mix|hwb|light-dark)\(")
+    .expect("Invalid RegExp")
+});
+static LOGICAL_PROPERTY_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(
+    r"(?:^|[{;\s])((?:margin|padding|inset|border)-(?:inline|block)(?:-(?:start|end))?(?:-(?:width|style|color))?|(?:min-|max-)?(?:inline|block)-size|border-(?:start|end)-(?:start|end)-radius)\s*:",
+  )
+  .expect("Invalid RegExp")
+});
+static VENDOR_PREFIX_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"(?:^|[^\w-])(-(?:webkit|moz|ms|o)-[\w-]+)").expect("Invalid RegExp")
+});
+
+/// The options of reporting what is transpiled for the targets.
+#[derive(Debug, Hash)]
+pub struct ReportOptions {
+  /// Emit the reports as a JSON asset with the filename, otherwise they're emitted as warnings.
+  pub filename: Option<String>,
+}
+
+/// What is transpiled in an asset for the targets.
+#[derive(Debug, Default)]
+pub(crate) struct AssetReport {
+  /// The features lowered for the targets, like `nesting`, `oklch()` and `margin-inline`.
+  pub transpiled: BTreeSet<String>,
+  pub prefixes_added: BTreeSet<String>,
+  pub prefixes_removed: BTreeSet<String>,
+  /// The features not supported by the targets and the declarations using them, they're kept
+  /// since they can't be lowered.
+  pub unsupported: BTreeSet<(String, String)>,
+}
+
+impl AssetReport {
+  pub fn is_empty(&self) -> bool {
+    self.transpiled.is_empty()
+      && self.prefixes_added.is_empty()
+      && self.prefixes_removed.is_empty()
+      && self.unsupported.is_empty()
+  }
+
+  /// The warnings of the declarations kept with the features not supported by the targets.
+  pub fn unsupported_warnings(&self, filename: &str) -> impl Iterator<Item = Diagnostic> {
+    self.unsupported.iter().map(move |(feature, declaration)| {
+      let mut diagnostic = Diagnostic::warn(
+        "LightningCSS transpilation warning".to_string(),
+        format!(
+          "The declaration `{declaration}` in \"{filename}\" can't be represented for the targets, {feature} isn't supported by the targets and can't be lowered."
+        ),
+      );
+      diagnostic.file = Some(filename.into());
+      diagnostic
+    })
+  }
+}
+
+/// How a feature is supported by the targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Support {
+  Supported,
+  /// The feature is kept with a fallback for the targets not supporting it.
+  Fallback,
+  Lowered,
+}
+
+/// Reports what is transpiled by comparing the input and output of the assets. The support of
+/// the features for the targets is found by compiling a small probe of each feature.
+#[derive(Debug, Default)]
+pub(crate) struct TranspilationReporter {
+  probes: Mutex<HashMap<String, Support>>,
+}
+
+impl TranspilationReporter {
+  pub fn report(&self, input: &str, output: &str, targets: Targets) -> AssetReport {
+    let input = COMMENT_REGEXP.replace_all(input, " ");
+    let output = COMMENT_REGEXP.replace_all(output, " ");
+    let mut report = AssetReport::default();
+
+    if has_nested_rules(&input) {
+      let support = self.probe("a{b{color:red}}", targets, has_nested_rules);
+      if support == Support::Lowered {
+        report.transpiled.insert("nesting".to_string());
+      }
+    }
+
+    for function in captures(&COLOR_FUNCTION_REGEXP, &input) {
+      let probe = format!("a{{color:{}}}", color_function_example(function));
+      let support = self.probe(&probe, targets, |output| {
+        captures(&COLOR_FUNCTION_REGEXP, output).contains(function)
+      });
+      let feature = format!("{function}()");
+      check(&mut report, feature, support, &output, |declaration| {
+        captures(&COLOR_FUNCTION_REGEXP, declaration).contains(function)
+      });
+    }
+
+    for property in captures(&LOGICAL_PROPERTY_REGEXP, &input) {
+      let probe = format!("a{{{property}:{}}}", logical_property_example(property));
+      let support = self.probe(&probe, targets, |output| {
+        captures(&LOGICAL_PROPERTY_REGEXP, output).contains(property)
+      });
+      check(
+        &mut report,
+        property.to_string(),
+        support,
+        &output,
+        |declaration| captures(&LOGICAL_PROPERTY_REGEXP, declaration).contains(property),
+      );
+    }
+
+    let input_prefixes = captures(&VENDOR_PREFIX_REGEXP, &input);
+    let output_prefixes = captures(&VENDOR_PREFIX_REGEXP, &output);
+    report.prefixes_added = output_prefixes
+      .difference(&input_prefixes)
+      .map(|prefix| prefix.to_string())
+      .collect();
+    report.prefixes_removed = input_prefixes
+      .difference(&output_prefixes)
+      .map(|prefix| prefix.to_string())
+      .collect();
+
+    report
+  }
+
+  fn probe(&self, css: &str, targets: Targets, uses_feature: impl Fn(&str) -> bool) -> Support {
+    if let Some(support) = self.probes.lock().expect("should lock").get(css) {
+      return *support;
+    }
+    let support = match compile(css, targets) {
+      Some(output) if !uses_feature(output.as_str()) => Support::Lowered,
+      // the fallback declarations are added before the declaration
+      Some(output) if declarations(&output).count() > 1 => Support::Fallback,
+      _ => Support::Supported,
+    };
+    self
+      .probes
+      .lock()
+      .expect("should lock")
+      .insert(css.to_string(), support);
+    support
+  }
+}
+
+/// Record the feature used by the input, and the declarations of the output still using the
+/// lowered feature.
+fn check(
+  report: &mut AssetReport,
+  feature: String,
+  support: Support,
+  output: &str,
+  uses_feature: impl Fn(&str) -> bool,
+) {
+  if support == Support::Supported {
+    return;
+  }
+  if support == Support::Lowered {
+    for declaration in declarations(output) {
+      if uses_feature(declaration) {
+        report
+          .unsupported
+          .insert((feature.clone(), declaration.to_string()));
+      }
+    }
+  }
+  report.transpiled.insert(feature);
+}
+
+fn compile(css: &str, targets: Targets) -> Option<String> {
+  let mut stylesheet = StyleSheet::parse(css, ParserOptions::default()).ok()?;
+  stylesheet
+    .minify(MinifyOptions {
+      targets,
+      ..Default::default()
+    })
+    .ok()?;
+  stylesheet
+    .to_css(PrinterOptions {
+      minify: true,
+      targets,
+      ..Default::default()
+    })
+    .ok()
+    .map(|result| result.code)
+}
+
+fn captures<'a>(regex: &Regex, css: &'a str) -> BTreeSet<&'a str> {
+  regex
+    .captures_iter(css)
+    .filter_map(|captures| captures.get(1))
+    .map(|capture| capture.as_str())
+    .collect()
+}
+
+/// The declarations in the blocks of the css. The `;` and braces in the strings, comments and
+/// functions like `url(data:image/png;base64,...)` don't end a declaration.
+fn declarations(css: &str) -> impl Iterator<Item = &str> {
+  let bytes = css.as_bytes();
+  let mut declarations = vec![];
+  let mut depth = 0usize;
+  let mut start = 0;
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        i = skip_comment(bytes, i);
+        continue;
+      }
+      b'"' | b'\'' => {
+        i = skip_string(bytes, i);
+        continue;
+      }
+      b'\\' => i += 1,
+      b'(' => depth += 1,
+      b')' => depth = depth.saturating_sub(1),
+      // the preludes of the rules aren't declarations
+      b'{' if depth == 0 => start = i + 1,
+      b'}' | b';' if depth == 0 => {
+        declarations.push(&css[start..i]);
+        start = i + 1;
+      }
+      _ => {}
+    }
+    i += 1;
+  }
+  declarations.push(&css[start..]);
+  declarations
+    .into_iter()
+    .map(str::trim)
+    .filter(|declaration| declaration.contains(':') && !declaration.starts_with('@'))
+}
+
+/// Whether a rule is nested in a style rule, like `.a { .b {} }` or `.a { @media (x) {} }`.
+fn has_nested_rules(css: &str) -> bool {
+  // whether each of the open blocks is a style rule
+  let mut blocks: Vec<bool> = vec![];
+  let mut prelude_start = 0;
+  let mut quote = None;
+  let mut chars = css.char_indices();
+  while let Some((index, c)) = chars.next() {
+    if let Some(q) = quote {
+      if c == '\\' {
+        chars.next();
+      } else if c == q {
+        quote = None;
+      }
+      continue;
+    }
+    match c {
+      '"' | '\'' => quote = Some(c),
+      '{' => {
+        if blocks.iter().any(|is_style_rule| *is_style_rule) {
+          return true;
+        }
+        blocks.push(!css[prelude_start..index].trim_start().starts_with('@'));
+        prelude_start = index + 1;
+      }
+      '}' => {
+        blocks.pop();
+        prelude_start = index + 1;
+      }
+      ';' => prelude_start = index + 1,
+      _ => {}
+    }
+  }
+  false
+}
+
+fn color_function_example(function: &str) -> &'static str {
+  match function {
+    "lab" => "lab(50% 20 30)",
+    "lch" => "lch(50% 30 40)",
+    "oklab" => "oklab(50% .1 .1)",
+    "oklch" => "oklch(50% .1 40)",
+    "color" => "color(display-p3 1 0 0)",
+    "color-mix" => "color-mix(in srgb,red,blue)",
+    "hwb" => "hwb(120 10% 10%)",
+    _ => "light-dark(red,blue)",
+  }
+}
+
+fn logical_property_example(property: &str) -> &'static str {
+  if property.ends_with("-color") {
+    "red"
+  } else if property.ends_with("-style") {
+    "solid"
+  } else {
+    "1px"
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use lightningcss::targets::Browsers;
+
+  use super::*;
+
+  fn chrome(version: u32) -> Targets {
+    Targets::from(Browsers {
+      chrome: Some(version << 16),
+      ..Default::default()
+    })
+  }
+
+  fn uses_oklch(declaration: &str) -> bool {
+    captures(&COLOR_FUNCTION_REGEXP, declaration).contains("oklch")
+  }
+
+  #[test]
+  fn test_has_nested_rules() {
+    assert!(has_nested_rules(".a{.b{color:red}}"));
+    assert!(has_nested_rules(".a{&:hover{color:red}}"));
+    assert!(has_nested_rules(".a{color:red;@media (x){color:blue}}"));
+    assert!(!has_nested_rules(".a{color:red}.b{color:blue}"));
+    assert!(!has_nested_rules(
+      "@media (x){@supports (y){.a{color:red}}}"
+    ));
+    assert!(!has_nested_rules(r#".a{content:"{"}.b{color:red}"#));
+  }
+
+  #[test]
+  fn test_captures() {
+    assert_eq!(
+      captures(
+        &COLOR_FUNCTION_REGEXP,
+        "a{color:oklch(50% .1 40);background:color-mix(in srgb,lab(50% 20 30),red)}"
+      ),
+      BTreeSet::from(["color-mix", "lab", "oklch"])
+    );
+    assert!(captures(&COLOR_FUNCTION_REGEXP, "a{color:red;b:mylab(1);c:--lab(2)}").is_empty());
+    assert_eq!(
+      captures(
+        &LOGICAL_PROPERTY_REGEXP,
+        "a{margin-inline-start:1px; padding-block: 2px;border-start-end-radius:1px;max-block-size:1px;margin-left:1px}"
+      ),
+      BTreeSet::from([
+        "border-start-end-radius",
+        "margin-inline-start",
+        "max-block-size",
+        "padding-block"
+      ])
+    );
+    assert_eq!(
+      captures(
+        &VENDOR_PREFIX_REGEXP,
+        "a{-webkit-user-select:none;transition:-moz-transform 1s;b:x-ms-y}"
+      ),
+      BTreeSet::from(["-moz-transform", "-webkit-user-select"])
+    );
+  }
+
+  #[test]
+  fn test_declarations() {
+    assert_eq!(
+      declarations(
+        r#"a:hover{color:red;background:url(data:image/png;base64,AAAA)}b{content:";}";margin:0}@media (x){c{d:e}}"#
+      )
+      .collect::<Vec<_>>(),
+      vec![
+        "color:red",
+        "background:url(data:image/png;base64,AAAA)",
+        r#"content:";}""#,
+        "margin:0",
+        "d:e"
+      ]
+    );
+  }
+
+  #[test]
+  fn test_probe() {
+    let reporter = TranspilationReporter::default();
+    assert_eq!(
+      reporter.probe("a{b{color:red}}", chrome(80), has_nested_rules),
+      Support::Lowered
+    );
+    // the probes are compiled once
+    assert_eq!(
+      reporter.probe("a{b{color:red}}", chrome(80), |_| unreachable!()),
+      Support::Lowered
+    );
+    assert_eq!(
+      reporter.probe("a{color:oklch(50% .1 40)}", chrome(80), uses_oklch),
+      Support::Lowered
+    );
+    assert_eq!(
+      reporter.probe("a{margin-inline-start:1px}", chrome(80), |output| {
+        output.contains("margin-inline-start")
+      }),
+      Support::Lowered
+    );
+
+    let reporter = TranspilationReporter::default();
+    assert_eq!(
+      reporter.probe("a{b{color:red}}", chrome(130), has_nested_rules),
+      Support::Supported
+    );
+    assert_eq!(
+      reporter.probe("a{color:oklch(50% .1 40)}", chrome(130), uses_oklch),
+      Support::Supported
+    );
+    assert_eq!(
+      reporter.probe("a{margin-inline-start:1px}", chrome(130), |output| {
+        output.contains("margin-inline-start")
+      }),
+      Support::Supported
+    );
+  }
+
+  #[test]
+  fn test_check() {
+    let output = "a{color:#fff;border-color:oklch(50% .1 var(--h))}";
+    let mut report = AssetReport::default();
+    check(
+      &mut report,
+      "oklch()".to_string(),
+      Support::Supported,
+      output,
+      uses_oklch,
+    );
+    assert!(report.is_empty());
+
+    // the declaration with a fallback is expected to keep the feature
+    check(
+      &mut report,
+      "oklch()".to_string(),
+      Support::Fallback,
+      output,
+      uses_oklch,
+    );
+    assert_eq!(report.transpiled, BTreeSet::from(["oklch()".to_string()]));
+    assert!(report.unsupported.is_empty());
+
+    check(
+      &mut report,
+      "oklch()".to_string(),
+      Support::Lowered,
+      output,
+      uses_oklch,
+    );
+    assert_eq!(
+      report.unsupported,
+      BTreeSet::from([(
+        "oklch()".to_string(),
+        "border-color:oklch(50% .1 var(--h))".to_string()
+      )])
+    );
+  }
+
+  #[test]
+  fn test_report() {
+    let reporter = TranspilationReporter::default();
+    let report = reporter.report(
+      "a{color:oklch(50% .1 40);margin-inline:1px;-webkit-border-radius:2px;/* lab( */b{user-select:none}}",
+      "a{color:#8a4e3b;margin-left:1px;margin-right:1px;border-radius:2px}a b{-webkit-user-select:none;user-select:none}",
+      chrome(80),
+    );
+    assert_eq!(
+      report.transpiled,
+      BTreeSet::from([
+        "margin-inline".to_string(),
+        "nesting".to_string(),
+        "oklch()".to_string()
+      ])
+    );
+    assert_eq!(
+      report.prefixes_added,
+      BTreeSet::from(["-webkit-user-select".to_string()])
+    );
+    assert_eq!(
+      report.prefixes_removed,
+      BTreeSet::from(["-webkit-border-radius".to_string()])
+    );
+    assert!(report.unsupported.is_empty());
+    assert_eq!(report.unsupported_warnings("a.css").count(), 0);
+  }
+
+  #[test]
+  fn test_unsupported_warnings() {
+    let reporter = TranspilationReporter::default();
+    let css = "a{color:oklch(50% .1 var(--h))}";
+    let report = reporter.report(css, css, chrome(80));
+    assert_eq!(
+      report.unsupported,
+      BTreeSet::from([(
+        "oklch()".to_string(),
+        "color:oklch(50% .1 var(--h))".to_string()
+      )])
+    );
+    let warnings = report.unsupported_warnings("a.css").collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+      warnings[0].error.message,
+      "The declaration `color:oklch(50% .1 var(--h))` in \"a.css\" can't be represented for the targets, oklch() isn't supported by the targets and can't be lowered."
+    );
+    assert_eq!(warnings[0].file, Some("a.css".into()));
+  }
+}
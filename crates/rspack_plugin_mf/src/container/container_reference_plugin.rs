@@ -1,18 +1,26 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
+use camino::Utf8PathBuf;
+use itertools::Itertools;
+use rspack_collections::Identifiable;
 use rspack_core::{
-  BoxModule, ChunkUkey, Compilation, CompilationParams, CompilationRuntimeRequirementInTree,
-  CompilerCompilation, DependencyType, ExternalType, ModuleExt, ModuleFactoryCreateData,
-  NormalModuleFactoryFactorize, Plugin, RuntimeGlobals,
+  AsyncModulesArtifact, BoxModule, ChunkUkey, Compilation, CompilationFinishModules,
+  CompilationParams, CompilationRuntimeRequirementInTree, CompilerCompilation, DependencyType,
+  ExternalType, ModuleExt, ModuleFactoryCreateData, NormalModuleFactoryFactorize, Plugin,
+  RuntimeGlobals,
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::itoa;
+use rustc_hash::FxHashMap;
 
 use super::{
-  fallback_module_factory::FallbackModuleFactory, remote_module::RemoteModule,
+  fallback_module_factory::FallbackModuleFactory,
+  remote_manifest::{RemoteManifest, load_remote_manifest},
+  remote_module::RemoteModule,
   remote_runtime_module::RemoteRuntimeModule,
 };
+use crate::{ConsumeSharedModule, ConsumeVersion, sharing::version::satisfy};
 
 #[derive(Debug)]
 pub struct ContainerReferencePluginOptions {
@@ -26,17 +34,75 @@ pub struct ContainerReferencePluginOptions {
 pub struct RemoteOptions {
   pub external: Vec<String>,
   pub share_scope: String,
+  /// The manifest of the remote, a file relative to the context or a `http://` url. The
+  /// exposed modules and shared versions used by the consumer are checked against it.
+  pub manifest: Option<String>,
 }
 
 #[plugin]
 #[derive(Debug)]
 pub struct ContainerReferencePlugin {
   options: ContainerReferencePluginOptions,
+  /// The manifests loaded by the previous compilations of the compiler, keyed by location.
+  manifests: Mutex<FxHashMap<String, (Arc<RemoteManifest>, Option<Utf8PathBuf>)>>,
 }
 
 impl ContainerReferencePlugin {
   pub fn new(options: ContainerReferencePluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, Default::default())
+  }
+
+  /// Load the manifest once per compiler, a local manifest is reloaded when it's modified, and
+  /// a manifest failed to load is retried in the next compilation.
+  async fn load_manifest(
+    &self,
+    location: &str,
+    compilation: &mut Compilation,
+  ) -> Result<Arc<RemoteManifest>> {
+    let cached = self
+      .manifests
+      .lock()
+      .expect("should lock manifests")
+      .get(location)
+      .cloned();
+    let (manifest, path) = match cached {
+      Some((manifest, path))
+        if path.as_ref().is_none_or(|path| {
+          !compilation
+            .modified_files
+            .iter()
+            .chain(compilation.removed_files.iter())
+            .any(|file| file.as_ref() == path.as_std_path())
+        }) =>
+      {
+        (manifest, path)
+      }
+      _ => match load_remote_manifest(location, compilation).await {
+        Ok((manifest, path)) => {
+          let manifest = Arc::new(manifest);
+          self
+            .manifests
+            .lock()
+            .expect("should lock manifests")
+            .insert(location.to_string(), (manifest.clone(), path.clone()));
+          (manifest, path)
+        }
+        Err(e) => {
+          self
+            .manifests
+            .lock()
+            .expect("should lock manifests")
+            .remove(location);
+          return Err(e);
+        }
+      },
+    };
+    if let Some(path) = path {
+      compilation
+        .file_dependencies
+        .insert(path.into_std_path_buf().into());
+    }
+    Ok(manifest)
   }
 }
 
@@ -104,6 +170,94 @@ async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<B
   Ok(None)
 }
 
+#[plugin_hook(CompilationFinishModules for ContainerReferencePlugin)]
+async fn finish_modules(
+  &self,
+  compilation: &mut Compilation,
+  _async_modules_artifact: &mut AsyncModulesArtifact,
+) -> Result<()> {
+  let mut manifests: Vec<(&str, Arc<RemoteManifest>)> = vec![];
+  for (key, config) in &self.options.remotes {
+    let Some(location) = &config.manifest else {
+      continue;
+    };
+    match self.load_manifest(location, compilation).await {
+      Ok(manifest) => manifests.push((key, manifest)),
+      // the remote may be not built or served yet, skip the checks
+      Err(e) => compilation.push_diagnostic(Diagnostic::warn(
+        "ModuleFederationRemoteManifest".into(),
+        e.to_string(),
+      )),
+    }
+  }
+  if manifests.is_empty() {
+    return Ok(());
+  }
+
+  let module_graph = compilation.get_module_graph();
+  let mut diagnostics = vec![];
+  for (identifier, module) in module_graph.modules() {
+    let issuer = module_graph
+      .get_issuer(&identifier)
+      .map(|issuer| issuer.identifier());
+
+    if let Some(remote) = module.downcast_ref::<RemoteModule>() {
+      let Some((_, manifest)) = manifests.iter().find(|(key, _)| *key == remote.remote_key) else {
+        continue;
+      };
+      if !manifest.exposes(&remote.internal_request) {
+        let mut diagnostic = Diagnostic::error(
+          "ModuleFederationRemoteContract".into(),
+          format!(
+            "The remote \"{}\" does not expose \"{}\", the exposed modules in its manifest are {}.",
+            remote.remote_key,
+            remote.internal_request,
+            manifest
+              .exposes
+              .iter()
+              .map(|expose| format!("\"{}\"", expose.path))
+              .join(", ")
+          ),
+        );
+        diagnostic.module_identifier = Some(issuer.unwrap_or(identifier));
+        diagnostics.push(diagnostic);
+      }
+    } else if let Some(consume) = module.downcast_ref::<ConsumeSharedModule>() {
+      let options = consume.options();
+      let Some(ConsumeVersion::Version(required_version)) = &options.required_version else {
+        continue;
+      };
+      for (key, manifest) in &manifests {
+        let Some(shared) = manifest
+          .shared
+          .iter()
+          .find(|shared| shared.name == options.share_key && !shared.version.is_empty())
+        else {
+          continue;
+        };
+        // skip the check if the range or the version can't be parsed, e.g. `workspace:*`
+        if satisfy(required_version, &shared.version) != Some(false) {
+          continue;
+        }
+        let message = format!(
+          "The shared module \"{}\" requires version \"{required_version}\", but the remote \"{key}\" provides version \"{}\".",
+          options.share_key, shared.version
+        );
+        // only the strict version fails at runtime, the others fall back to the local version
+        let mut diagnostic = if options.strict_version {
+          Diagnostic::error("ModuleFederationRemoteContract".into(), message)
+        } else {
+          Diagnostic::warn("ModuleFederationRemoteContract".into(), message)
+        };
+        diagnostic.module_identifier = Some(issuer.unwrap_or(identifier));
+        diagnostics.push(diagnostic);
+      }
+    }
+  }
+  compilation.extend_diagnostics(diagnostics);
+  Ok(())
+}
+
 #[plugin_hook(CompilationRuntimeRequirementInTree for ContainerReferencePlugin)]
 async fn runtime_requirements_in_tree(
   &self,
@@ -141,6 +295,10 @@ impl Plugin for ContainerReferencePlugin {
       .normal_module_factory_hooks
       .factorize
       .tap(factorize::new(self));
+    ctx
+      .compilation_hooks
+      .finish_modules
+      .tap(finish_modules::new(self));
     ctx
       .compilation_hooks
       .runtime_requirement_in_tree
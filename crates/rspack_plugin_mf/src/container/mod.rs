@@ -16,6 +16,7 @@ pub mod federation_modules_plugin;
 pub mod federation_runtime_dependency;
 pub mod hoist_container_references_plugin;
 pub mod module_federation_runtime_plugin;
+pub mod remote_manifest;
 pub mod remote_module;
 pub mod remote_runtime_module;
 pub mod remote_to_external_dependency;
@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use camino::Utf8PathBuf;
+use rspack_core::Compilation;
+use rspack_error::{Result, error};
+use rspack_util::http::PlainHttpClient;
+use serde::Deserialize;
+
+/// The timeout of connecting to the server and of receiving the whole manifest.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The parts of a remote's manifest used to check the consumer against it.
+#[derive(Debug, Default, Deserialize)]
+pub struct RemoteManifest {
+  #[serde(default)]
+  pub name: String,
+  #[serde(default)]
+  pub exposes: Vec<RemoteManifestExpose>,
+  #[serde(default)]
+  pub shared: Vec<RemoteManifestShared>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RemoteManifestExpose {
+  pub path: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RemoteManifestShared {
+  pub name: String,
+  #[serde(default)]
+  pub version: String,
+}
+
+impl RemoteManifest {
+  /// Whether the remote exposes the request, like `./Button`.
+  pub fn exposes(&self, request: &str) -> bool {
+    let request = request.trim_start_matches("./");
+    self
+      .exposes
+      .iter()
+      .any(|expose| expose.path.trim_start_matches("./") == request)
+  }
+}
+
+/// Load the manifest from a file relative to the context, or a `http://` url.
+///
+/// Returns the resolved path of the file to watch it.
+pub async fn load_remote_manifest(
+  location: &str,
+  compilation: &Compilation,
+) -> Result<(RemoteManifest, Option<Utf8PathBuf>)> {
+  let (content, path) = if location.starts_with("http://") {
+    (fetch(location).await?, None)
+  } else if location.contains("://") {
+    return Err(error!(
+      "Failed to load the remote manifest {location}: only local files and http urls are supported"
+    ));
+  } else {
+    let path = compilation.options.context.as_path().join(location);
+    let content = compilation
+      .input_filesystem
+      .read_to_string(&path)
+      .await
+      .map_err(|e| error!("Failed to load the remote manifest {location}: {e}"))?;
+    (content, Some(path))
+  };
+  let manifest = serde_json::from_str(&content)
+    .map_err(|e| error!("Failed to parse the remote manifest {location}: {e}"))?;
+  Ok((manifest, path))
+}
+
+/// Fetch a `http://` url, it's meant for the manifests served by a local dev server.
+async fn fetch(url: &str) -> Result<String> {
+  let fail = |reason: String| error!("Failed to fetch the remote manifest {url}: {reason}");
+
+  let client = PlainHttpClient::new(vec![("Accept".to_string(), "application/json".to_string())])
+    .with_timeouts(FETCH_TIMEOUT, FETCH_TIMEOUT);
+  let res = client.get(url).await.map_err(|e| fail(e.to_string()))?;
+  match res.status {
+    200 => String::from_utf8(res.body).map_err(|e| fail(e.to_string())),
+    404 => Err(fail("the server responded with status 404".to_string())),
+    status => Err(fail(format!(
+      "the server responded with unexpected status {status}"
+    ))),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+  };
+
+  use super::*;
+
+  /// Serve a single request with the raw response, return the url of the server.
+  async fn serve(response: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+      let (mut stream, _) = listener.accept().await.unwrap();
+      let mut head = vec![];
+      let mut buf = [0; 1024];
+      while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await.unwrap();
+        if n == 0 {
+          return;
+        }
+        head.extend_from_slice(&buf[..n]);
+      }
+      assert!(head.starts_with(b"GET /remote/mf-manifest.json HTTP/1.1\r\n"));
+      stream.write_all(response.as_bytes()).await.unwrap();
+    });
+    format!("http://{addr}")
+  }
+
+  #[tokio::test]
+  async fn test_fetch() {
+    let url = serve(
+      "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n\
+       1e\r\n{\"exposes\":[{\"path\":\"./Button\"\r\n3\r\n}]}\r\n0\r\n\r\n",
+    )
+    .await;
+    let content = fetch(&format!("{url}/remote/mf-manifest.json#hash"))
+      .await
+      .unwrap();
+    let manifest: RemoteManifest = serde_json::from_str(&content).unwrap();
+    assert!(manifest.exposes("./Button"));
+    assert!(manifest.exposes("Button"));
+    assert!(!manifest.exposes("./Input"));
+  }
+
+  #[tokio::test]
+  async fn test_fetch_failed() {
+    let url = serve("HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n").await;
+    let error = fetch(&format!("{url}/remote/mf-manifest.json"))
+      .await
+      .unwrap_err();
+    assert!(error.to_string().contains("status 404"));
+
+    let url = serve("HTTP/1.1 500 Internal Server Error\r\nContent-Length: 0\r\n\r\n").await;
+    let error = fetch(&format!("{url}/remote/mf-manifest.json"))
+      .await
+      .unwrap_err();
+    assert!(error.to_string().contains("unexpected status 500"));
+  }
+}
@@ -0,0 +1,376 @@
+use std::cmp::Ordering;
+
+/// A `major.minor.patch-prerelease` version, the build metadata is ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Version {
+  parts: [u64; 3],
+  prerelease: Vec<String>,
+}
+
+impl Version {
+  fn new(parts: [u64; 3]) -> Self {
+    Self {
+      parts,
+      prerelease: vec![],
+    }
+  }
+}
+
+impl PartialOrd for Version {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Version {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.parts.cmp(&other.parts).then_with(|| {
+      match (self.prerelease.is_empty(), other.prerelease.is_empty()) {
+        (true, true) => Ordering::Equal,
+        // a prerelease has a lower precedence than the release
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => {
+          for (a, b) in self.prerelease.iter().zip(&other.prerelease) {
+            let ordering = match (a.parse::<u64>(), b.parse::<u64>()) {
+              (Ok(a), Ok(b)) => a.cmp(&b),
+              (Ok(_), Err(_)) => Ordering::Less,
+              (Err(_), Ok(_)) => Ordering::Greater,
+              (Err(_), Err(_)) => a.cmp(b),
+            };
+            if ordering != Ordering::Equal {
+              return ordering;
+            }
+          }
+          self.prerelease.len().cmp(&other.prerelease.len())
+        }
+      }
+    })
+  }
+}
+
+/// A version with the missing or wildcard parts, like `1.x` or `2`.
+#[derive(Debug)]
+struct PartialVersion {
+  parts: [Option<u64>; 3],
+  prerelease: Vec<String>,
+}
+
+impl PartialVersion {
+  fn parse(version: &str) -> Option<Self> {
+    let version = version.trim().trim_start_matches(['v', '=']);
+    let version = version.split('+').next().unwrap_or_default();
+    let (version, prerelease) = match version.split_once('-') {
+      Some((version, prerelease)) => (version, prerelease.split('.').map(str::to_string).collect()),
+      None => (version, vec![]),
+    };
+    let mut parts = [None; 3];
+    for (index, part) in version.split('.').enumerate() {
+      if index >= 3 {
+        return None;
+      }
+      parts[index] = match part {
+        "x" | "X" | "*" => None,
+        part => Some(part.parse().ok()?),
+      };
+    }
+    Some(Self { parts, prerelease })
+  }
+
+  /// The number of the leading present parts.
+  fn precision(&self) -> usize {
+    self.parts.iter().take_while(|part| part.is_some()).count()
+  }
+
+  /// The lowest version matched by the partial version.
+  fn floor(&self) -> Version {
+    let precision = self.precision();
+    let mut parts = [0; 3];
+    for (index, part) in self.parts.iter().take(precision).enumerate() {
+      parts[index] = part.unwrap_or_default();
+    }
+    Version {
+      parts,
+      prerelease: if precision == 3 {
+        self.prerelease.clone()
+      } else {
+        vec![]
+      },
+    }
+  }
+
+  /// The lowest version greater than every version matched by the partial version, `None` if
+  /// it's unbounded.
+  fn ceil(&self, precision: usize) -> Option<Version> {
+    if precision == 0 {
+      return None;
+    }
+    let mut parts = [0; 3];
+    for (index, part) in self.parts.iter().take(precision).enumerate() {
+      parts[index] = part.unwrap_or_default();
+    }
+    parts[precision - 1] += 1;
+    Some(Version::new(parts))
+  }
+}
+
+#[derive(Debug)]
+enum Comparator {
+  Greater(Version),
+  GreaterOrEqual(Version),
+  Less(Version),
+  LessOrEqual(Version),
+  Equal(Version),
+}
+
+impl Comparator {
+  fn test(&self, version: &Version) -> bool {
+    match self {
+      Comparator::Greater(bound) => version > bound,
+      Comparator::GreaterOrEqual(bound) => version >= bound,
+      Comparator::Less(bound) => version < bound,
+      Comparator::LessOrEqual(bound) => version <= bound,
+      Comparator::Equal(bound) => version == bound,
+    }
+  }
+
+  fn version(&self) -> &Version {
+    match self {
+      Comparator::Greater(version)
+      | Comparator::GreaterOrEqual(version)
+      | Comparator::Less(version)
+      | Comparator::LessOrEqual(version)
+      | Comparator::Equal(version) => version,
+    }
+  }
+}
+
+/// Whether the version satisfies the npm style range, e.g. `^1.2.0`, `>=1.0.0 <2.0.0 || 3.x`.
+///
+/// Returns `None` if the version isn't a full `major.minor.patch` version, or the range can't
+/// be parsed and none of its parsable comparator sets is satisfied, e.g. `workspace:*`.
+pub fn satisfy(range: &str, version: &str) -> Option<bool> {
+  let version = PartialVersion::parse(version)
+    .filter(|version| version.precision() == 3)?
+    .floor();
+  let mut parsed = true;
+  for set in range.split("||") {
+    let Some(comparators) = parse_comparator_set(set) else {
+      parsed = false;
+      continue;
+    };
+    if comparators
+      .iter()
+      .all(|comparator| comparator.test(&version))
+      // a prerelease only satisfies the comparators with a prerelease of the same version
+      && (version.prerelease.is_empty()
+        || comparators.iter().any(|comparator| {
+          let bound = comparator.version();
+          !bound.prerelease.is_empty() && bound.parts == version.parts
+        }))
+    {
+      return Some(true);
+    }
+  }
+  parsed.then_some(false)
+}
+
+fn parse_comparator_set(set: &str) -> Option<Vec<Comparator>> {
+  let set = set.trim();
+  if let Some((from, to)) = set.split_once(" - ") {
+    let from = PartialVersion::parse(from)?;
+    let to = PartialVersion::parse(to)?;
+    let mut comparators = vec![Comparator::GreaterOrEqual(from.floor())];
+    let precision = to.precision();
+    if precision == 3 {
+      comparators.push(Comparator::LessOrEqual(to.floor()));
+    } else if let Some(ceil) = to.ceil(precision) {
+      comparators.push(Comparator::Less(ceil));
+    }
+    return Some(comparators);
+  }
+
+  // join the operators separated from their versions, like `>= 1.0.0`
+  let mut tokens: Vec<String> = vec![];
+  for token in set.split_whitespace() {
+    match tokens.last_mut() {
+      Some(last)
+        if last
+          .chars()
+          .all(|c| matches!(c, '<' | '>' | '=' | '^' | '~')) =>
+      {
+        last.push_str(token)
+      }
+      _ => tokens.push(token.to_string()),
+    }
+  }
+
+  let mut comparators = vec![];
+  for token in tokens {
+    let operator_len = token
+      .find(|c: char| !matches!(c, '<' | '>' | '=' | '^' | '~'))
+      .unwrap_or(token.len());
+    let (operator, version) = token.split_at(operator_len);
+    if matches!(version, "" | "*" | "x" | "X" | "latest") {
+      continue;
+    }
+    let version = PartialVersion::parse(version)?;
+    let precision = version.precision();
+    let floor = version.floor();
+    match operator {
+      "^" => {
+        // the first non-zero part can't be changed
+        let fixed = version
+          .parts
+          .iter()
+          .take(precision)
+          .position(|part| *part != Some(0))
+          .unwrap_or(precision.saturating_sub(1))
+          + 1;
+        comparators.push(Comparator::GreaterOrEqual(floor));
+        comparators.extend(version.ceil(fixed.min(precision)).map(Comparator::Less));
+      }
+      "~" | "~>" => {
+        comparators.push(Comparator::GreaterOrEqual(floor));
+        comparators.extend(
+          version
+            .ceil(if precision == 1 { 1 } else { precision.min(2) })
+            .map(Comparator::Less),
+        );
+      }
+      ">" if precision < 3 => {
+        comparators.extend(version.ceil(precision).map(Comparator::GreaterOrEqual));
+      }
+      ">" => comparators.push(Comparator::Greater(floor)),
+      ">=" => comparators.push(Comparator::GreaterOrEqual(floor)),
+      "<" => comparators.push(Comparator::Less(floor)),
+      "<=" if precision < 3 => {
+        comparators.extend(version.ceil(precision).map(Comparator::Less));
+      }
+      "<=" => comparators.push(Comparator::LessOrEqual(floor)),
+      "" | "=" if precision < 3 => {
+        comparators.push(Comparator::GreaterOrEqual(floor));
+        comparators.extend(version.ceil(precision).map(Comparator::Less));
+      }
+      "" | "=" => comparators.push(Comparator::Equal(floor)),
+      _ => return None,
+    }
+  }
+  Some(comparators)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::satisfy;
+
+  #[test]
+  fn test_satisfy() {
+    let cases = [
+      // exact
+      ("1.2.3", "1.2.3", true),
+      ("=1.2.3", "v1.2.3", true),
+      ("1.2.3", "1.2.3+build.1", true),
+      ("1.2.3", "1.2.4", false),
+      // caret
+      ("^1.2.3", "1.9.9", true),
+      ("^1.2.3", "1.2.2", false),
+      ("^1.2.3", "2.0.0", false),
+      ("^0.2.3", "0.2.9", true),
+      ("^0.2.3", "0.3.0", false),
+      ("^0.0.3", "0.0.3", true),
+      ("^0.0.3", "0.0.4", false),
+      ("^0.0", "0.0.9", true),
+      ("^0.0", "0.1.0", false),
+      ("^1.x", "1.5.0", true),
+      ("^1.x", "2.0.0", false),
+      ("^0.x", "0.9.0", true),
+      ("^0.x", "1.0.0", false),
+      // tilde
+      ("~1.2.3", "1.2.9", true),
+      ("~1.2.3", "1.3.0", false),
+      ("~1.2", "1.2.0", true),
+      ("~1.2", "1.3.0", false),
+      ("~1", "1.9.0", true),
+      ("~1", "2.0.0", false),
+      ("~0.2.3", "0.2.5", true),
+      ("~>1.2", "1.2.5", true),
+      // x-ranges
+      ("*", "1.2.3", true),
+      ("", "1.2.3", true),
+      ("x", "0.0.1", true),
+      ("1.x", "1.0.0", true),
+      ("1.x", "2.0.0", false),
+      ("1.2.*", "1.2.9", true),
+      ("1.2.X", "1.3.0", false),
+      ("1", "1.9.9", true),
+      ("1.2", "1.3.0", false),
+      // comparators
+      (">1.2.3", "1.2.4", true),
+      (">1.2.3", "1.2.3", false),
+      (">1.2", "1.2.9", false),
+      (">1.2", "1.3.0", true),
+      (">=1.2", "1.2.0", true),
+      ("<1.2", "1.1.9", true),
+      ("<1.2", "1.2.0", false),
+      ("<=1.2", "1.2.9", true),
+      ("<=1.2", "1.3.0", false),
+      (">= 1.2.3 < 2", "1.5.0", true),
+      (">= 1.2.3 < 2", "2.0.0", false),
+      // hyphen ranges
+      ("1.2.3 - 2.3.4", "1.2.3", true),
+      ("1.2.3 - 2.3.4", "2.3.4", true),
+      ("1.2.3 - 2.3.4", "2.3.5", false),
+      ("1.2 - 2.3", "2.3.9", true),
+      ("1.2 - 2.3", "2.4.0", false),
+      ("1.2.3 - 2", "2.9.9", true),
+      ("1.2.3 - 2", "1.2.2", false),
+      // unions
+      ("^1.0.0 || ^3.0.0", "3.1.0", true),
+      ("^1.0.0 || ^3.0.0", "2.1.0", false),
+      ("<1.0.0 || >=2.3.1 <2.4.5", "2.4.0", true),
+      ("<1.0.0 || >=2.3.1 <2.4.5", "1.5.0", false),
+      // prereleases
+      ("^1.2.3-beta.2", "1.2.3-beta.4", true),
+      ("^1.2.3-beta.2", "1.2.3-beta.10", true),
+      ("^1.2.3-beta.2", "1.2.3-beta.1", false),
+      ("^1.2.3-beta.2", "1.2.3", true),
+      ("^1.2.3-beta.2", "1.2.4-beta.2", false),
+      (">1.2.3-alpha.3", "1.2.3-alpha.7", true),
+      (">1.2.3-alpha.3", "1.2.3-alpha.beta", true),
+      ("1.2.3-alpha", "1.2.3-alpha", true),
+      ("^1.2.3", "1.2.3-beta", false),
+      ("^1.2.3", "1.3.0-beta", false),
+      ("1.x", "1.2.3-alpha", false),
+      ("*", "1.2.3-alpha", false),
+      ("latest", "1.2.3", true),
+    ];
+    for (range, version, expected) in cases {
+      assert_eq!(
+        satisfy(range, version),
+        Some(expected),
+        "satisfy({range:?}, {version:?})"
+      );
+    }
+  }
+
+  #[test]
+  fn test_satisfy_unparsable() {
+    let cases = [
+      ("^1.2.3", "1.2"),
+      ("^1.2.3", "latest"),
+      ("^a", "1.0.0"),
+      ("!1.0.0", "1.0.0"),
+      ("workspace:*", "1.0.0"),
+      ("workspace:* || ^2.0.0", "1.0.0"),
+    ];
+    for (range, version) in cases {
+      assert_eq!(
+        satisfy(range, version),
+        None,
+        "satisfy({range:?}, {version:?})"
+      );
+    }
+    // a parsable comparator set is still matched
+    assert_eq!(satisfy("workspace:* || ^1.0.0", "1.2.0"), Some(true));
+  }
+}
@@ -0,0 +1,422 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use cow_utils::CowUtils;
+use rspack_util::fx_hash::FxHashMap;
+use url::Url;
+
+use super::http_cache::{HttpClient, HttpResponse};
+use crate::http_uri::HttpUriPluginOptions;
+
+/// Proxies used to fetch the remote resources.
+#[derive(Debug, Clone, Default)]
+pub struct HttpUriProxyOptions {
+  /// Proxy of the `http:` urls, like the `HTTP_PROXY` environment variable.
+  pub http: Option<String>,
+  /// Proxy of the `https:` urls, like the `HTTPS_PROXY` environment variable.
+  pub https: Option<String>,
+  /// Hosts fetched without the proxy, in the format of the `NO_PROXY` environment variable.
+  pub no_proxy: Vec<String>,
+}
+
+impl HttpUriProxyOptions {
+  /// Create the proxy options, `no_proxy` is a list of hosts separated by commas or spaces.
+  pub fn new(http: Option<String>, https: Option<String>, no_proxy: Option<&str>) -> Self {
+    let no_proxy = no_proxy
+      .unwrap_or_default()
+      .split([',', ' '])
+      .map(|host| host.trim().cow_to_ascii_lowercase().into_owned())
+      .filter(|host| !host.is_empty())
+      .collect();
+    Self {
+      http,
+      https,
+      no_proxy,
+    }
+  }
+
+  /// Get the proxy for the scheme of the url, `None` if the host of the url matches `no_proxy`.
+  pub fn proxy_for(&self, url: &str) -> Option<&str> {
+    let Ok(url) = Url::parse(url) else {
+      return self.https.as_deref();
+    };
+    let proxy = if url.scheme() == "http" {
+      self.http.as_deref()?
+    } else {
+      self.https.as_deref()?
+    };
+    let host = url.host_str().unwrap_or_default().cow_to_ascii_lowercase();
+    // the brackets of ipv6 hosts are not part of the `NO_PROXY` entries
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let port = url.port_or_known_default();
+
+    let bypass = self.no_proxy.iter().any(|entry| {
+      if entry == "*" {
+        return true;
+      }
+      let (entry_host, entry_port) = match entry.rsplit_once(':') {
+        Some((entry_host, entry_port))
+          if !entry_host.contains(':') || entry_host.ends_with(']') =>
+        {
+          (entry_host, entry_port.parse::<u16>().ok())
+        }
+        _ => (entry.as_str(), None),
+      };
+      if entry_port.is_some() && entry_port != port {
+        return false;
+      }
+      let entry_host = entry_host
+        .trim_start_matches("*.")
+        .trim_start_matches('.')
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+      host == entry_host
+        || host
+          .strip_suffix(entry_host)
+          .is_some_and(|prefix| prefix.ends_with('.'))
+    });
+    (!bypass).then_some(proxy)
+  }
+}
+
+/// Retry policy of the failed requests, a request is retried when the [`HttpClient`]
+/// fails, or the response status is `429` or `5xx`.
+#[derive(Debug, Clone)]
+pub struct HttpUriRetryOptions {
+  /// The max number of retries, `0` to disable retrying.
+  pub retries: u32,
+  /// The delay before the first retry, doubled for each of the next retries.
+  pub delay: Duration,
+  /// The upper bound of the delay, also applied to the `retry-after` header.
+  pub max_delay: Duration,
+}
+
+impl Default for HttpUriRetryOptions {
+  fn default() -> Self {
+    Self {
+      retries: 0,
+      delay: Duration::from_millis(500),
+      max_delay: Duration::from_secs(10),
+    }
+  }
+}
+
+impl HttpUriRetryOptions {
+  fn backoff(&self, attempt: u32, response: Option<&HttpResponse>) -> Duration {
+    let retry_after = response
+      .and_then(|response| response.headers.get("retry-after"))
+      .and_then(|retry_after| retry_after.trim().parse::<u64>().ok())
+      .map(Duration::from_secs);
+    retry_after
+      .unwrap_or_else(|| self.delay.saturating_mul(2u32.saturating_pow(attempt)))
+      .min(self.max_delay)
+  }
+}
+
+fn should_retry(status: u16) -> bool {
+  status == 429 || (500..=599).contains(&status)
+}
+
+/// Get the headers configured for the origin of the url.
+///
+/// Several keys may serialize to the same origin, e.g. `https://a.com` and `https://a.com/`,
+/// the smallest key wins then so that the result doesn't depend on the order of the map.
+fn origin_headers<'a>(
+  url: &str,
+  headers: &'a FxHashMap<String, FxHashMap<String, String>>,
+) -> Option<&'a FxHashMap<String, String>> {
+  let origin = Url::parse(url).ok()?.origin().ascii_serialization();
+  headers
+    .iter()
+    .filter(|(key, _)| {
+      let key_origin = Url::parse(key)
+        .map(|key| key.origin().ascii_serialization())
+        .unwrap_or_else(|_| key.trim_end_matches('/').to_string());
+      key_origin == origin
+    })
+    .min_by_key(|(key, _)| key.as_str())
+    .map(|(_, headers)| headers)
+}
+
+/// Send a request with the [`HttpClient`], applying the proxy, the headers of the origin and
+/// the retry policy of the options.
+pub async fn send_request(
+  url: &str,
+  mut headers: FxHashMap<String, String>,
+  client: &dyn HttpClient,
+  options: &HttpUriPluginOptions,
+) -> Result<HttpResponse> {
+  if let Some(origin_headers) = origin_headers(url, &options.headers) {
+    for (key, value) in origin_headers {
+      headers.insert(key.cow_to_ascii_lowercase().into_owned(), value.clone());
+    }
+  }
+  let proxy = options
+    .proxy
+    .as_ref()
+    .and_then(|proxy| proxy.proxy_for(url));
+
+  let retry = &options.retry;
+  let mut attempt = 0;
+  loop {
+    let result = client.get(url, &headers, proxy).await;
+    let retryable = match &result {
+      Ok(response) => should_retry(response.status),
+      Err(_) => true,
+    };
+    if !retryable || attempt >= retry.retries {
+      return result.map_err(|e| {
+        if attempt > 0 {
+          anyhow::anyhow!("Failed to fetch {url} after {} attempts: {e}", attempt + 1)
+        } else {
+          e
+        }
+      });
+    }
+    tokio::time::sleep(retry.backoff(attempt, result.as_ref().ok())).await;
+    attempt += 1;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicUsize, Ordering},
+  };
+
+  use async_trait::async_trait;
+  use napi::bindgen_prelude::Buffer;
+
+  use super::*;
+  use crate::http_uri::HttpUriOptionsAllowedUris;
+
+  #[derive(Debug, Default)]
+  struct MockServer {
+    /// The responses of each request in order, the last one is repeated.
+    statuses: Vec<u16>,
+    requests: AtomicUsize,
+    received: Mutex<Vec<(FxHashMap<String, String>, Option<String>)>>,
+  }
+
+  #[async_trait]
+  impl HttpClient for MockServer {
+    async fn get(
+      &self,
+      _url: &str,
+      headers: &FxHashMap<String, String>,
+      proxy: Option<&str>,
+    ) -> Result<HttpResponse> {
+      let index = self.requests.fetch_add(1, Ordering::SeqCst);
+      self
+        .received
+        .lock()
+        .expect("should lock received")
+        .push((headers.clone(), proxy.map(str::to_string)));
+      let status = *self
+        .statuses
+        .get(index)
+        .or(self.statuses.last())
+        .expect("should have status");
+      if status == 0 {
+        anyhow::bail!("connection reset");
+      }
+      Ok(HttpResponse {
+        status,
+        headers: Default::default(),
+        body: Buffer::from(vec![]),
+      })
+    }
+  }
+
+  fn options(server: Arc<MockServer>) -> HttpUriPluginOptions {
+    HttpUriPluginOptions {
+      allowed_uris: HttpUriOptionsAllowedUris::new(
+        rspack_util::asset_condition::AssetConditions::Multiple(vec![]),
+      ),
+      lockfile_location: None,
+      cache_location: None,
+      upgrade: false,
+      frozen: false,
+      offline: false,
+      proxy: None,
+      headers: Default::default(),
+      retry: HttpUriRetryOptions {
+        retries: 2,
+        delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(10),
+      },
+      filesystem: Arc::new(rspack_fs::MemoryFileSystem::default()),
+      http_client: server,
+    }
+  }
+
+  #[test]
+  fn test_no_proxy() {
+    let proxy = HttpUriProxyOptions::new(
+      None,
+      Some("http://proxy.corp:3128".to_string()),
+      Some("localhost, .internal.corp,registry.corp:8443 [::1]"),
+    );
+    assert_eq!(
+      proxy.proxy_for("https://unpkg.com/a.js"),
+      Some("http://proxy.corp:3128")
+    );
+    assert_eq!(proxy.proxy_for("https://localhost:8080/a.js"), None);
+    assert_eq!(proxy.proxy_for("https://[::1]:8080/a.js"), None);
+    assert_eq!(proxy.proxy_for("https://internal.corp/a.js"), None);
+    assert_eq!(proxy.proxy_for("https://cdn.internal.corp/a.js"), None);
+    assert_eq!(
+      proxy.proxy_for("https://notinternal.corp/a.js"),
+      Some("http://proxy.corp:3128")
+    );
+    assert_eq!(proxy.proxy_for("https://registry.corp:8443/a.js"), None);
+    assert_eq!(
+      proxy.proxy_for("https://registry.corp/a.js"),
+      Some("http://proxy.corp:3128")
+    );
+
+    let proxy =
+      HttpUriProxyOptions::new(None, Some("http://proxy.corp:3128".to_string()), Some("*"));
+    assert_eq!(proxy.proxy_for("https://unpkg.com/a.js"), None);
+  }
+
+  #[test]
+  fn test_proxy_of_scheme() {
+    let proxy = HttpUriProxyOptions::new(
+      Some("http://http-proxy.corp:3128".to_string()),
+      Some("http://https-proxy.corp:3128".to_string()),
+      None,
+    );
+    assert_eq!(
+      proxy.proxy_for("http://unpkg.com/a.js"),
+      Some("http://http-proxy.corp:3128")
+    );
+    assert_eq!(
+      proxy.proxy_for("https://unpkg.com/a.js"),
+      Some("http://https-proxy.corp:3128")
+    );
+
+    // only `HTTP_PROXY` is set
+    let proxy =
+      HttpUriProxyOptions::new(Some("http://http-proxy.corp:3128".to_string()), None, None);
+    assert_eq!(
+      proxy.proxy_for("http://unpkg.com/a.js"),
+      Some("http://http-proxy.corp:3128")
+    );
+    assert_eq!(proxy.proxy_for("https://unpkg.com/a.js"), None);
+  }
+
+  #[test]
+  fn test_origin_headers() {
+    let headers = FxHashMap::from_iter([
+      (
+        "https://registry.corp/".to_string(),
+        FxHashMap::from_iter([("authorization".to_string(), "slash".to_string())]),
+      ),
+      (
+        "https://registry.corp".to_string(),
+        FxHashMap::from_iter([("authorization".to_string(), "origin".to_string())]),
+      ),
+      (
+        "https://other.corp".to_string(),
+        FxHashMap::from_iter([("authorization".to_string(), "other".to_string())]),
+      ),
+    ]);
+    assert_eq!(
+      origin_headers("https://registry.corp/a.js", &headers)
+        .and_then(|headers| headers.get("authorization"))
+        .map(String::as_str),
+      Some("origin")
+    );
+    assert_eq!(origin_headers("http://registry.corp/a.js", &headers), None);
+  }
+
+  #[tokio::test]
+  async fn test_origin_headers_and_proxy() {
+    let server = Arc::new(MockServer {
+      statuses: vec![200],
+      ..Default::default()
+    });
+    let mut options = options(server.clone());
+    options.proxy = Some(HttpUriProxyOptions::new(
+      Some("http://proxy.corp:3128".to_string()),
+      Some("http://proxy.corp:3128".to_string()),
+      Some("localhost"),
+    ));
+    options.headers.insert(
+      "https://registry.corp/".to_string(),
+      FxHashMap::from_iter([("Authorization".to_string(), "Bearer token".to_string())]),
+    );
+
+    let headers = FxHashMap::from_iter([("user-agent".to_string(), "webpack".to_string())]);
+    send_request(
+      "https://registry.corp/a.js",
+      headers.clone(),
+      server.as_ref(),
+      &options,
+    )
+    .await
+    .unwrap();
+    send_request("http://localhost/a.js", headers, server.as_ref(), &options)
+      .await
+      .unwrap();
+
+    let received = server.received.lock().unwrap();
+    assert_eq!(received[0].0.get("authorization").unwrap(), "Bearer token");
+    assert_eq!(received[0].0.get("user-agent").unwrap(), "webpack");
+    assert_eq!(received[0].1.as_deref(), Some("http://proxy.corp:3128"));
+    assert!(!received[1].0.contains_key("authorization"));
+    assert_eq!(received[1].1, None);
+  }
+
+  #[tokio::test]
+  async fn test_retry() {
+    // recovers after a failed connection and a 503
+    let server = Arc::new(MockServer {
+      statuses: vec![0, 503, 200],
+      ..Default::default()
+    });
+    let response = send_request(
+      "https://unpkg.com/a.js",
+      Default::default(),
+      server.as_ref(),
+      &options(server.clone()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status, 200);
+    assert_eq!(server.requests.load(Ordering::SeqCst), 3);
+
+    // the retries are bounded
+    let server = Arc::new(MockServer {
+      statuses: vec![502],
+      ..Default::default()
+    });
+    let response = send_request(
+      "https://unpkg.com/a.js",
+      Default::default(),
+      server.as_ref(),
+      &options(server.clone()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status, 502);
+    assert_eq!(server.requests.load(Ordering::SeqCst), 3);
+
+    // client errors are not retried
+    let server = Arc::new(MockServer {
+      statuses: vec![404],
+      ..Default::default()
+    });
+    send_request(
+      "https://unpkg.com/a.js",
+      Default::default(),
+      server.as_ref(),
+      &options(server.clone()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(server.requests.load(Ordering::SeqCst), 1);
+  }
+}
@@ -11,7 +11,10 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha512};
 use url::Url;
 
-use super::lockfile::{LockfileCache, LockfileEntry};
+use super::{
+  client::send_request,
+  lockfile::{LockfileCache, LockfileEntry},
+};
 use crate::http_uri::HttpUriPluginOptions;
 
 /// This enum is used for avoiding [Buffer::to_vec] overhead
@@ -37,7 +40,13 @@ pub struct HttpResponse {
 
 #[async_trait]
 pub trait HttpClient: Send + Sync + std::fmt::Debug {
-  async fn get(&self, url: &str, headers: &FxHashMap<String, String>) -> Result<HttpResponse>;
+  /// Send a `GET` request, through the `proxy` if it's provided.
+  async fn get(
+    &self,
+    url: &str,
+    headers: &FxHashMap<String, String>,
+    proxy: Option<&str>,
+  ) -> Result<HttpResponse>;
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -107,21 +116,60 @@ impl HttpCache {
     url: &str,
     options: &HttpUriPluginOptions,
   ) -> Result<FetchResultType> {
+    let locked_entry = if options.frozen {
+      let lockfile = self.lockfile_cache.get_lockfile().await?;
+      let lock_guard = lockfile.lock().await;
+      let Some(entry) = lock_guard.get_entry(url).cloned() else {
+        return Err(anyhow::anyhow!(
+          "{url} is not in the lockfile, but the lockfile is frozen. Build without frozen to add it to the lockfile"
+        ));
+      };
+      Some(entry)
+    } else {
+      None
+    };
+
     let cached_result = self.read_from_cache(url).await?;
 
-    if let Some(ref cached) = cached_result
-      && (!options.upgrade || cached.meta.fresh)
+    let result = if let Some(cached) = cached_result
+      && (options.offline || !options.upgrade || cached.meta.fresh)
+    {
+      FetchResultType::Content(cached)
+    } else if options.offline {
+      // the cache is the only source in offline mode, a miss would have required the network
+      return Err(match &self.cache_location {
+        Some(cache_location) => anyhow::anyhow!(
+          "{url} is not in the cache at {}, fetching it requires the network which is not allowed in offline mode",
+          cache_location.display()
+        ),
+        None => anyhow::anyhow!(
+          "{url} can't be served in offline mode, because cacheLocation is disabled"
+        ),
+      });
+    } else {
+      self.fetch_content_raw(url, cached_result, options).await?
+    };
+
+    if let Some(locked_entry) = locked_entry
+      && let FetchResultType::Content(content) = &result
     {
-      return Ok(FetchResultType::Content(cached.clone()));
+      let integrity = compute_integrity(content.content());
+      if integrity != locked_entry.integrity {
+        return Err(anyhow::anyhow!(
+          "{url} has a different integrity than the frozen lockfile\nExpected: {}\nReceived: {integrity}",
+          locked_entry.integrity
+        ));
+      }
     }
 
-    self.fetch_content_raw(url, cached_result).await
+    Ok(result)
   }
 
   async fn fetch_content_raw(
     &self,
     url: &str,
     cached_result: Option<ContentFetchResult>,
+    options: &HttpUriPluginOptions,
   ) -> Result<FetchResultType> {
     let request_time = current_time();
     let mut headers = FxHashMap::default();
@@ -139,7 +187,7 @@ impl HttpCache {
       headers.insert("if-none-match".to_string(), etag.clone());
     }
 
-    let response = self.http_client.get(url, &headers).await?;
+    let response = send_request(url, headers, self.http_client.as_ref(), options).await?;
     let status = response.status;
     let headers = response.headers;
     let etag = headers.get("etag").cloned();
@@ -264,6 +312,11 @@ impl HttpCache {
           self.write_to_cache(url, result.content()).await?;
         }
 
+        // the frozen lockfile is never modified, a changed content is reported by the caller
+        if options.frozen {
+          return Ok(FetchResultType::Content(result));
+        }
+
         let lockfile = self.lockfile_cache.get_lockfile().await?;
         let mut lock_guard = lockfile.lock().await;
 
@@ -474,3 +527,153 @@ fn compute_integrity(content: &[u8]) -> String {
   // Use base64 for integrity as that's the standard format
   format!("sha512-{}", base64::encode_to_string(digest))
 }
+
+#[cfg(test)]
+mod tests {
+  use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+  };
+
+  use rspack_fs::MemoryFileSystem;
+
+  use super::*;
+  use crate::http_uri::{HttpUriOptionsAllowedUris, HttpUriRetryOptions};
+
+  const URL: &str = "https://unpkg.com/a.js";
+  const LOCKFILE: &str = "/project/rspack.lock";
+
+  #[derive(Debug, Default)]
+  struct MockServer {
+    requests: AtomicUsize,
+  }
+
+  #[async_trait]
+  impl HttpClient for MockServer {
+    async fn get(
+      &self,
+      _url: &str,
+      _headers: &FxHashMap<String, String>,
+      _proxy: Option<&str>,
+    ) -> Result<HttpResponse> {
+      self.requests.fetch_add(1, Ordering::SeqCst);
+      Ok(HttpResponse {
+        status: 200,
+        headers: Default::default(),
+        body: Buffer::from(b"export default 1".to_vec()),
+      })
+    }
+  }
+
+  async fn frozen_cache(
+    entries: serde_json::Value,
+  ) -> (
+    HttpCache,
+    HttpUriPluginOptions,
+    Arc<MockServer>,
+    Arc<MemoryFileSystem>,
+  ) {
+    let filesystem = Arc::new(MemoryFileSystem::default());
+    filesystem
+      .create_dir_all(Utf8Path::new("/project"))
+      .await
+      .unwrap();
+    filesystem
+      .write(
+        Utf8Path::new(LOCKFILE),
+        serde_json::json!({ "version": 1, "entries": entries })
+          .to_string()
+          .as_bytes(),
+      )
+      .await
+      .unwrap();
+    let server = Arc::new(MockServer::default());
+    let options = HttpUriPluginOptions {
+      allowed_uris: HttpUriOptionsAllowedUris::new(
+        rspack_util::asset_condition::AssetConditions::Multiple(vec![]),
+      ),
+      lockfile_location: Some(LOCKFILE.to_string()),
+      cache_location: None,
+      upgrade: false,
+      frozen: true,
+      offline: false,
+      proxy: None,
+      headers: Default::default(),
+      retry: HttpUriRetryOptions {
+        retries: 0,
+        delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(1),
+      },
+      filesystem: filesystem.clone(),
+      http_client: server.clone(),
+    };
+    let cache = HttpCache::new(
+      None,
+      Some(LOCKFILE.to_string()),
+      filesystem.clone(),
+      server.clone(),
+    );
+    (cache, options, server, filesystem)
+  }
+
+  async fn read_lockfile(filesystem: &MemoryFileSystem) -> String {
+    String::from_utf8(filesystem.read_file(Utf8Path::new(LOCKFILE)).await.unwrap()).unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_frozen_missing_url() {
+    let (cache, options, server, filesystem) = frozen_cache(serde_json::json!({})).await;
+    let lockfile = read_lockfile(&filesystem).await;
+
+    let Err(error) = cache.fetch_content(URL, &options).await else {
+      panic!("should fail for a url missing from the frozen lockfile");
+    };
+    assert!(
+      error
+        .to_string()
+        .contains("is not in the lockfile, but the lockfile is frozen")
+    );
+    // the url is never fetched and the lockfile is untouched
+    assert_eq!(server.requests.load(Ordering::SeqCst), 0);
+    assert_eq!(read_lockfile(&filesystem).await, lockfile);
+  }
+
+  #[tokio::test]
+  async fn test_frozen_integrity_mismatch() {
+    let (cache, options, server, filesystem) = frozen_cache(serde_json::json!({
+      URL: { "integrity": "sha512-outdated", "content_type": "", "valid_until": 0 }
+    }))
+    .await;
+    let lockfile = read_lockfile(&filesystem).await;
+
+    let Err(error) = cache.fetch_content(URL, &options).await else {
+      panic!("should fail for a content not matching the frozen lockfile");
+    };
+    let error = error.to_string();
+    assert!(error.contains("has a different integrity than the frozen lockfile"));
+    assert!(error.contains("Expected: sha512-outdated"));
+    assert!(error.contains(&format!(
+      "Received: {}",
+      compute_integrity(b"export default 1")
+    )));
+    assert_eq!(server.requests.load(Ordering::SeqCst), 1);
+    assert_eq!(read_lockfile(&filesystem).await, lockfile);
+  }
+
+  #[tokio::test]
+  async fn test_frozen_integrity_match() {
+    let (cache, options, _, _) = frozen_cache(serde_json::json!({
+      URL: {
+        "integrity": compute_integrity(b"export default 1"),
+        "content_type": "",
+        "valid_until": 0
+      }
+    }))
+    .await;
+
+    let Ok(FetchResultType::Content(result)) = cache.fetch_content(URL, &options).await else {
+      panic!("should fetch a content matching the frozen lockfile");
+    };
+    assert_eq!(result.content(), b"export default 1");
+  }
+}
@@ -1,8 +1,10 @@
+mod client;
 mod http_cache;
 mod lockfile;
 
 use std::{fmt::Debug, sync::Arc};
 
+pub use client::{HttpUriProxyOptions, HttpUriRetryOptions};
 use http_cache::{ContentFetchResult, FetchResultType, fetch_content};
 pub use http_cache::{HttpClient, HttpResponse};
 use once_cell::sync::Lazy;
@@ -14,7 +16,10 @@ use rspack_core::{
 use rspack_error::{AnyhowResultToRspackResultExt, Result, error};
 use rspack_fs::{ReadableFileSystem, WritableFileSystem};
 use rspack_hook::{plugin, plugin_hook};
-use rspack_util::asset_condition::{AssetCondition, AssetConditions};
+use rspack_util::{
+  asset_condition::{AssetCondition, AssetConditions},
+  fx_hash::FxHashMap,
+};
 use url::Url;
 
 static EXTERNAL_HTTP_REQUEST: Lazy<Regex> =
@@ -161,8 +166,15 @@ pub struct HttpUriPluginOptions {
   pub lockfile_location: Option<String>,
   pub cache_location: Option<String>,
   pub upgrade: bool,
-  // pub proxy: Option<String>,
-  // pub frozen: Option<bool>,
+  pub proxy: Option<HttpUriProxyOptions>,
+  /// Extra request headers of each origin, e.g. the credentials of a private registry.
+  pub headers: FxHashMap<String, FxHashMap<String, String>>,
+  pub retry: HttpUriRetryOptions,
+  /// Fail when a url is missing from the lockfile or its content doesn't match the integrity,
+  /// the lockfile is never modified.
+  pub frozen: bool,
+  /// Serve only from the cache, fail for every url that would require the network.
+  pub offline: bool,
   pub filesystem: Arc<dyn WritableFileSystem>,
   pub http_client: Arc<dyn HttpClient>,
 }
@@ -1,3 +1,5 @@
+mod mangle_properties;
+
 use std::{
   collections::HashMap,
   hash::Hash,
@@ -9,9 +11,10 @@ use cow_utils::CowUtils;
 use once_cell::sync::OnceCell;
 use rayon::prelude::*;
 use regex::Regex;
+use rspack_collections::Identifier;
 use rspack_core::{
   AssetInfo, ChunkUkey, Compilation, CompilationAsset, CompilationParams, CompilationProcessAssets,
-  CompilerCompilation, Plugin,
+  CompilerCompilation, MinimizedAsset, Plugin,
   diagnostics::MinifyError,
   rspack_sources::{
     ConcatSource, MapOptions, ObjectPool, RawStringSource, Source, SourceExt, SourceMapSource,
@@ -35,6 +38,9 @@ pub use swc_ecma_minifier::option::{
 };
 use thread_local::ThreadLocal;
 
+pub use self::mangle_properties::MangleProperties;
+use self::mangle_properties::PropertyMangler;
+
 const PLUGIN_NAME: &str = "rspack.SwcJsMinimizerRspackPlugin";
 
 static JAVASCRIPT_ASSET_REGEXP: LazyLock<Regex> =
@@ -46,6 +52,7 @@ pub struct PluginOptions {
   pub include: Option<AssetConditions>,
   pub exclude: Option<AssetConditions>,
   pub extract_comments: Option<ExtractComments>,
+  pub mangle_properties: Option<MangleProperties>,
   pub minimizer_options: MinimizerOptions,
 }
 
@@ -124,11 +131,12 @@ struct NormalizedExtractComments<'a> {
 #[derive(Debug)]
 pub struct SwcJsMinimizerRspackPlugin {
   options: PluginOptions,
+  property_mangler: PropertyMangler,
 }
 
 impl SwcJsMinimizerRspackPlugin {
   pub fn new(options: PluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, Default::default())
   }
 }
 
@@ -173,9 +181,21 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         .unwrap_or_else(|_| panic!("`{condition}` is invalid extractComments condition"))
     });
   let enter_span = tracing::Span::current();
+  let mangled_properties = match &options.mangle_properties {
+    Some(mangle_properties) => {
+      self
+        .property_mangler
+        .mangle(options, mangle_properties, compilation)
+        .await?
+    }
+    None => None,
+  };
+  let compiler_options = compilation.options.clone();
+  // the minified assets are reused across rebuilds and persisted by the persistent cache
+  let minimize_cache = std::mem::take(&mut compilation.minimize_cache_artifact);
 
   let tls: ThreadLocal<ObjectPool> = ThreadLocal::new();
-  compilation
+  let result = compilation
     .assets_mut()
     .par_iter_mut()
     .filter(|(filename, original)| {
@@ -196,17 +216,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         let object_pool = tls.get_or(ObjectPool::default);
         let input_source_map = original_source.map(object_pool, &MapOptions::default());
 
-        let is_module = if let Some(module) = minimizer_options.module {
-          Some(module)
-        } else if let Some(module) = original.info.javascript_module {
-          Some(module)
-        } else if filename.ends_with(".mjs") {
-          Some(true)
-        } else if filename.ends_with(".cjs") {
-          Some(false)
-        } else {
-          None
-        };
+        let is_module = is_module(minimizer_options, original.get_info(), filename);
 
         let js_minify_options = rspack_javascript_compiler::minify::JsMinifyOptions {
           minify: minimizer_options.minify.unwrap_or(true),
@@ -217,6 +227,9 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           source_map: BoolOrDataConfig::from_bool(input_source_map.is_some()),
           inline_sources_content: true, /* Using true so original_source can be None in SourceMapSource */
           module: is_module,
+          rename_properties: mangled_properties
+            .as_ref()
+            .map(|mangled_properties| mangled_properties.names.clone()),
           ..Default::default()
           };
         let extract_comments_option = options.extract_comments.as_ref().map(|extract_comments| {
@@ -238,84 +251,130 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           }
         });
 
-        let javascript_compiler = JavaScriptCompiler::new();
-        let comments_op = |comments: &SingleThreadedComments| {
-          if let Some(ref extract_comments) = extract_comments_option {
-            let mut extracted_comments = vec![];
-            // add all matched comments to source
-
-            let (leading_trivial, trailing_trivial) = comments.borrow_all();
-
-            leading_trivial.iter().for_each(|(_, comments)| {
-              comments.iter().for_each(|c| {
-                if extract_comments.condition.is_match(&c.text) {
-                  let comment = match c.kind {
-                    CommentKind::Line => {
-                      format!("//{}", c.text)
-                    }
-                    CommentKind::Block => {
-                      format!("/*{}*/", c.text)
+        let mut hasher = RspackHash::from(&compiler_options.output);
+        PLUGIN_NAME.hash(&mut hasher);
+        filename.hash(&mut hasher);
+        is_module.hash(&mut hasher);
+        input_source_map.is_some().hash(&mut hasher);
+        options.hash(&mut hasher);
+        minimizer_options.minify.hash(&mut hasher);
+        format!("{:?}", minimizer_options.ecma).hash(&mut hasher);
+        if let Some(mangled_properties) = &mangled_properties {
+          mangled_properties.hash_asset(filename, &mut hasher);
+        }
+        input.hash(&mut hasher);
+        // the key of the minified result, the input source map isn't part of it since it's only
+        // used as the inner source map of the result
+        let cache_key =
+          Identifier::from(hasher.digest(&compiler_options.output.hash_digest).encoded());
+
+        let minimized = if let Some(minimized) = minimize_cache.get(&cache_key) {
+          minimized
+        } else {
+          let mut extracted_comments = vec![];
+          let javascript_compiler = JavaScriptCompiler::new();
+          let comments_op = |comments: &SingleThreadedComments| {
+            if let Some(ref extract_comments) = extract_comments_option {
+              // add all matched comments to source
+
+              let (leading_trivial, trailing_trivial) = comments.borrow_all();
+
+              leading_trivial.iter().for_each(|(_, comments)| {
+                comments.iter().for_each(|c| {
+                  if extract_comments.condition.is_match(&c.text) {
+                    let comment = match c.kind {
+                      CommentKind::Line => {
+                        format!("//{}", c.text)
+                      }
+                      CommentKind::Block => {
+                        format!("/*{}*/", c.text)
+                      }
+                    };
+                    if !extracted_comments.contains(&comment) {
+                      extracted_comments.push(comment);
                     }
-                  };
-                  if !extracted_comments.contains(&comment) {
-                    extracted_comments.push(comment);
                   }
-                }
+                });
               });
-            });
-            trailing_trivial.iter().for_each(|(_, comments)| {
-              comments.iter().for_each(|c| {
-                if extract_comments.condition.is_match(&c.text) {
-                  let comment = match c.kind {
-                    CommentKind::Line => {
-                      format!("//{}", c.text)
-                    }
-                    CommentKind::Block => {
-                      format!("/*{}*/", c.text)
+              trailing_trivial.iter().for_each(|(_, comments)| {
+                comments.iter().for_each(|c| {
+                  if extract_comments.condition.is_match(&c.text) {
+                    let comment = match c.kind {
+                      CommentKind::Line => {
+                        format!("//{}", c.text)
+                      }
+                      CommentKind::Block => {
+                        format!("/*{}*/", c.text)
+                      }
+                    };
+                    if !extracted_comments.contains(&comment) {
+                      extracted_comments.push(comment);
                     }
-                  };
-                  if !extracted_comments.contains(&comment) {
-                    extracted_comments.push(comment);
                   }
-                }
+                });
               });
-            });
 
-            // if not matched comments, we don't need to emit .License.txt file
-            if !extracted_comments.is_empty() {
               extracted_comments.sort();
-              all_extracted_comments
-                .lock()
-                .expect("all_extract_comments lock failed")
-                .insert(
-                  filename.to_string(),
-                  ExtractedCommentsInfo {
-                    source: RawStringSource::from(extracted_comments.join("\n\n")).boxed(),
-                    comments_file_name: extract_comments.filename.to_string(),
-                  },
-                );
             }
-          }
-        };
+          };
+
+          let output = match javascript_compiler.minify(
+            swc_core::common::FileName::Custom(filename.to_string()),
+            input,
+            js_minify_options,
+            Some(comments_op),
+          ) {
+              Ok(r) => r,
+              Err(e) => {
+                let errors = e.into_inner().into_iter().map(|err| {
+                  let mut d = Diagnostic::from(MinifyError(err));
+                  d.file = Some(filename.into());
+                  d
+                }).collect::<Vec<_>>();
+                tx.send(errors)?;
+                return Ok(())
+              },
+          };
 
-        let mut output = match javascript_compiler.minify(
-          swc_core::common::FileName::Custom(filename.to_string()),
-          input,
-          js_minify_options,
-          Some(comments_op),
-        ) {
-            Ok(r) => r,
-            Err(e) => {
-              let errors = e.into_inner().into_iter().map(|err| {
-                let mut d = Diagnostic::from(MinifyError(err));
-                d.file = Some(filename.into());
-                d
-              }).collect::<Vec<_>>();
-              tx.send(errors)?;
-              return Ok(())
+          let minimized = MinimizedAsset {
+            source: if let Some(source_map) = output.map {
+              SourceMapSource::new(SourceMapSourceOptions {
+                value: output.code,
+                name: filename,
+                source_map,
+                original_source: None,
+                inner_source_map: None,
+                remove_original_source: true,
+              })
+              .boxed()
+            } else {
+              RawStringSource::from(output.code).boxed()
             },
+            extracted_comments,
+          };
+          minimize_cache.set(cache_key, minimized.clone());
+          minimized
         };
 
+        // if not matched comments, we don't need to emit .License.txt file
+        if let Some(extract_comments) = &extract_comments_option
+          && !minimized.extracted_comments.is_empty()
+        {
+          all_extracted_comments
+            .lock()
+            .expect("all_extract_comments lock failed")
+            .insert(
+              filename.to_string(),
+              ExtractedCommentsInfo {
+                source: RawStringSource::from(minimized.extracted_comments.join("\n\n")).boxed(),
+                comments_file_name: extract_comments.filename.to_string(),
+              },
+            );
+        }
+
+        let mut code = minimized.source.source().into_string_lossy().into_owned();
+        let map = minimized.source.map(object_pool, &MapOptions::default());
+
         let banner = if all_extracted_comments
           .lock()
           .expect("all_extract_comments lock failed")
@@ -332,20 +391,20 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
               // 2. There's a shebang, we prepend the shebang, then the banner, then the code.
 
               let mut shebang = None;
-              if output.code.starts_with("#!") {
-                if let Some(line_pos) = output.code.find('\n') {
-                  shebang = Some(output.code[0..line_pos + 1].to_string());
-                  output.code = output.code[line_pos + 1..].to_string();
+              if code.starts_with("#!") {
+                if let Some(line_pos) = code.find('\n') {
+                  shebang = Some(code[0..line_pos + 1].to_string());
+                  code = code[line_pos + 1..].to_string();
                 } else {
                   // Handle shebang without newline - treat entire content as shebang
-                  shebang = Some(output.code.clone());
-                  output.code = String::new();
+                  shebang = Some(code.clone());
+                  code = String::new();
                 }
               }
 
-              let source = if let Some(source_map) = output.map {
+              let source = if let Some(source_map) = map {
                 SourceMapSource::new(SourceMapSourceOptions {
-                  value: output.code,
+                  value: code,
                   name: filename,
                   source_map,
                   original_source: None,
@@ -354,7 +413,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
                 })
                 .boxed()
               } else {
-                RawStringSource::from(output.code).boxed()
+                RawStringSource::from(code).boxed()
               };
 
               if let Some(shebang) = shebang {
@@ -373,10 +432,10 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
               }
             },
             None => {
-              // If there's no banner, we don't need to handle `output.code` at all.
-              if let Some(source_map) = output.map {
+              // If there's no banner, we don't need to handle `code` at all.
+              if let Some(source_map) = map {
                 SourceMapSource::new(SourceMapSourceOptions {
-                  value: output.code,
+                  value: code,
                   name: filename,
                   source_map,
                   original_source: None,
@@ -385,7 +444,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
                 })
                 .boxed()
               } else {
-                RawStringSource::from(output.code).boxed()
+                RawStringSource::from(code).boxed()
               }
             },
         };
@@ -395,7 +454,9 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       }
 
       Ok(())
-  })?;
+  });
+  compilation.minimize_cache_artifact = minimize_cache;
+  result?;
   compilation.extend_diagnostics(rx.into_iter().flatten().collect::<Vec<_>>());
 
   // write all extracted comments to assets
@@ -420,6 +481,24 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   Ok(())
 }
 
+fn is_module(
+  minimizer_options: &MinimizerOptions,
+  info: &AssetInfo,
+  filename: &str,
+) -> Option<bool> {
+  if let Some(module) = minimizer_options.module {
+    Some(module)
+  } else if let Some(module) = info.javascript_module {
+    Some(module)
+  } else if filename.ends_with(".mjs") {
+    Some(true)
+  } else if filename.ends_with(".cjs") {
+    Some(false)
+  } else {
+    None
+  }
+}
+
 pub fn match_object(obj: &PluginOptions, str: &str) -> bool {
   if let Some(condition) = &obj.test {
     if !condition.try_match(str) {
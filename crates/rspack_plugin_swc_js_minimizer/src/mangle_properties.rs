@@ -0,0 +1,404 @@
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  hash::Hash,
+  sync::{Arc, Mutex},
+};
+
+use rayon::prelude::*;
+use regex::Regex;
+use rspack_core::Compilation;
+use rspack_error::{Result, error};
+use rspack_hash::RspackHash;
+use rspack_javascript_compiler::JavaScriptCompiler;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use crate::{PluginOptions, is_module, match_object};
+
+/// The characters of the mangled names, a name can't start with the digits at the end.
+const NAME_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_0123456789";
+const NAME_START_CHARS: usize = 54;
+
+/// Mangle the properties across all the minimized assets.
+#[derive(Debug, Hash)]
+pub struct MangleProperties {
+  /// The properties whose names match the regex are mangled.
+  pub regex: String,
+  /// The properties never mangled.
+  pub reserved: Vec<String>,
+  /// The file storing the mangled names relative to the context, so the names are stable across
+  /// builds.
+  pub name_cache: Option<String>,
+}
+
+/// The new names of the properties used to minimize the assets.
+#[derive(Debug)]
+pub(crate) struct MangledProperties {
+  pub names: Arc<HashMap<String, String>>,
+  asset_properties: HashMap<String, Arc<Vec<String>>>,
+}
+
+impl MangledProperties {
+  /// Hash the new names of the properties in the asset, the minimized asset changes with them.
+  pub fn hash_asset(&self, filename: &str, hasher: &mut RspackHash) {
+    let Some(properties) = self.asset_properties.get(filename) else {
+      return;
+    };
+    for property in properties.iter() {
+      if let Some(name) = self.names.get(property) {
+        property.hash(hasher);
+        name.hash(hasher);
+      }
+    }
+  }
+}
+
+/// Keeps the mangled names and the properties of the assets across rebuilds.
+#[derive(Debug, Default)]
+pub(crate) struct PropertyMangler {
+  /// The mangled names by the properties, `None` before the name cache is loaded.
+  mangled: Mutex<Option<BTreeMap<String, String>>>,
+  /// The properties of the assets and the hash of the content they are collected from.
+  asset_properties: Mutex<HashMap<String, (String, Arc<Vec<String>>)>>,
+}
+
+impl PropertyMangler {
+  /// Collect the properties of the assets to minimize, and assign a short name to each property
+  /// matched by the options. The names assigned in the previous builds are kept unless they
+  /// collide with a property in the assets.
+  ///
+  /// Returns `None` when an asset fails to parse, since the new names may collide with its
+  /// unknown properties.
+  pub async fn mangle(
+    &self,
+    options: &PluginOptions,
+    mangle_properties: &MangleProperties,
+    compilation: &Compilation,
+  ) -> Result<Option<MangledProperties>> {
+    let regex = Regex::new(&mangle_properties.regex).map_err(|e| {
+      error!(
+        "Invalid mangleProperties.regex `{}`: {e}",
+        mangle_properties.regex
+      )
+    })?;
+    let minimizer_options = &options.minimizer_options;
+    let output_options = &compilation.options.output;
+
+    let previous = std::mem::take(
+      &mut *self
+        .asset_properties
+        .lock()
+        .expect("asset_properties lock failed"),
+    );
+    let collected = compilation
+      .assets()
+      .par_iter()
+      .filter(|(filename, asset)| {
+        match_object(options, filename) && !asset.get_info().minimized.unwrap_or(false)
+      })
+      .filter_map(|(filename, asset)| {
+        let source = asset.get_source()?;
+        let filename = filename.split('?').next().expect("Should have filename");
+        let code = source.source().into_string_lossy();
+        let mut hasher = RspackHash::from(output_options);
+        code.hash(&mut hasher);
+        let hash = hasher
+          .digest(&output_options.hash_digest)
+          .encoded()
+          .to_string();
+        if let Some((previous_hash, properties)) = previous.get(filename)
+          && *previous_hash == hash
+        {
+          return Some((filename.to_string(), Some((hash, properties.clone()))));
+        }
+        let properties = JavaScriptCompiler::new()
+          .collect_property_names(
+            swc_core::common::FileName::Custom(filename.to_string()),
+            code.into_owned(),
+            minimizer_options.ecma.clone(),
+            is_module(minimizer_options, asset.get_info(), filename),
+          )
+          .ok();
+        Some((
+          filename.to_string(),
+          properties.map(|properties| (hash, Arc::new(properties))),
+        ))
+      })
+      .collect::<Vec<_>>();
+
+    let parsed = collected.iter().all(|(_, properties)| properties.is_some());
+    let asset_properties = collected
+      .into_iter()
+      .filter_map(|(filename, properties)| Some((filename, properties?)))
+      .collect::<HashMap<_, _>>();
+    if !parsed {
+      // the syntax errors are reported by the minification
+      *self
+        .asset_properties
+        .lock()
+        .expect("asset_properties lock failed") = asset_properties;
+      return Ok(None);
+    }
+
+    let properties = asset_properties
+      .values()
+      .flat_map(|(_, properties)| properties.iter().map(String::as_str))
+      .collect::<BTreeSet<_>>();
+
+    let name_cache = mangle_properties
+      .name_cache
+      .as_ref()
+      .map(|name_cache| compilation.options.context.as_path().join(name_cache));
+    let loaded = self.mangled.lock().expect("mangled lock failed").take();
+    let mut mangled = match (loaded, &name_cache) {
+      (Some(mangled), _) => mangled,
+      (None, Some(name_cache)) => match compilation
+        .input_filesystem
+        .read_to_string(name_cache)
+        .await
+      {
+        Ok(content) => parse_name_cache(&content)
+          .map_err(|e| error!("Failed to parse the name cache {name_cache}: {e}"))?,
+        // the name cache is created by the first build
+        Err(rspack_fs::Error::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+          BTreeMap::default()
+        }
+        Err(e) => return Err(error!("Failed to read the name cache {name_cache}: {e}")),
+      },
+      (None, None) => BTreeMap::default(),
+    };
+
+    let changed = assign_names(&regex, mangle_properties, &properties, &mut mangled);
+    if changed && let Some(name_cache) = &name_cache {
+      let content = stringify_name_cache(&mangled)
+        .map_err(|e| error!("Failed to serialize the name cache: {e}"))?;
+      let fs = &compilation.output_filesystem;
+      if let Some(dir) = name_cache.parent() {
+        fs.create_dir_all(dir)
+          .await
+          .map_err(|e| error!("Failed to write the name cache {name_cache}: {e}"))?;
+      }
+      fs.write(name_cache, content.as_bytes())
+        .await
+        .map_err(|e| error!("Failed to write the name cache {name_cache}: {e}"))?;
+    }
+
+    let mangled_properties = MangledProperties {
+      names: Arc::new(
+        mangled
+          .iter()
+          .map(|(property, name)| (property.clone(), name.clone()))
+          .collect(),
+      ),
+      asset_properties: asset_properties
+        .iter()
+        .map(|(filename, (_, properties))| (filename.clone(), properties.clone()))
+        .collect(),
+    };
+    *self.mangled.lock().expect("mangled lock failed") = Some(mangled);
+    *self
+      .asset_properties
+      .lock()
+      .expect("asset_properties lock failed") = asset_properties;
+    Ok(Some(mangled_properties))
+  }
+}
+
+/// Serialize the mangled names as the `{ "props": { "property": "name" } }` name cache.
+fn stringify_name_cache(mangled: &BTreeMap<String, String>) -> serde_json::Result<String> {
+  serde_json::to_string_pretty(&serde_json::json!({ "props": mangled }))
+}
+
+/// Parse the `{ "props": { "property": "name" } }` name cache.
+fn parse_name_cache(content: &str) -> std::result::Result<BTreeMap<String, String>, String> {
+  let value = serde_json::from_str::<serde_json::Value>(content).map_err(|e| e.to_string())?;
+  let Some(props) = value.get("props") else {
+    return Ok(BTreeMap::default());
+  };
+  let props = props
+    .as_object()
+    .ok_or_else(|| "`props` should be an object".to_string())?;
+  props
+    .iter()
+    .map(|(property, name)| {
+      let name = name
+        .as_str()
+        .ok_or_else(|| format!("the name of `{property}` should be a string"))?;
+      Ok((property.clone(), name.to_string()))
+    })
+    .collect()
+}
+
+/// Assign the names to the properties matched by the regex, returns whether `mangled` is changed.
+fn assign_names(
+  regex: &Regex,
+  options: &MangleProperties,
+  properties: &BTreeSet<&str>,
+  mangled: &mut BTreeMap<String, String>,
+) -> bool {
+  let reserved = options
+    .reserved
+    .iter()
+    .map(String::as_str)
+    .collect::<HashSet<_>>();
+  let is_mangled = |property: &str| regex.is_match(property) && !reserved.contains(property);
+  // a name can't be the name of a property in the assets, since every name matched by the
+  // regex should be renamed
+  let is_available = |name: &str| !properties.contains(name) && !reserved.contains(name);
+
+  let mut changed = false;
+  mangled.retain(|property, name| {
+    let keep = is_mangled(property) && is_available(name);
+    changed |= !keep;
+    keep
+  });
+
+  let mut used = mangled.values().cloned().collect::<HashSet<_>>();
+  let mut index = 0;
+  for property in properties {
+    if !is_mangled(property) || mangled.contains_key(*property) {
+      continue;
+    }
+    let name = loop {
+      let name = short_name(index);
+      index += 1;
+      if is_available(&name) && !used.contains(&name) {
+        break name;
+      }
+    };
+    used.insert(name.clone());
+    mangled.insert(property.to_string(), name);
+    changed = true;
+  }
+  changed
+}
+
+/// The `index`th name of `a`, `b`, ..., `_`, `aa`, `ba`, ...
+fn short_name(mut index: usize) -> String {
+  let mut name = String::new();
+  name.push(NAME_CHARS[index % NAME_START_CHARS] as char);
+  index /= NAME_START_CHARS;
+  while index > 0 {
+    index -= 1;
+    name.push(NAME_CHARS[index % NAME_CHARS.len()] as char);
+    index /= NAME_CHARS.len();
+  }
+  name
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn mangle_options(regex: &str) -> (Regex, MangleProperties) {
+    (
+      Regex::new(regex).unwrap(),
+      MangleProperties {
+        regex: regex.to_string(),
+        reserved: vec!["_keep".to_string()],
+        name_cache: None,
+      },
+    )
+  }
+
+  fn names(mangled: &[(&str, &str)]) -> BTreeMap<String, String> {
+    mangled
+      .iter()
+      .map(|(property, name)| (property.to_string(), name.to_string()))
+      .collect()
+  }
+
+  #[test]
+  fn test_short_name() {
+    assert_eq!(short_name(0), "a");
+    assert_eq!(short_name(25), "z");
+    assert_eq!(short_name(26), "A");
+    assert_eq!(short_name(53), "_");
+    assert_eq!(short_name(54), "aa");
+    assert_eq!(short_name(55), "ba");
+    assert_eq!(short_name(3509), "_9");
+    assert_eq!(short_name(3510), "aaa");
+    let names = (0..10000).map(short_name).collect::<HashSet<_>>();
+    assert_eq!(names.len(), 10000);
+    assert!(
+      names
+        .iter()
+        .all(|name| !name.as_bytes()[0].is_ascii_digit())
+    );
+  }
+
+  #[test]
+  fn test_assign_names() {
+    let (regex, options) = mangle_options("^_");
+    let mut mangled = BTreeMap::default();
+    let properties = BTreeSet::from(["_a", "_b", "_keep", "a", "b", "c"]);
+    // the names of the properties in the assets are skipped
+    assert!(assign_names(&regex, &options, &properties, &mut mangled));
+    assert_eq!(mangled, names(&[("_a", "d"), ("_b", "e")]));
+    assert!(!assign_names(&regex, &options, &properties, &mut mangled));
+    assert_eq!(mangled, names(&[("_a", "d"), ("_b", "e")]));
+
+    // a name colliding with a new property in the assets is assigned again
+    let properties = BTreeSet::from(["_a", "_b", "_keep", "a", "b", "c", "d"]);
+    assert!(assign_names(&regex, &options, &properties, &mut mangled));
+    assert_eq!(mangled, names(&[("_a", "f"), ("_b", "e")]));
+
+    // the properties no longer matched are removed
+    let (regex, options) = mangle_options("^_b");
+    assert!(assign_names(&regex, &options, &properties, &mut mangled));
+    assert_eq!(mangled, names(&[("_b", "e")]));
+  }
+
+  #[test]
+  fn test_parse_name_cache() {
+    assert_eq!(
+      parse_name_cache(r#"{ "props": { "_a": "d" } }"#),
+      Ok(names(&[("_a", "d")]))
+    );
+    assert_eq!(parse_name_cache("{}"), Ok(BTreeMap::default()));
+    assert_eq!(
+      parse_name_cache(r#"{ "props": [] }"#),
+      Err("`props` should be an object".to_string())
+    );
+    assert_eq!(
+      parse_name_cache(r#"{ "props": { "_a": 1 } }"#),
+      Err("the name of `_a` should be a string".to_string())
+    );
+    assert!(parse_name_cache("{").is_err());
+  }
+
+  #[test]
+  fn test_name_cache_across_builds() {
+    let (regex, options) = mangle_options("^_");
+
+    let mut mangled = BTreeMap::default();
+    let properties = BTreeSet::from(["_b", "_c", "a"]);
+    assert!(assign_names(&regex, &options, &properties, &mut mangled));
+    let name_cache = stringify_name_cache(&mangled).unwrap();
+
+    // the next build starts with the names in the name cache
+    let mut next_mangled = parse_name_cache(&name_cache).unwrap();
+    assert_eq!(next_mangled, mangled);
+    assert!(!assign_names(
+      &regex,
+      &options,
+      &properties,
+      &mut next_mangled
+    ));
+    assert_eq!(next_mangled, mangled);
+
+    // a new property sorted before the others doesn't change their names
+    let properties = BTreeSet::from(["_a", "_b", "_c", "a"]);
+    assert!(assign_names(
+      &regex,
+      &options,
+      &properties,
+      &mut next_mangled
+    ));
+    assert_eq!(next_mangled["_b"], mangled["_b"]);
+    assert_eq!(next_mangled["_c"], mangled["_c"]);
+    assert_eq!(
+      next_mangled,
+      names(&[("_a", "d"), ("_b", "b"), ("_c", "c")])
+    );
+  }
+}
@@ -65,7 +65,7 @@ impl std::fmt::Display for ErrorReason {
   }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum ErrorType {
   Validate,
   Save,
@@ -85,7 +85,7 @@ impl std::fmt::Display for ErrorType {
 #[derive(Debug)]
 pub struct Error {
   r#type: Option<ErrorType>,
-  scope: Option<&'static str>,
+  scope: Option<String>,
   inner: ErrorReason,
 }
 
@@ -112,34 +112,30 @@ impl From<BatchFSError> for Error {
 impl Error {
   pub fn from_detail(
     r#type: Option<ErrorType>,
-    scope: Option<&'static str>,
+    scope: Option<&str>,
     detail: InvalidDetail,
   ) -> Self {
     Self {
       r#type,
-      scope,
+      scope: scope.map(ToString::to_string),
       inner: ErrorReason::Detail(detail),
     }
   }
   pub fn from_error(
     r#type: Option<ErrorType>,
-    scope: Option<&'static str>,
+    scope: Option<&str>,
     error: Box<dyn std::error::Error + Send + Sync>,
   ) -> Self {
     Self {
       r#type,
-      scope,
+      scope: scope.map(ToString::to_string),
       inner: ErrorReason::Error(error),
     }
   }
-  pub fn from_reason(
-    r#type: Option<ErrorType>,
-    scope: Option<&'static str>,
-    reason: String,
-  ) -> Self {
+  pub fn from_reason(r#type: Option<ErrorType>, scope: Option<&str>, reason: String) -> Self {
     Self {
       r#type,
-      scope,
+      scope: scope.map(ToString::to_string),
       inner: ErrorReason::Reason(reason),
     }
   }
@@ -149,7 +145,7 @@ impl std::fmt::Display for Error {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     if let Some(t) = &self.r#type {
       write!(f, "{t} ")?;
-      if let Some(scope) = self.scope {
+      if let Some(scope) = &self.scope {
         write!(f, "scope `{scope}` ")?;
       }
       write!(f, "failed due to")?;
@@ -1,12 +1,16 @@
 mod error;
 mod fs;
 mod pack;
+mod remote;
 
 use std::sync::Arc;
 
-pub use error::Result;
+pub use error::{Error, ErrorType, Result};
 pub use fs::{BridgeFileSystem, FSError, FSOperation, FSResult, FileSystem, Reader, Writer};
-pub use pack::{PackStorage, PackStorageOptions};
+pub use pack::{
+  PackReport, PackStorage, PackStorageInspector, PackStorageOptions, ScopeReport, VersionReport,
+};
+pub use remote::{HttpRemoteClient, RemoteClient, RemoteStorage, RemoteStorageOptions};
 use tokio::sync::oneshot::Receiver;
 
 type ItemKey = Vec<u8>;
@@ -9,6 +9,7 @@ pub struct PackFileMeta {
   pub hash: String,
   pub name: String,
   pub size: usize,
+  pub item_count: usize,
   pub wrote: bool,
   pub generation: usize,
 }
@@ -46,6 +47,7 @@ pub struct ScopeMeta {
   pub bucket_size: usize,
   pub pack_size: usize,
   pub generation: usize,
+  pub item_count: usize,
   pub packs: Vec<Vec<PackFileMeta>>,
 }
 
@@ -60,10 +62,15 @@ impl ScopeMeta {
       bucket_size: options.bucket_size,
       pack_size: options.pack_size,
       generation: 0,
+      item_count: 0,
       packs,
     }
   }
 
+  pub fn total_size(&self) -> usize {
+    self.packs.iter().flatten().map(|pack| pack.size).sum()
+  }
+
   pub fn get_path(dir: &Utf8Path) -> Utf8PathBuf {
     dir.join("scope_meta")
   }
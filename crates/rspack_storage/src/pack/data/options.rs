@@ -1,9 +1,64 @@
 use rspack_paths::Utf8PathBuf;
 
+use super::ScopeMeta;
+
+const ADAPTIVE_ITEMS_PER_BUCKET: usize = 1000;
+const ADAPTIVE_PACKS_PER_BUCKET: usize = 8;
+const ADAPTIVE_MIN_BUCKET_SIZE: usize = 4;
+const ADAPTIVE_MAX_BUCKET_SIZE: usize = 256;
+const ADAPTIVE_MIN_PACK_SIZE: usize = 64 * 1024;
+const ADAPTIVE_MAX_PACK_SIZE: usize = 16 * 1024 * 1024;
+
 #[derive(Debug)]
 pub struct PackOptions {
   pub bucket_size: usize,
   pub pack_size: usize,
+  /// Pick bucket size and pack size of each scope from the item count and
+  /// the total size stored in its scope meta, the sizes above are only used
+  /// for new scopes.
+  pub adaptive: bool,
+}
+
+impl PackOptions {
+  pub fn new(bucket_size: usize, pack_size: usize) -> Self {
+    Self {
+      bucket_size,
+      pack_size,
+      adaptive: false,
+    }
+  }
+
+  /// Get the sizes recorded in scope meta, adaptive scopes are validated with them.
+  pub fn from_meta(meta: &ScopeMeta) -> Self {
+    Self {
+      bucket_size: meta.bucket_size,
+      pack_size: meta.pack_size,
+      adaptive: true,
+    }
+  }
+
+  /// Get the recommended sizes for a scope with the observed item count and total size.
+  pub fn adapt(item_count: usize, total_size: usize) -> Self {
+    let bucket_size = item_count
+      .div_ceil(ADAPTIVE_ITEMS_PER_BUCKET)
+      .next_power_of_two()
+      .clamp(ADAPTIVE_MIN_BUCKET_SIZE, ADAPTIVE_MAX_BUCKET_SIZE);
+    let pack_size = (total_size / bucket_size / ADAPTIVE_PACKS_PER_BUCKET)
+      .next_power_of_two()
+      .clamp(ADAPTIVE_MIN_PACK_SIZE, ADAPTIVE_MAX_PACK_SIZE);
+    Self {
+      bucket_size,
+      pack_size,
+      adaptive: true,
+    }
+  }
+
+  /// Only resize when one of the sizes is off by more than a factor of two,
+  /// so that scopes around a threshold are not rewritten on every build.
+  pub fn should_resize(&self, recommended: &Self) -> bool {
+    let far = |a: usize, b: usize| a * 2 < b || b * 2 < a;
+    far(self.bucket_size, recommended.bucket_size) || far(self.pack_size, recommended.pack_size)
+  }
 }
 
 #[derive(Debug)]
@@ -12,3 +67,32 @@ pub struct RootOptions {
   pub expire: u64,
   pub clean: bool,
 }
+
+#[cfg(test)]
+mod tests {
+  use super::PackOptions;
+
+  #[test]
+  fn should_adapt_sizes() {
+    let small = PackOptions::adapt(0, 0);
+    assert_eq!(small.bucket_size, 4);
+    assert_eq!(small.pack_size, 64 * 1024);
+
+    let large = PackOptions::adapt(100_000, 1024 * 1024 * 1024);
+    assert_eq!(large.bucket_size, 128);
+    assert_eq!(large.pack_size, 1024 * 1024);
+
+    let huge = PackOptions::adapt(10_000_000, 100 * 1024 * 1024 * 1024);
+    assert_eq!(huge.bucket_size, 256);
+    assert_eq!(huge.pack_size, 16 * 1024 * 1024);
+  }
+
+  #[test]
+  fn should_resize_with_hysteresis() {
+    let current = PackOptions::adapt(100_000, 1024 * 1024 * 1024);
+    assert!(!current.should_resize(&PackOptions::adapt(120_000, 1024 * 1024 * 1024)));
+    assert!(!current.should_resize(&PackOptions::adapt(200_000, 1024 * 1024 * 1024)));
+    assert!(current.should_resize(&PackOptions::adapt(400_000, 1024 * 1024 * 1024)));
+    assert!(current.should_resize(&PackOptions::adapt(100_000, 4096 * 1024 * 1024)));
+  }
+}
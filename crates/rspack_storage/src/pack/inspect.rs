@@ -0,0 +1,414 @@
+use std::sync::Arc;
+
+use rspack_paths::Utf8PathBuf;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use super::{
+  data::{PackOptions, PackScope, RootMeta, RootMetaFrom, RootOptions, ScopeMeta, current_time},
+  manager::save_scopes,
+  strategy::{
+    RootStrategy, ScopeReadStrategy, ScopeUpdate, ScopeValidateStrategy, ScopeWriteStrategy,
+    SplitPackStrategy, walk_dir,
+  },
+};
+use crate::{
+  FileSystem,
+  error::{Error, ErrorType, Result, ValidateResult},
+};
+
+#[derive(Debug)]
+pub struct PackReport {
+  pub path: Utf8PathBuf,
+  pub hash: String,
+  pub size: usize,
+  pub generation: usize,
+}
+
+#[derive(Debug)]
+pub struct ScopeReport {
+  pub name: String,
+  pub bucket_size: usize,
+  pub pack_size: usize,
+  pub generation: usize,
+  pub item_count: usize,
+  pub packs: Vec<PackReport>,
+  /// Files in the scope directory which are not referenced by scope meta.
+  pub unused_files: Vec<Utf8PathBuf>,
+  /// Packs whose hash does not match scope meta, only filled when validating.
+  pub invalid_packs: Vec<String>,
+  /// The scope is listed in storage meta but its scope meta does not exist.
+  pub missing_meta: bool,
+  /// The scope can not be read, e.g. its scope meta is corrupted.
+  pub error: Option<String>,
+}
+
+impl ScopeReport {
+  fn empty(name: &str) -> Self {
+    Self {
+      name: name.to_string(),
+      bucket_size: 0,
+      pack_size: 0,
+      generation: 0,
+      item_count: 0,
+      packs: vec![],
+      unused_files: vec![],
+      invalid_packs: vec![],
+      missing_meta: false,
+      error: None,
+    }
+  }
+
+  pub fn size(&self) -> usize {
+    self.packs.iter().map(|pack| pack.size).sum()
+  }
+}
+
+#[derive(Debug)]
+pub struct VersionReport {
+  pub version: String,
+  /// `None` means the version has no storage meta, e.g. an interrupted saving.
+  pub expire_time: Option<u64>,
+  pub expired: bool,
+  pub scopes: Vec<ScopeReport>,
+}
+
+impl VersionReport {
+  pub fn is_valid(&self) -> bool {
+    self.expire_time.is_some()
+      && !self.expired
+      && self
+        .scopes
+        .iter()
+        .all(|scope| !scope.missing_meta && scope.invalid_packs.is_empty() && scope.error.is_none())
+  }
+}
+
+/// Pack Storage Inspector
+///
+/// Inspect and maintain a pack storage directory offline, the directory contains
+/// a sub directory for each version just like `PackStorageOptions.root`.
+///
+/// Must not be used while a compiler is writing to the same directory.
+#[derive(Debug)]
+pub struct PackStorageInspector {
+  root: Utf8PathBuf,
+  temp_root: Utf8PathBuf,
+  fs: Arc<dyn FileSystem>,
+}
+
+impl PackStorageInspector {
+  pub fn new(root: Utf8PathBuf, temp_root: Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Self {
+    Self {
+      root,
+      temp_root,
+      fs,
+    }
+  }
+
+  fn strategy(&self, version: &str) -> SplitPackStrategy {
+    SplitPackStrategy::new(
+      self.root.join(version),
+      self.temp_root.join(version),
+      self.fs.clone(),
+      None,
+      None,
+    )
+  }
+
+  /// Get the version directories, hidden directories such as the temp root are skipped.
+  pub async fn versions(&self) -> Result<Vec<String>> {
+    if !self.fs.exists(&self.root).await? {
+      return Ok(vec![]);
+    }
+    let mut versions = vec![];
+    for name in self.fs.read_dir(&self.root).await? {
+      if name.starts_with('.') {
+        continue;
+      }
+      if self.fs.metadata(&self.root.join(&name)).await?.is_directory {
+        versions.push(name);
+      }
+    }
+    versions.sort();
+    Ok(versions)
+  }
+
+  pub async fn inspect(&self, validate: bool) -> Result<Vec<VersionReport>> {
+    let mut reports = vec![];
+    for version in self.versions().await? {
+      reports.push(self.inspect_version(&version, validate).await?);
+    }
+    Ok(reports)
+  }
+
+  pub async fn inspect_version(&self, version: &str, validate: bool) -> Result<VersionReport> {
+    let strategy = self.strategy(version);
+    let Some(root_meta) = strategy.read_root_meta().await? else {
+      return Ok(VersionReport {
+        version: version.to_string(),
+        expire_time: None,
+        expired: false,
+        scopes: vec![],
+      });
+    };
+
+    let mut scope_names = root_meta
+      .scopes
+      .iter()
+      .filter(|name| !name.is_empty())
+      .collect::<Vec<_>>();
+    scope_names.sort();
+    let mut scopes = vec![];
+    for name in scope_names {
+      let report = match self.inspect_scope(&strategy, name, validate).await {
+        Ok(report) => report,
+        Err(error) => ScopeReport {
+          error: Some(error.to_string()),
+          ..ScopeReport::empty(name)
+        },
+      };
+      scopes.push(report);
+    }
+
+    Ok(VersionReport {
+      version: version.to_string(),
+      expire_time: Some(root_meta.expire_time),
+      expired: current_time() > root_meta.expire_time,
+      scopes,
+    })
+  }
+
+  async fn inspect_scope(
+    &self,
+    strategy: &SplitPackStrategy,
+    name: &str,
+    validate: bool,
+  ) -> Result<ScopeReport> {
+    let mut report = ScopeReport::empty(name);
+    let Some(mut scope) = self.read_scope(strategy, name, validate).await? else {
+      report.missing_meta = true;
+      return Ok(report);
+    };
+
+    let meta = scope.meta.expect_value();
+    let scope_path = &scope.path;
+    report.bucket_size = meta.bucket_size;
+    report.pack_size = meta.pack_size;
+    report.generation = meta.generation;
+    report.item_count = meta.item_count;
+    report.packs = meta
+      .packs
+      .iter()
+      .enumerate()
+      .flat_map(|(bucket_id, pack_metas)| {
+        pack_metas.iter().map(move |pack_meta| PackReport {
+          path: scope_path.join(bucket_id.to_string()).join(&pack_meta.name),
+          hash: pack_meta.hash.clone(),
+          size: pack_meta.size,
+          generation: pack_meta.generation,
+        })
+      })
+      .collect();
+    report.unused_files = self.unused_files(&scope).await?;
+    if validate && let ValidateResult::Invalid(detail) = strategy.validate_packs(&mut scope).await?
+    {
+      report.invalid_packs = detail.packs;
+    }
+    Ok(report)
+  }
+
+  /// Read scope meta and pack list, pack keys are read as well when validating.
+  ///
+  /// Return `None` if the scope meta does not exist.
+  async fn read_scope(
+    &self,
+    strategy: &SplitPackStrategy,
+    name: &str,
+    read_keys: bool,
+  ) -> Result<Option<PackScope>> {
+    let path = strategy.get_path(name);
+    if !self.fs.exists(&ScopeMeta::get_path(&path)).await? {
+      return Ok(None);
+    }
+    let mut scope = PackScope::new(
+      name,
+      path,
+      Arc::new(PackOptions {
+        bucket_size: 0,
+        pack_size: 0,
+        adaptive: true,
+      }),
+    );
+    strategy.ensure_meta(&mut scope).await?;
+    // validate with the sizes the scope was written with
+    scope.options = Arc::new(PackOptions::from_meta(scope.meta.expect_value()));
+    if read_keys {
+      strategy.ensure_keys(&mut scope).await?;
+    } else {
+      strategy.ensure_packs(&mut scope).await?;
+    }
+    Ok(Some(scope))
+  }
+
+  async fn unused_files(&self, scope: &PackScope) -> Result<Vec<Utf8PathBuf>> {
+    let mut used_files = scope
+      .packs
+      .expect_value()
+      .iter()
+      .flatten()
+      .map(|pack| pack.path.clone())
+      .collect::<HashSet<_>>();
+    used_files.insert(ScopeMeta::get_path(&scope.path));
+    let mut unused_files = walk_dir(&scope.path, self.fs.clone())
+      .await?
+      .into_iter()
+      .filter(|file| !used_files.contains(file))
+      .collect::<Vec<_>>();
+    unused_files.sort();
+    Ok(unused_files)
+  }
+
+  /// Get the most recently saved version except `except`, expired versions are skipped.
+  pub async fn latest_version(&self, except: &str) -> Result<Option<String>> {
+    let mut latest: Option<(u64, String)> = None;
+    for version in self.versions().await? {
+      if version == except {
+        continue;
+      }
+      let Some(root_meta) = self.strategy(&version).read_root_meta().await? else {
+        continue;
+      };
+      if current_time() > root_meta.expire_time {
+        continue;
+      }
+      if latest
+        .as_ref()
+        .is_none_or(|(expire_time, _)| root_meta.expire_time > *expire_time)
+      {
+        latest = Some((root_meta.expire_time, version));
+      }
+    }
+    Ok(latest.map(|(_, version)| version))
+  }
+
+  /// Remove expired or incomplete versions, unused scopes, unreadable scopes and unused
+  /// files in scopes.
+  ///
+  /// Return the removed paths.
+  pub async fn gc(&self) -> Result<Vec<Utf8PathBuf>> {
+    let mut removed = vec![];
+    for version in self.versions().await? {
+      let version_dir = self.root.join(&version);
+      let strategy = self.strategy(&version);
+      let root_meta = match strategy.before_load().await {
+        Ok(()) => strategy.read_root_meta().await?,
+        // illegal lock files
+        Err(_) => None,
+      };
+      let Some(root_meta) = root_meta.filter(|meta| current_time() <= meta.expire_time) else {
+        self.fs.remove_dir(&version_dir).await?;
+        removed.push(version_dir);
+        continue;
+      };
+
+      for name in self.fs.read_dir(&version_dir).await? {
+        let dir = version_dir.join(&name);
+        if name.starts_with('.')
+          || root_meta.scopes.contains(&name)
+          || !self.fs.metadata(&dir).await?.is_directory
+        {
+          continue;
+        }
+        self.fs.remove_dir(&dir).await?;
+        removed.push(dir);
+      }
+
+      for name in root_meta.scopes.iter().filter(|name| !name.is_empty()) {
+        let scope = match self.read_scope(&strategy, name, false).await {
+          Ok(Some(scope)) => scope,
+          Ok(None) => continue,
+          // the compiler will rebuild a scope without scope meta
+          Err(_) => {
+            let dir = strategy.get_path(name);
+            self.fs.remove_dir(&dir).await?;
+            removed.push(dir);
+            continue;
+          }
+        };
+        for file in self.unused_files(&scope).await? {
+          self.fs.remove_file(&file).await?;
+          removed.push(file);
+        }
+      }
+    }
+    Ok(removed)
+  }
+
+  /// Rewrite all items of a version into new packs.
+  ///
+  /// The sizes of each scope are kept and the generation continues, so the compacted
+  /// storage is still valid for the compiler which wrote it. Like a normal saving,
+  /// unused files and expired versions are removed as well.
+  ///
+  /// Fail if any scope can not be read, run `gc` first to remove the unreadable scopes.
+  pub async fn compact(&self, version: &str) -> Result<()> {
+    let strategy = self.strategy(version);
+    strategy.before_load().await?;
+    let Some(root_meta) = strategy.read_root_meta().await? else {
+      return Err(Error::from_reason(
+        Some(ErrorType::Load),
+        None,
+        format!("version `{version}` has no storage meta"),
+      ));
+    };
+    if let ValidateResult::Invalid(detail) = strategy.validate_root(&root_meta).await? {
+      return Err(Error::from_detail(Some(ErrorType::Validate), None, detail));
+    }
+
+    let mut scopes = HashMap::default();
+    for name in root_meta.scopes.iter().filter(|name| !name.is_empty()) {
+      let Some(mut scope) = self.read_scope(&strategy, name, true).await? else {
+        continue;
+      };
+      if let ValidateResult::Invalid(detail) = strategy.validate_packs(&mut scope).await? {
+        return Err(Error::from_detail(
+          Some(ErrorType::Validate),
+          Some(&scope.name),
+          detail,
+        ));
+      }
+      strategy.ensure_contents(&mut scope).await?;
+      let updates = scope
+        .get_contents()
+        .into_iter()
+        .map(|(key, value)| (key.as_ref().clone(), Some(value.as_ref().clone())))
+        .collect::<ScopeUpdate>();
+
+      let mut compacted = PackScope::empty(
+        scope.name.clone(),
+        scope.path.clone(),
+        scope.options.clone(),
+      );
+      compacted.meta.expect_value_mut().generation = scope.meta.expect_value().generation;
+      strategy.update_scope(&mut compacted, updates).await?;
+      scopes.insert(name.clone(), compacted);
+    }
+
+    save_scopes(
+      scopes,
+      &RootMeta {
+        expire_time: root_meta.expire_time,
+        scopes: root_meta.scopes.clone(),
+        from: RootMetaFrom::New,
+      },
+      &strategy,
+      &RootOptions {
+        root: self.root.clone(),
+        expire: 0,
+        clean: true,
+      },
+    )
+    .await?;
+    Ok(())
+  }
+}
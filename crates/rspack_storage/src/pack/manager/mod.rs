@@ -27,6 +27,9 @@ pub struct ScopeManager {
   pub scopes: Arc<Mutex<ScopeMap>>,
   pub root_meta: Arc<Mutex<RootMetaState>>,
   pub queue: TaskQueue,
+  /// Adaptive scopes which are resized during loading,
+  /// all their items should be written again with the new sizes.
+  pub resized: Mutex<HashSet<&'static str>>,
 }
 
 impl ScopeManager {
@@ -42,6 +45,7 @@ impl ScopeManager {
       scopes: Default::default(),
       queue: TaskQueue::new(),
       root_meta: Default::default(),
+      resized: Default::default(),
     }
   }
 
@@ -99,13 +103,16 @@ impl ScopeManager {
   }
 
   async fn clear_scope(&self, name: &str) {
-    self
-      .scopes
-      .lock()
-      .await
-      .get_mut(name)
-      .expect("should have scope")
-      .clear();
+    let mut scopes = self.scopes.lock().await;
+    let scope = scopes.get_mut(name).expect("should have scope");
+    // adaptive scopes may be validated with the sizes in scope meta
+    scope.options = self.pack_options.clone();
+    scope.clear();
+  }
+
+  /// Take whether the scope is resized in the last loading
+  pub async fn take_resized(&self, name: &str) -> bool {
+    self.resized.lock().await.remove(name)
   }
 
   pub async fn load(&self, name: &'static str) -> Result<ItemPairs> {
@@ -146,6 +153,24 @@ impl ScopeManager {
           self.strategy.ensure_contents(scope).await?;
           let res = scope.get_contents();
           self.strategy.release_scope(scope).await?;
+          if self.pack_options.adaptive {
+            let meta = scope.meta.expect_value();
+            let recommended = PackOptions::adapt(meta.item_count, meta.total_size());
+            if scope.options.should_resize(&recommended) {
+              let old_files = scope
+                .packs
+                .expect_value()
+                .iter()
+                .flatten()
+                .map(|pack| pack.path.clone())
+                .collect::<Vec<_>>();
+              scope.options = Arc::new(recommended);
+              scope.clear();
+              // the packs written with the old sizes are removed in next saving
+              scope.removed.extend(old_files);
+              self.resized.lock().await.insert(name);
+            }
+          }
           Ok(res)
         }
         // create empty scope if not exists
@@ -191,6 +216,10 @@ impl ScopeManager {
     let validated = self.strategy.validate_root(root_meta).await?;
     if validated.is_valid() {
       self.strategy.ensure_meta(scope).await?;
+      if self.pack_options.adaptive {
+        // adaptive scopes keep the sizes they were written with
+        scope.options = Arc::new(PackOptions::from_meta(scope.meta.expect_value()));
+      }
       let validated = self.strategy.validate_meta(scope).await?;
       if validated.is_valid() {
         self.strategy.ensure_keys(scope).await?;
@@ -277,7 +306,7 @@ async fn update_scopes(
 }
 
 #[tracing::instrument("Cache::Storage::save_scopes", skip_all)]
-async fn save_scopes(
+pub(crate) async fn save_scopes(
   mut scopes: ScopeMap,
   root_meta: &RootMeta,
   strategy: &dyn ScopeStrategy,
@@ -372,10 +401,7 @@ mod tests {
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
     });
-    let pack_options = Arc::new(PackOptions {
-      bucket_size: 10,
-      pack_size: 500,
-    });
+    let pack_options = Arc::new(PackOptions::new(10, 500));
 
     let strategy = Arc::new(SplitPackStrategy::new(
       root.to_path_buf(),
@@ -426,10 +452,7 @@ mod tests {
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
     });
-    let pack_options = Arc::new(PackOptions {
-      bucket_size: 10,
-      pack_size: 500,
-    });
+    let pack_options = Arc::new(PackOptions::new(10, 500));
 
     let strategy = Arc::new(SplitPackStrategy::new(
       root.to_path_buf(),
@@ -499,11 +522,10 @@ mod tests {
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
     });
-    let pack_options = Arc::new(PackOptions {
+    let pack_options = Arc::new(PackOptions::new(
       // different bucket size
-      bucket_size: 100,
-      pack_size: 500,
-    });
+      100, 500,
+    ));
 
     let strategy = Arc::new(SplitPackStrategy::new(
       root.to_path_buf(),
@@ -546,10 +568,7 @@ mod tests {
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
     });
-    let pack_options = Arc::new(PackOptions {
-      bucket_size: 100,
-      pack_size: 500,
-    });
+    let pack_options = Arc::new(PackOptions::new(100, 500));
 
     let strategy = Arc::new(SplitPackStrategy::new(
       root.to_path_buf(),
@@ -1,4 +1,5 @@
 mod data;
+mod inspect;
 mod manager;
 mod strategy;
 
@@ -8,6 +9,7 @@ use std::{
 };
 
 use data::{PackOptions, RootOptions};
+pub use inspect::{PackReport, PackStorageInspector, ScopeReport, VersionReport};
 use manager::ScopeManager;
 use rspack_paths::AssertUtf8;
 use rustc_hash::FxHashMap as HashMap;
@@ -38,6 +40,16 @@ pub struct PackStorageOptions {
 
 impl PackStorage {
   pub fn new(options: PackStorageOptions) -> Self {
+    Self::create(options, false)
+  }
+
+  /// Pick bucket size and pack size of each scope from its item count,
+  /// `bucket_size` and `pack_size` of the options are only used for new scopes.
+  pub fn new_adaptive(options: PackStorageOptions) -> Self {
+    Self::create(options, true)
+  }
+
+  fn create(options: PackStorageOptions, adaptive: bool) -> Self {
     Self {
       manager: ScopeManager::new(
         Arc::new(RootOptions {
@@ -48,6 +60,7 @@ impl PackStorage {
         Arc::new(PackOptions {
           bucket_size: options.bucket_size,
           pack_size: options.pack_size,
+          adaptive,
         }),
         Arc::new(SplitPackStrategy::new(
           options.root.join(&options.version).assert_utf8(),
@@ -65,7 +78,18 @@ impl PackStorage {
 #[async_trait::async_trait]
 impl Storage for PackStorage {
   async fn load(&self, name: &'static str) -> Result<ItemPairs> {
-    self.manager.load(name).await
+    let res = self.manager.load(name).await?;
+    if self.manager.take_resized(name).await {
+      // write all items again with the new sizes in next saving
+      let mut updates = self.updates.lock().expect("should get lock");
+      let scope_update = updates.entry(name).or_default();
+      for (key, value) in res.iter() {
+        scope_update
+          .entry(key.as_ref().clone())
+          .or_insert_with(|| Some(value.as_ref().clone()));
+      }
+    }
+    Ok(res)
   }
   fn set(&self, scope: &'static str, key: ItemKey, value: ItemValue) {
     let mut updates = self.updates.lock().expect("should get lock");
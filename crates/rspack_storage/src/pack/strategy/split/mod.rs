@@ -11,6 +11,7 @@ use std::{
   sync::Arc,
 };
 
+pub(crate) use handle_file::walk_dir;
 use handle_file::{
   recovery_move_lock, recovery_remove_lock, remove_expired_versions, remove_unused_scope_files,
   remove_unused_scopes,
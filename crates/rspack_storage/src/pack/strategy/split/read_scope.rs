@@ -20,7 +20,7 @@ impl ScopeReadStrategy for SplitPackStrategy {
   async fn ensure_meta(&self, scope: &mut PackScope) -> Result<()> {
     if !scope.meta.loaded() {
       let meta_path = ScopeMeta::get_path(&scope.path);
-      let meta = read_scope_meta(scope.name, &meta_path, self.fs.clone())
+      let meta = read_scope_meta(&scope.name, &meta_path, self.fs.clone())
         .await?
         .unwrap_or_else(|| ScopeMeta::new(&scope.path, &scope.options));
       scope.meta.set_value(meta);
@@ -91,7 +91,7 @@ impl ScopeReadStrategy for SplitPackStrategy {
 }
 
 async fn read_scope_meta(
-  scope: &'static str,
+  scope: &str,
   path: &Utf8Path,
   fs: Arc<dyn FileSystem>,
 ) -> Result<Option<ScopeMeta>> {
@@ -127,6 +127,8 @@ async fn read_scope_meta(
   let bucket_size = option_items[0];
   let pack_size = option_items[1];
   let generation = option_items[2];
+  // item count is missing in scope meta written by previous versions
+  let item_count = option_items.get(3).copied().unwrap_or_default();
 
   let mut packs = vec![];
   for _ in 0..bucket_size {
@@ -162,6 +164,11 @@ async fn read_scope_meta(
                   format!("parse file meta failed: {e}"),
                 )
               })?,
+              // item count is missing in pack meta written by previous versions
+              item_count: i
+                .get(4)
+                .and_then(|count| count.parse::<usize>().ok())
+                .unwrap_or_default(),
               wrote: true,
             })
           }
@@ -183,6 +190,7 @@ async fn read_scope_meta(
     bucket_size,
     pack_size,
     generation,
+    item_count,
     packs,
   }))
 }
@@ -207,7 +215,7 @@ async fn read_keys(scope: &PackScope, strategy: &SplitPackStrategy) -> Result<Ve
       let strategy = strategy.clone();
       let path = i.1.path.clone();
       tokio::spawn(async move { strategy.read_pack_keys(&path).await })
-        .map_err(|e| Error::from_error(Some(ErrorType::Load), Some(scope.name), Box::new(e)))
+        .map_err(|e| Error::from_error(Some(ErrorType::Load), Some(&scope.name), Box::new(e)))
     })
     .collect_vec();
 
@@ -252,7 +260,7 @@ async fn read_contents(
       let strategy = strategy.to_owned();
       let path = i.1.path.to_owned();
       tokio::spawn(async move { strategy.read_pack_contents(&path).await })
-        .map_err(|e| Error::from_error(Some(ErrorType::Load), Some(scope.name), Box::new(e)))
+        .map_err(|e| Error::from_error(Some(ErrorType::Load), Some(&scope.name), Box::new(e)))
     })
     .collect_vec();
   let pack_contents = join_all(tasks).await.into_iter().process_results(|iter| {
@@ -372,10 +380,7 @@ mod tests {
   async fn should_read_scope() -> Result<()> {
     for strategy in create_strategies("read_scope") {
       clean_strategy(&strategy).await;
-      let options = Arc::new(PackOptions {
-        bucket_size: 1,
-        pack_size: 16,
-      });
+      let options = Arc::new(PackOptions::new(1, 16));
       let mut scope = PackScope::new(
         "scope_name",
         strategy.get_path("scope_name"),
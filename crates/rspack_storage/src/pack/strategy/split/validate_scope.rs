@@ -44,7 +44,7 @@ impl ScopeValidateStrategy for SplitPackStrategy {
             Err(_) => false,
           }
         })
-        .map_err(|e| Error::from_error(Some(ErrorType::Validate), Some(scope.name), e.into()))
+        .map_err(|e| Error::from_error(Some(ErrorType::Validate), Some(&scope.name), e.into()))
       });
 
     let validate_results = join_all(tasks)
@@ -103,10 +103,7 @@ mod tests {
   };
 
   async fn test_valid_meta(scope_path: Utf8PathBuf, strategy: &SplitPackStrategy) -> Result<()> {
-    let same_options = Arc::new(PackOptions {
-      bucket_size: 10,
-      pack_size: 100,
-    });
+    let same_options = Arc::new(PackOptions::new(10, 100));
     let mut scope = PackScope::new("scope_name", scope_path, same_options);
     strategy.ensure_meta(&mut scope).await?;
     let validated = strategy.validate_meta(&mut scope).await?;
@@ -119,10 +116,7 @@ mod tests {
     scope_path: Utf8PathBuf,
     strategy: &SplitPackStrategy,
   ) -> Result<()> {
-    let bucket_changed_options = Arc::new(PackOptions {
-      bucket_size: 1,
-      pack_size: 100,
-    });
+    let bucket_changed_options = Arc::new(PackOptions::new(1, 100));
     let mut scope = PackScope::new(
       "scope_name",
       scope_path.clone(),
@@ -139,10 +133,7 @@ mod tests {
       panic!("should be invalid");
     }
 
-    let max_size_changed_options = Arc::new(PackOptions {
-      bucket_size: 10,
-      pack_size: 99,
-    });
+    let max_size_changed_options = Arc::new(PackOptions::new(10, 99));
     let mut scope = PackScope::new(
       "scope_name",
       scope_path.clone(),
@@ -209,10 +200,7 @@ mod tests {
       .expect("should mock root meta file");
 
       let scope_path = strategy.get_path("scope_meta");
-      let pack_options = Arc::new(PackOptions {
-        bucket_size: 10,
-        pack_size: 100,
-      });
+      let pack_options = Arc::new(PackOptions::new(10, 100));
       mock_scope_meta_file(
         &ScopeMeta::get_path(&scope_path),
         strategy.fs.as_ref(),
@@ -241,10 +229,7 @@ mod tests {
       .expect("should mock root meta file");
 
       let scope_path = strategy.get_path("scope_packs");
-      let pack_options = Arc::new(PackOptions {
-        bucket_size: 10,
-        pack_size: 100,
-      });
+      let pack_options = Arc::new(PackOptions::new(10, 100));
       let mut mock_scope = PackScope::empty("scope_name", scope_path.clone(), pack_options.clone());
       let updates = mock_updates(0, 100, 30, UpdateVal::Value("val".to_string()));
       strategy
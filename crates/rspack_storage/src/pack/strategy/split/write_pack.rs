@@ -354,6 +354,7 @@ fn create_pack(dir: &Utf8Path, candidates: Vec<PackItemCandidate>) -> (PackFileM
   let mut keys = vec![];
   let mut contents = vec![];
   let mut generations = vec![];
+  let item_count = candidates.len();
   for candidate in candidates {
     keys.push(candidate.key);
     contents.push(candidate.value);
@@ -374,6 +375,7 @@ fn create_pack(dir: &Utf8Path, candidates: Vec<PackItemCandidate>) -> (PackFileM
       name: file_name,
       hash: Default::default(),
       size: new_pack.size(),
+      item_count,
       wrote: false,
       generation: latest_generation,
     },
@@ -455,10 +457,7 @@ mod tests {
 
   async fn test_update_packs(strategy: &SplitPackStrategy) -> Result<()> {
     let dir = strategy.root.join("update");
-    let options = PackOptions {
-      bucket_size: 1,
-      pack_size: 2100,
-    };
+    let options = PackOptions::new(1, 2100);
 
     // half pack
     let mut packs = HashMap::default();
@@ -287,7 +287,15 @@ impl ScopeWriteStrategy for SplitPackStrategy {
   }
 
   async fn write_meta(&self, scope: &mut PackScope) -> Result<WriteScopeResult> {
-    let meta = scope.meta.expect_value();
+    let meta = scope.meta.expect_value_mut();
+    // count from the pack metas, the keys of the packs may not be loaded
+    let item_count = meta
+      .packs
+      .iter()
+      .flatten()
+      .map(|pack| pack.item_count)
+      .sum::<usize>();
+    meta.item_count = item_count;
     let path = redirect_to_path(&meta.path, &self.root, &self.temp_root)?;
     self
       .fs
@@ -299,8 +307,8 @@ impl ScopeWriteStrategy for SplitPackStrategy {
     writer
       .write_line(
         format!(
-          "{} {} {}",
-          meta.bucket_size, meta.pack_size, meta.generation
+          "{} {} {} {}",
+          meta.bucket_size, meta.pack_size, meta.generation, item_count
         )
         .as_str(),
       )
@@ -315,8 +323,8 @@ impl ScopeWriteStrategy for SplitPackStrategy {
             .iter()
             .map(|meta| {
               format!(
-                "{},{},{},{}",
-                meta.name, meta.hash, meta.size, meta.generation
+                "{},{},{},{},{}",
+                meta.name, meta.hash, meta.size, meta.generation, meta.item_count
               )
             })
             .join(" ")
@@ -581,10 +589,7 @@ mod tests {
   #[cfg_attr(miri, ignore)]
   async fn should_write_single_bucket_scope() -> Result<()> {
     for strategy in create_strategies("write_single") {
-      let options = Arc::new(PackOptions {
-        bucket_size: 1,
-        pack_size: 36,
-      });
+      let options = Arc::new(PackOptions::new(1, 36));
       let mut scope = PackScope::empty(
         "scope_name",
         strategy.get_path("scope_name"),
@@ -601,10 +606,7 @@ mod tests {
   #[cfg_attr(miri, ignore)]
   async fn should_write_multi_bucket_scope() -> Result<()> {
     for strategy in create_strategies("write_multi") {
-      let options = Arc::new(PackOptions {
-        bucket_size: 10,
-        pack_size: 36,
-      });
+      let options = Arc::new(PackOptions::new(10, 36));
       let mut scope = PackScope::empty(
         "scope_name",
         strategy.get_path("scope_name"),
@@ -621,10 +623,7 @@ mod tests {
   #[cfg_attr(miri, ignore)]
   async fn should_write_big_bucket_scope() -> Result<()> {
     for strategy in create_strategies("write_big") {
-      let options = Arc::new(PackOptions {
-        bucket_size: 1,
-        pack_size: 2100,
-      });
+      let options = Arc::new(PackOptions::new(1, 2100));
       let mut scope = PackScope::empty(
         "scope_name",
         strategy.get_path("scope_name"),
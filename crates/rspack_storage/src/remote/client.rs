@@ -0,0 +1,161 @@
+use std::time::Duration;
+
+use rspack_util::http::{HttpResponse, PlainHttpClient};
+
+use crate::error::{Error, ErrorType, Result};
+
+/// Remote object store client
+///
+/// Objects are addressed by plain string keys, the remote storage decides
+/// how keys are composed (see `RemoteStorage`).
+#[async_trait::async_trait]
+pub trait RemoteClient: std::fmt::Debug + Sync + Send {
+  /// Get the object, return `None` if it does not exist.
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
+  /// Upload the object, existing object will be overwritten.
+  async fn put(&self, key: &str, data: Vec<u8>) -> Result<()>;
+  /// Check whether the object exists.
+  async fn exists(&self, key: &str) -> Result<bool>;
+}
+
+/// Http remote client
+///
+/// Maps objects to `{base}/{key}` with a `PlainHttpClient`:
+/// * `GET` to download an object, `404` means not exists
+/// * `HEAD` to check whether an object exists
+/// * `PUT` to upload an object
+///
+/// Only `http://` urls are supported, use a custom `RemoteClient` to talk
+/// with an object store which requires TLS.
+#[derive(Debug)]
+pub struct HttpRemoteClient {
+  url: String,
+  client: PlainHttpClient,
+}
+
+impl HttpRemoteClient {
+  pub fn new(url: String, headers: Vec<(String, String)>) -> Self {
+    Self {
+      url,
+      client: PlainHttpClient::new(headers),
+    }
+  }
+
+  pub fn with_timeouts(mut self, connect_timeout: Duration, response_timeout: Duration) -> Self {
+    self.client = self.client.with_timeouts(connect_timeout, response_timeout);
+    self
+  }
+
+  fn object_url(&self, key: &str) -> String {
+    format!("{}/{key}", self.url.trim_end_matches('/'))
+  }
+
+  async fn request(
+    &self,
+    method: &str,
+    key: &str,
+    body: &[u8],
+    r#type: ErrorType,
+  ) -> Result<HttpResponse> {
+    self
+      .client
+      .request(method, &self.object_url(key), body)
+      .await
+      .map_err(|e| {
+        Error::from_reason(
+          Some(r#type),
+          None,
+          format!("request `{method} {key}` failed: {e}"),
+        )
+      })
+  }
+}
+
+fn unexpected_status(r#type: ErrorType, method: &str, key: &str, status: u16) -> Error {
+  Error::from_reason(
+    Some(r#type),
+    None,
+    format!("request `{method} {key}` responded with unexpected status {status}"),
+  )
+}
+
+#[async_trait::async_trait]
+impl RemoteClient for HttpRemoteClient {
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+    let res = self.request("GET", key, &[], ErrorType::Load).await?;
+    match res.status {
+      200 => Ok(Some(res.body)),
+      404 => Ok(None),
+      status => Err(unexpected_status(ErrorType::Load, "GET", key, status)),
+    }
+  }
+
+  async fn put(&self, key: &str, data: Vec<u8>) -> Result<()> {
+    let res = self.request("PUT", key, &data, ErrorType::Save).await?;
+    match res.status {
+      200..=299 => Ok(()),
+      status => Err(unexpected_status(ErrorType::Save, "PUT", key, status)),
+    }
+  }
+
+  async fn exists(&self, key: &str) -> Result<bool> {
+    let res = self.request("HEAD", key, &[], ErrorType::Load).await?;
+    match res.status {
+      200 => Ok(true),
+      404 => Ok(false),
+      status => Err(unexpected_status(ErrorType::Load, "HEAD", key, status)),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use super::{HttpRemoteClient, RemoteClient};
+
+  #[test]
+  fn should_join_object_url() {
+    let client = HttpRemoteClient::new("http://127.0.0.1:8080/cache/".to_string(), vec![]);
+    assert_eq!(
+      client.object_url("objects/abc"),
+      "http://127.0.0.1:8080/cache/objects/abc"
+    );
+
+    let client = HttpRemoteClient::new("http://cache.local".to_string(), vec![]);
+    assert_eq!(
+      client.object_url("objects/abc"),
+      "http://cache.local/objects/abc"
+    );
+  }
+
+  #[tokio::test]
+  async fn should_fail_with_unsupported_url() {
+    let client = HttpRemoteClient::new("https://cache.local".to_string(), vec![]);
+    let err = client.get("objects/abc").await.expect_err("should fail");
+    assert!(err.to_string().contains("unsupported url"));
+  }
+
+  #[tokio::test]
+  async fn should_time_out_when_remote_hangs() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+      .await
+      .expect("should bind");
+    let addr = listener.local_addr().expect("should have address");
+    // accept the connection and never respond
+    let server = tokio::spawn(async move {
+      let (stream, _) = listener.accept().await.expect("should accept");
+      tokio::time::sleep(Duration::from_secs(10)).await;
+      drop(stream);
+    });
+
+    let client = HttpRemoteClient::new(format!("http://{addr}"), vec![])
+      .with_timeouts(Duration::from_secs(1), Duration::from_millis(50));
+    let err = client
+      .get("objects/abc")
+      .await
+      .expect_err("should time out");
+    assert!(err.to_string().contains("timed out"));
+    server.abort();
+  }
+}
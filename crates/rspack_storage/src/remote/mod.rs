@@ -0,0 +1,300 @@
+mod client;
+
+use std::sync::Arc;
+
+pub use client::{HttpRemoteClient, RemoteClient};
+use futures::future::join_all;
+use itertools::Itertools;
+use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
+use rustc_hash::FxHashSet as HashSet;
+use tokio::sync::{
+  Mutex,
+  oneshot::{self, Receiver},
+};
+
+use crate::{
+  FileSystem, ItemKey, ItemPairs, ItemValue, PackStorage, PackStorageOptions, Storage,
+  error::{Error, ErrorType, Result},
+};
+
+const MANIFEST_FILE: &str = "storage_meta";
+
+pub struct RemoteStorageOptions {
+  pub pack: PackStorageOptions,
+  pub client: Arc<dyn RemoteClient>,
+  /// Only download packs from remote, never upload local packs.
+  pub read_only: bool,
+  /// See `PackStorage::new_adaptive`.
+  pub adaptive: bool,
+}
+
+/// Remote Storage
+///
+/// A read-through layer in front of `PackStorage`.
+///
+/// The remote is a content-addressed object store:
+/// * `objects/{hash}` holds the content of a pack storage file
+/// * `refs/{version}` holds the manifest of a version, each line is `{hash} {relative_path}`
+///
+/// When the local version directory has no storage meta, the files listed in the
+/// remote manifest are downloaded before the first load. After each save, the
+/// missing objects and the new manifest are uploaded. The objects known to exist on
+/// the remote are remembered, so they are not checked again on the next save.
+#[derive(Debug)]
+pub struct RemoteStorage {
+  local: PackStorage,
+  client: Arc<dyn RemoteClient>,
+  fs: Arc<dyn FileSystem>,
+  root: Arc<Utf8PathBuf>,
+  version: Arc<String>,
+  read_only: bool,
+  pulled: Mutex<bool>,
+  known_objects: Arc<Mutex<HashSet<String>>>,
+}
+
+impl RemoteStorage {
+  pub fn new(options: RemoteStorageOptions) -> Self {
+    let pack = options.pack;
+    let root = pack.root.join(&pack.version).assert_utf8();
+    let fs = pack.fs.clone();
+    let version = pack.version.clone();
+    Self {
+      local: if options.adaptive {
+        PackStorage::new_adaptive(pack)
+      } else {
+        PackStorage::new(pack)
+      },
+      client: options.client,
+      fs,
+      root: Arc::new(root),
+      version: Arc::new(version),
+      read_only: options.read_only,
+      pulled: Mutex::new(false),
+      known_objects: Default::default(),
+    }
+  }
+
+  async fn pull(&self) -> Result<()> {
+    let mut pulled = self.pulled.lock().await;
+    if *pulled {
+      return Ok(());
+    }
+    *pulled = true;
+
+    if self.fs.exists(&self.root.join(MANIFEST_FILE)).await? {
+      return Ok(());
+    }
+    let Some(manifest) = self.client.get(&manifest_key(&self.version)).await? else {
+      return Ok(());
+    };
+    let files = parse_manifest(&manifest)?;
+
+    let tasks = files.into_iter().map(|(hash, path)| {
+      let client = self.client.clone();
+      async move {
+        let Some(content) = client.get(&object_key(&hash)).await? else {
+          return Err(Error::from_reason(
+            Some(ErrorType::Load),
+            None,
+            format!("remote object `{hash}` of `{path}` is missing"),
+          ));
+        };
+        if get_content_hash(&content) != hash {
+          return Err(Error::from_reason(
+            Some(ErrorType::Load),
+            None,
+            format!("remote object `{hash}` of `{path}` is modified"),
+          ));
+        }
+        Ok::<_, Error>((path, content))
+      }
+    });
+    let mut contents = join_all(tasks)
+      .await
+      .into_iter()
+      .collect::<Result<Vec<_>>>()?;
+    self.known_objects.lock().await.extend(
+      contents
+        .iter()
+        .map(|(_, content)| get_content_hash(content)),
+    );
+
+    // write storage meta at last, so that an interrupted pull is never treated as a valid storage
+    contents.sort_by_key(|(path, _)| path == MANIFEST_FILE);
+    for (path, content) in contents {
+      let mut writer = self.fs.write_file(&self.root.join(path)).await?;
+      writer.write_all(&content).await?;
+      writer.flush().await?;
+    }
+    Ok(())
+  }
+}
+
+async fn push(
+  client: Arc<dyn RemoteClient>,
+  fs: Arc<dyn FileSystem>,
+  root: &Utf8Path,
+  version: &str,
+  known_objects: Arc<Mutex<HashSet<String>>>,
+) -> Result<()> {
+  let files = collect_files(fs.as_ref(), root).await?;
+
+  let tasks = files.into_iter().map(|path| {
+    let client = client.clone();
+    let fs = fs.clone();
+    let known_objects = known_objects.clone();
+    async move {
+      let content = fs.read_file(&path).await?.read_to_end().await?;
+      let hash = get_content_hash(&content);
+      if !known_objects.lock().await.contains(&hash) {
+        let key = object_key(&hash);
+        if !client.exists(&key).await? {
+          client.put(&key, content).await?;
+        }
+        known_objects.lock().await.insert(hash.clone());
+      }
+      let relative = path
+        .strip_prefix(root)
+        .expect("should be inside storage root")
+        .to_string();
+      Ok::<_, Error>((hash, relative))
+    }
+  });
+  let files = join_all(tasks)
+    .await
+    .into_iter()
+    .collect::<Result<Vec<_>>>()?;
+
+  let manifest = files
+    .into_iter()
+    .sorted()
+    .map(|(hash, path)| format!("{hash} {path}"))
+    .join("\n");
+  client
+    .put(&manifest_key(version), manifest.into_bytes())
+    .await
+}
+
+async fn collect_files(fs: &dyn FileSystem, root: &Utf8Path) -> Result<Vec<Utf8PathBuf>> {
+  let mut files = vec![];
+  if !fs.exists(root).await? {
+    return Ok(files);
+  }
+  let mut dirs = vec![root.to_path_buf()];
+  while let Some(dir) = dirs.pop() {
+    for name in fs.read_dir(&dir).await? {
+      // lock files only exist during saving
+      if name.ends_with(".lock") {
+        continue;
+      }
+      let path = dir.join(name);
+      if fs.metadata(&path).await?.is_directory {
+        dirs.push(path);
+      } else {
+        files.push(path);
+      }
+    }
+  }
+  Ok(files)
+}
+
+fn parse_manifest(manifest: &[u8]) -> Result<Vec<(String, String)>> {
+  let invalid = || {
+    Error::from_reason(
+      Some(ErrorType::Load),
+      None,
+      "remote manifest is invalid".to_string(),
+    )
+  };
+  std::str::from_utf8(manifest)
+    .map_err(|_| invalid())?
+    .lines()
+    .filter(|line| !line.is_empty())
+    .map(|line| {
+      let (hash, path) = line.split_once(' ').ok_or_else(invalid)?;
+      if path.split('/').any(|part| part == ".." || part.is_empty()) {
+        return Err(invalid());
+      }
+      Ok((hash.to_string(), path.to_string()))
+    })
+    .collect()
+}
+
+fn get_content_hash(content: &[u8]) -> String {
+  format!("{:032x}", xxhash_rust::xxh3::xxh3_128(content))
+}
+
+fn object_key(hash: &str) -> String {
+  format!("objects/{hash}")
+}
+
+fn manifest_key(version: &str) -> String {
+  format!("refs/{version}")
+}
+
+#[async_trait::async_trait]
+impl Storage for RemoteStorage {
+  async fn load(&self, scope: &'static str) -> Result<ItemPairs> {
+    if let Err(err) = self.pull().await {
+      // a broken remote should not block the local cache
+      tracing::warn!("pull remote storage failed: {err}");
+    }
+    self.local.load(scope).await
+  }
+  fn set(&self, scope: &'static str, key: ItemKey, value: ItemValue) {
+    self.local.set(scope, key, value)
+  }
+  fn remove(&self, scope: &'static str, key: &[u8]) {
+    self.local.remove(scope, key)
+  }
+  fn trigger_save(&self) -> Result<Receiver<Result<()>>> {
+    let local_rx = self.local.trigger_save()?;
+    if self.read_only {
+      return Ok(local_rx);
+    }
+
+    let (tx, rx) = oneshot::channel();
+    let client = self.client.clone();
+    let fs = self.fs.clone();
+    let root = self.root.clone();
+    let version = self.version.clone();
+    let known_objects = self.known_objects.clone();
+    tokio::spawn(async move {
+      let res = match local_rx.await {
+        Ok(Ok(())) => push(client, fs, &root, &version, known_objects).await,
+        Ok(Err(e)) => Err(e),
+        Err(e) => Err(Error::from_error(Some(ErrorType::Save), None, Box::new(e))),
+      };
+      let _ = tx.send(res);
+    });
+    Ok(rx)
+  }
+  async fn reset(&self) {
+    self.local.reset().await;
+  }
+  async fn scopes(&self) -> Result<Vec<String>> {
+    if let Err(err) = self.pull().await {
+      tracing::warn!("pull remote storage failed: {err}");
+    }
+    self.local.scopes().await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::parse_manifest;
+
+  #[test]
+  fn should_parse_manifest() {
+    let files = parse_manifest(b"aaa storage_meta\nbbb scope/scope_meta\n").expect("should parse");
+    assert_eq!(
+      files,
+      vec![
+        ("aaa".to_string(), "storage_meta".to_string()),
+        ("bbb".to_string(), "scope/scope_meta".to_string()),
+      ]
+    );
+    assert!(parse_manifest(b"aaa").is_err());
+    assert!(parse_manifest(b"aaa ../outside").is_err());
+  }
+}
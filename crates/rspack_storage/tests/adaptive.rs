@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod test_storage_adaptive {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, NativeFileSystem};
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, PackStorage, PackStorageOptions, Result, Storage,
+  };
+
+  pub fn get_native_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_adaptive")
+      .join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_adaptive/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_pack_options(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    version: &str,
+    fs: Arc<dyn FileSystem>,
+  ) -> PackStorageOptions {
+    PackStorageOptions {
+      version: version.to_string(),
+      root: root.into(),
+      temp_root: temp_root.into(),
+      fs,
+      bucket_size: 10,
+      pack_size: 200,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      clean: true,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+    }
+  }
+
+  async fn read_scope_options(root: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Result<String> {
+    let mut reader = fs
+      .read_file(&root.join("xxx/test_scope/scope_meta"))
+      .await?;
+    Ok(reader.read_line().await?)
+  }
+
+  /// Get the count of the packs in scope meta and the count of the pack files in the scope
+  async fn count_packs(root: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Result<(usize, usize)> {
+    let scope_dir = root.join("xxx/test_scope");
+    let mut reader = fs.read_file(&scope_dir.join("scope_meta")).await?;
+    let bucket_size = reader
+      .read_line()
+      .await?
+      .split(' ')
+      .next()
+      .and_then(|size| size.parse::<usize>().ok())
+      .expect("should have bucket size");
+    let mut meta_packs = 0;
+    for _ in 0..bucket_size {
+      meta_packs += reader
+        .read_line()
+        .await?
+        .split(' ')
+        .filter(|pack| !pack.is_empty())
+        .count();
+    }
+
+    let mut files = 0;
+    let mut dirs = vec![scope_dir];
+    while let Some(dir) = dirs.pop() {
+      for name in fs.read_dir(&dir).await? {
+        let path = dir.join(name);
+        if fs.metadata(&path).await?.is_directory {
+          dirs.push(path);
+        } else if path.file_name() != Some("scope_meta") {
+          files += 1;
+        }
+      }
+    }
+    Ok((meta_packs, files))
+  }
+
+  async fn test_initial_build(
+    root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+    options: PackStorageOptions,
+  ) -> Result<()> {
+    let storage = PackStorage::new_adaptive(options);
+    assert!(storage.load("test_scope").await?.is_empty());
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    // new scope uses the configured sizes
+    assert!(
+      read_scope_options(root, fs)
+        .await?
+        .starts_with("10 200 1 100")
+    );
+    Ok(())
+  }
+
+  async fn test_resize(
+    root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+    options: PackStorageOptions,
+  ) -> Result<()> {
+    let storage = PackStorage::new_adaptive(options);
+    assert_eq!(storage.load("test_scope").await?.len(), 100);
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    // resized by the observed item count
+    assert!(
+      read_scope_options(root, fs.clone())
+        .await?
+        .starts_with(&format!("4 {} 1 100", 64 * 1024))
+    );
+    // the packs written with the old sizes are removed
+    let (meta_packs, files) = count_packs(root, fs).await?;
+    assert_eq!(meta_packs, files);
+    Ok(())
+  }
+
+  async fn test_stable(
+    root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+    options: PackStorageOptions,
+  ) -> Result<()> {
+    let storage = PackStorage::new_adaptive(options);
+    assert_eq!(storage.load("test_scope").await?.len(), 100);
+    storage.set(
+      "test_scope",
+      "key_new".as_bytes().to_vec(),
+      "val_new".as_bytes().to_vec(),
+    );
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    // sizes are kept since the item count does not change much
+    assert!(
+      read_scope_options(root, fs)
+        .await?
+        .starts_with(&format!("4 {} 2 101", 64 * 1024))
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_adaptive() -> Result<()> {
+    let cases = [
+      (
+        get_native_path("test_adaptive_native"),
+        Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+      ),
+      (
+        get_memory_path("test_adaptive_memory"),
+        Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      ),
+    ];
+
+    for ((root, temp_root), fs) in cases {
+      let root = root.assert_utf8();
+      let temp_root = temp_root.assert_utf8();
+      fs.remove_dir(&root).await.expect("should remove root");
+      fs.remove_dir(&temp_root)
+        .await
+        .expect("should remove temp root");
+
+      test_initial_build(
+        &root,
+        fs.clone(),
+        create_pack_options(&root, &temp_root, "xxx", fs.clone()),
+      )
+      .await?;
+      test_resize(
+        &root,
+        fs.clone(),
+        create_pack_options(&root, &temp_root, "xxx", fs.clone()),
+      )
+      .await?;
+      test_stable(
+        &root,
+        fs.clone(),
+        create_pack_options(&root, &temp_root, "xxx", fs.clone()),
+      )
+      .await?;
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,224 @@
+#[cfg(test)]
+mod test_storage_inspect {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, NativeFileSystem};
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, PackStorage, PackStorageInspector, PackStorageOptions, Result,
+    Storage,
+  };
+
+  pub fn get_native_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_inspect")
+      .join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_inspect/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_storage(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> PackStorage {
+    PackStorage::new(PackStorageOptions {
+      version: "xxx".to_string(),
+      root: root.into(),
+      temp_root: temp_root.into(),
+      fs,
+      bucket_size: 10,
+      pack_size: 200,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      clean: true,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+    })
+  }
+
+  async fn test_build(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    // save in several generations to produce small packs
+    for round in 0..4 {
+      let storage = create_storage(root, temp_root, fs.clone());
+      assert_eq!(storage.load("test_scope").await?.len(), round * 25);
+      for i in round * 25..(round + 1) * 25 {
+        storage.set(
+          "test_scope",
+          format!("key_{i:0>3}").as_bytes().to_vec(),
+          format!("val_{i:0>3}").as_bytes().to_vec(),
+        );
+      }
+      let rx = storage.trigger_save()?;
+      rx.await.expect("should save")?;
+    }
+    Ok(())
+  }
+
+  async fn test_inspect(inspector: &PackStorageInspector) -> Result<usize> {
+    let reports = inspector.inspect(true).await?;
+    assert_eq!(reports.len(), 1);
+    let report = &reports[0];
+    assert_eq!(report.version, "xxx");
+    assert!(report.is_valid());
+    assert_eq!(report.scopes.len(), 1);
+    let scope = &report.scopes[0];
+    assert_eq!(scope.name, "test_scope");
+    assert_eq!(scope.bucket_size, 10);
+    assert_eq!(scope.item_count, 100);
+    assert!(scope.size() > 0);
+    assert!(scope.unused_files.is_empty());
+    assert!(scope.invalid_packs.is_empty());
+    assert!(!scope.missing_meta);
+    Ok(scope.packs.len())
+  }
+
+  async fn test_gc(
+    root: &Utf8PathBuf,
+    inspector: &PackStorageInspector,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let garbage = root.join("xxx/test_scope/0/garbage.pack");
+    fs.ensure_dir(&root.join("xxx/test_scope/0")).await?;
+    let mut writer = fs.write_file(&garbage).await?;
+    writer.write_all("garbage".as_bytes()).await?;
+    writer.flush().await?;
+    fs.ensure_dir(&root.join("xxx/unused_scope")).await?;
+    fs.ensure_dir(&root.join("yyy/test_scope")).await?;
+
+    let report = inspector.inspect_version("xxx", false).await?;
+    assert_eq!(report.scopes[0].unused_files, vec![garbage.clone()]);
+    assert!(
+      inspector
+        .inspect_version("yyy", false)
+        .await?
+        .expire_time
+        .is_none()
+    );
+    // versions without storage meta are skipped
+    assert_eq!(
+      inspector.latest_version("zzz").await?,
+      Some("xxx".to_string())
+    );
+    assert_eq!(inspector.latest_version("xxx").await?, None);
+
+    let mut removed = inspector.gc().await?;
+    removed.sort();
+    assert_eq!(
+      removed,
+      vec![garbage, root.join("xxx/unused_scope"), root.join("yyy")]
+    );
+    assert_eq!(inspector.versions().await?, vec!["xxx".to_string()]);
+    Ok(())
+  }
+
+  async fn test_compact(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    inspector: &PackStorageInspector,
+    fs: Arc<dyn FileSystem>,
+    pack_count: usize,
+  ) -> Result<()> {
+    inspector.compact("xxx").await?;
+    assert!(test_inspect(inspector).await? <= pack_count);
+
+    let storage = create_storage(root, temp_root, fs);
+    assert_eq!(storage.load("test_scope").await?.len(), 100);
+    Ok(())
+  }
+
+  async fn test_validate(inspector: &PackStorageInspector, fs: Arc<dyn FileSystem>) -> Result<()> {
+    let report = inspector.inspect_version("xxx", false).await?;
+    let pack = &report.scopes[0].packs[0].path;
+    let mut writer = fs.write_file(pack).await?;
+    writer.write_all("modified".as_bytes()).await?;
+    writer.flush().await?;
+
+    let report = inspector.inspect_version("xxx", true).await?;
+    assert!(!report.is_valid());
+    assert_eq!(report.scopes[0].invalid_packs, vec![pack.to_string()]);
+    assert!(inspector.compact("xxx").await.is_err());
+    Ok(())
+  }
+
+  async fn test_missing_meta(
+    root: &Utf8PathBuf,
+    inspector: &PackStorageInspector,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    fs.remove_file(&root.join("xxx/test_scope/scope_meta"))
+      .await?;
+
+    let report = inspector.inspect_version("xxx", true).await?;
+    assert!(!report.is_valid());
+    assert_eq!(report.scopes.len(), 1);
+    assert!(report.scopes[0].missing_meta);
+    assert!(report.scopes[0].invalid_packs.is_empty());
+    assert!(report.scopes[0].packs.is_empty());
+    Ok(())
+  }
+
+  async fn test_corrupted_meta(
+    root: &Utf8PathBuf,
+    inspector: &PackStorageInspector,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let scope_dir = root.join("xxx/test_scope");
+    let mut writer = fs.write_file(&scope_dir.join("scope_meta")).await?;
+    writer.write_all("corrupted".as_bytes()).await?;
+    writer.flush().await?;
+
+    let report = inspector.inspect_version("xxx", true).await?;
+    assert!(!report.is_valid());
+    assert_eq!(report.scopes.len(), 1);
+    assert!(report.scopes[0].error.is_some());
+    assert!(!report.scopes[0].missing_meta);
+
+    assert!(inspector.gc().await?.contains(&scope_dir));
+    let report = inspector.inspect_version("xxx", false).await?;
+    assert!(report.scopes[0].missing_meta);
+    assert!(report.scopes[0].error.is_none());
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_inspector() -> Result<()> {
+    let cases = [
+      (
+        get_native_path("test_inspector_native"),
+        Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+      ),
+      (
+        get_memory_path("test_inspector_memory"),
+        Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      ),
+    ];
+
+    for ((root, temp_root), fs) in cases {
+      let root = root.assert_utf8();
+      let temp_root = temp_root.assert_utf8();
+      fs.remove_dir(&root).await.expect("should remove root");
+      fs.remove_dir(&temp_root)
+        .await
+        .expect("should remove temp root");
+
+      test_build(&root, &temp_root, fs.clone()).await?;
+      let inspector = PackStorageInspector::new(root.clone(), temp_root.clone(), fs.clone());
+      let pack_count = test_inspect(&inspector).await?;
+      test_gc(&root, &inspector, fs.clone()).await?;
+      test_compact(&root, &temp_root, &inspector, fs.clone(), pack_count).await?;
+      test_validate(&inspector, fs.clone()).await?;
+      test_missing_meta(&root, &inspector, fs.clone()).await?;
+      test_corrupted_meta(&root, &inspector, fs.clone()).await?;
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,212 @@
+#[cfg(test)]
+mod test_storage_remote {
+  use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+  };
+
+  use rspack_fs::MemoryFileSystem;
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, HttpRemoteClient, PackStorageOptions, RemoteStorage,
+    RemoteStorageOptions, Result, Storage,
+  };
+  use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+  };
+
+  type Objects = Arc<Mutex<HashMap<String, Vec<u8>>>>;
+
+  /// A local stand-in of the remote object store
+  async fn start_server() -> (String, Objects) {
+    let listener = TcpListener::bind("127.0.0.1:0")
+      .await
+      .expect("should bind port");
+    let url = format!(
+      "http://{}/cache",
+      listener.local_addr().expect("should have address")
+    );
+    let objects: Objects = Default::default();
+    let server_objects = objects.clone();
+    tokio::spawn(async move {
+      loop {
+        let Ok((stream, _)) = listener.accept().await else {
+          break;
+        };
+        tokio::spawn(handle_request(stream, server_objects.clone()));
+      }
+    });
+    (url, objects)
+  }
+
+  async fn handle_request(mut stream: TcpStream, objects: Objects) {
+    let mut buf = vec![];
+    let head_end = loop {
+      let mut chunk = [0; 1024];
+      let n = stream.read(&mut chunk).await.expect("should read request");
+      if n == 0 {
+        return;
+      }
+      buf.extend_from_slice(&chunk[..n]);
+      if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+        break pos;
+      }
+    };
+    let head = String::from_utf8(buf[..head_end].to_vec()).expect("should be utf8");
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().expect("should have request line").split(' ');
+    let method = request_line.next().expect("should have method").to_string();
+    let key = request_line
+      .next()
+      .expect("should have path")
+      .trim_start_matches("/cache/")
+      .to_string();
+    let length = lines
+      .filter_map(|line| line.split_once(':'))
+      .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+      .map(|(_, value)| value.trim().parse::<usize>().expect("should be number"))
+      .unwrap_or_default();
+    let mut body = buf[head_end + 4..].to_vec();
+    while body.len() < length {
+      let mut chunk = [0; 1024];
+      let n = stream.read(&mut chunk).await.expect("should read body");
+      if n == 0 {
+        return;
+      }
+      body.extend_from_slice(&chunk[..n]);
+    }
+
+    let (status, content) = match method.as_str() {
+      "PUT" => {
+        objects.lock().expect("should get lock").insert(key, body);
+        ("201 Created", vec![])
+      }
+      "GET" | "HEAD" => match objects.lock().expect("should get lock").get(&key) {
+        Some(content) => ("200 OK", content.clone()),
+        None => ("404 Not Found", vec![]),
+      },
+      _ => ("405 Method Not Allowed", vec![]),
+    };
+    let mut res = format!(
+      "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+      content.len()
+    )
+    .into_bytes();
+    if method != "HEAD" {
+      res.extend(content);
+    }
+    stream.write_all(&res).await.expect("should write response");
+    let _ = stream.shutdown().await;
+  }
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_remote/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_storage(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    url: &str,
+    read_only: bool,
+    fs: Arc<dyn FileSystem>,
+  ) -> RemoteStorage {
+    RemoteStorage::new(RemoteStorageOptions {
+      pack: PackStorageOptions {
+        version: "xxx".to_string(),
+        root: root.into(),
+        temp_root: temp_root.into(),
+        fs,
+        bucket_size: 10,
+        pack_size: 200,
+        expire: 7 * 24 * 60 * 60 * 1000,
+        clean: true,
+        fresh_generation: Some(1),
+        release_generation: Some(2),
+      },
+      client: Arc::new(HttpRemoteClient::new(url.to_string(), vec![])),
+      read_only,
+      adaptive: false,
+    })
+  }
+
+  async fn test_upload(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    url: &str,
+    objects: &Objects,
+  ) -> Result<()> {
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let storage = create_storage(root, temp_root, url, false, fs);
+    assert!(storage.load("test_scope").await?.is_empty());
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+
+    let objects = objects.lock().expect("should get lock");
+    assert!(objects.contains_key("refs/xxx"));
+    assert!(objects.keys().filter(|k| k.starts_with("objects/")).count() > 2);
+    Ok(())
+  }
+
+  async fn test_download(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    url: &str,
+    objects: &Objects,
+  ) -> Result<()> {
+    let object_count = objects.lock().expect("should get lock").len();
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let storage = create_storage(root, temp_root, url, true, fs.clone());
+    assert_eq!(storage.load("test_scope").await?.len(), 100);
+    assert!(fs.exists(&root.join("xxx/storage_meta")).await?);
+
+    storage.set(
+      "test_scope",
+      "key_new".as_bytes().to_vec(),
+      "val_new".as_bytes().to_vec(),
+    );
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    // read only storage never uploads
+    assert_eq!(objects.lock().expect("should get lock").len(), object_count);
+    Ok(())
+  }
+
+  async fn test_broken_remote(root: &Utf8PathBuf, temp_root: &Utf8PathBuf) -> Result<()> {
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    // nothing listens on port 1
+    let storage = create_storage(root, temp_root, "http://127.0.0.1:1", false, fs);
+    assert!(storage.load("test_scope").await?.is_empty());
+    storage.set(
+      "test_scope",
+      "key".as_bytes().to_vec(),
+      "val".as_bytes().to_vec(),
+    );
+    let rx = storage.trigger_save()?;
+    assert!(rx.await.expect("should save").is_err());
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_remote_storage() -> Result<()> {
+    let (url, objects) = start_server().await;
+    let (root, temp_root) = get_memory_path("test_remote");
+    let root = root.assert_utf8();
+    let temp_root = temp_root.assert_utf8();
+
+    test_upload(&root, &temp_root, &url, &objects).await?;
+    test_download(&root, &temp_root, &url, &objects).await?;
+    test_broken_remote(&root, &temp_root).await?;
+    Ok(())
+  }
+}
@@ -63,6 +63,7 @@ pub fn load_storages_from_path(path: &Utf8PathBuf) -> HashMap<String, Arc<dyn St
     let storage = create_storage(
       StorageOptions::FileSystem {
         directory: path.clone().into(),
+        pack: Default::default(),
       },
       v.clone(),
       fs.clone(),
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use rspack_error::Result;
+use rspack_fs::NativeFileSystem;
+use rspack_paths::Utf8PathBuf;
+use rspack_storage::{BridgeFileSystem, PackStorageInspector, ScopeReport, VersionReport};
+use serde_json::{Value, json};
+
+/// Create an inspector for the storage directory, which is the `storage.directory`
+/// of persistent cache and contains a sub directory for each version.
+fn create_inspector(path: Utf8PathBuf) -> PackStorageInspector {
+  let fs = Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false))));
+  // keep the same temp root as the compiler
+  let temp_root = path.join(".temp");
+  PackStorageInspector::new(path, temp_root, fs)
+}
+
+fn scope_to_json(scope: &ScopeReport) -> Value {
+  let packs = scope
+    .packs
+    .iter()
+    .map(|pack| {
+      json!({
+        "path": pack.path.as_str(),
+        "hash": pack.hash,
+        "size": pack.size,
+        "generation": pack.generation,
+      })
+    })
+    .collect::<Vec<_>>();
+  let unused_files = scope
+    .unused_files
+    .iter()
+    .map(|file| file.as_str())
+    .collect::<Vec<_>>();
+  json!({
+    "name": scope.name,
+    "bucketSize": scope.bucket_size,
+    "packSize": scope.pack_size,
+    "generation": scope.generation,
+    "itemCount": scope.item_count,
+    "packCount": scope.packs.len(),
+    "size": scope.size(),
+    "packs": packs,
+    "unusedFiles": unused_files,
+    "invalidPacks": scope.invalid_packs,
+    "missingMeta": scope.missing_meta,
+    "error": scope.error,
+  })
+}
+
+fn version_to_json(version: &VersionReport) -> Value {
+  json!({
+    "version": version.version,
+    "expireTime": version.expire_time,
+    "expired": version.expired,
+    "valid": version.is_valid(),
+    "packCount": version.scopes.iter().map(|scope| scope.packs.len()).sum::<usize>(),
+    "size": version.scopes.iter().map(|scope| scope.size()).sum::<usize>(),
+    "scopes": version.scopes.iter().map(scope_to_json).collect::<Vec<_>>(),
+  })
+}
+
+fn versions_to_json(path: &Utf8PathBuf, versions: &[VersionReport]) -> Value {
+  let expired = versions
+    .iter()
+    .filter(|version| version.expired || version.expire_time.is_none());
+  json!({
+    "root": path.as_str(),
+    "summary": {
+      "versions": versions.len(),
+      "expiredVersions": expired.clone().count(),
+      "expiredPacks": expired
+        .flat_map(|version| version.scopes.iter())
+        .map(|scope| scope.packs.len())
+        .sum::<usize>(),
+      "invalidPacks": versions
+        .iter()
+        .flat_map(|version| version.scopes.iter())
+        .map(|scope| scope.invalid_packs.len())
+        .sum::<usize>(),
+      "unusedFiles": versions
+        .iter()
+        .flat_map(|version| version.scopes.iter())
+        .map(|scope| scope.unused_files.len())
+        .sum::<usize>(),
+    },
+    "versions": versions.iter().map(version_to_json).collect::<Vec<_>>(),
+  })
+}
+
+/// List versions, scopes and packs of a storage directory
+pub async fn inspect_cache_dir(path: Utf8PathBuf, validate: bool) -> Result<Value> {
+  let inspector = create_inspector(path.clone());
+  let versions = inspector.inspect(validate).await?;
+  Ok(versions_to_json(&path, &versions))
+}
+
+/// Validate the hash of every pack in a storage directory
+///
+/// Return the report and whether all versions are valid.
+pub async fn validate_cache_dir(path: Utf8PathBuf) -> Result<(Value, bool)> {
+  let inspector = create_inspector(path.clone());
+  let versions = inspector.inspect(true).await?;
+  let valid = versions.iter().all(|version| version.is_valid());
+  Ok((versions_to_json(&path, &versions), valid))
+}
+
+/// Remove expired versions, unused scopes and unused files of a storage directory
+pub async fn gc_cache_dir(path: Utf8PathBuf) -> Result<Value> {
+  let inspector = create_inspector(path.clone());
+  let removed = inspector.gc().await?;
+  Ok(json!({
+    "root": path.as_str(),
+    "removed": removed.iter().map(|file| file.as_str()).collect::<Vec<_>>(),
+  }))
+}
+
+/// Rewrite the packs of versions in a storage directory, all versions are compacted if
+/// `version` is not specified.
+pub async fn compact_cache_dir(path: Utf8PathBuf, version: Option<String>) -> Result<Value> {
+  let inspector = create_inspector(path.clone());
+  let versions = match version {
+    Some(version) => vec![version],
+    None => inspector.versions().await?,
+  };
+  let mut results = vec![];
+  for version in versions {
+    let before = inspector.inspect_version(&version, false).await?;
+    if before.expire_time.is_none() || before.expired {
+      // nothing to keep, leave it to gc
+      continue;
+    }
+    inspector.compact(&version).await?;
+    let after = inspector.inspect_version(&version, false).await?;
+    results.push(json!({
+      "version": version,
+      "before": version_to_json(&before),
+      "after": version_to_json(&after),
+    }));
+  }
+  Ok(json!({
+    "root": path.as_str(),
+    "versions": results,
+  }))
+}
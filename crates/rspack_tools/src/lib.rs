@@ -1,5 +1,7 @@
 mod compare;
 mod debug_info;
+mod inspect;
 mod utils;
 
 pub use compare::compare_cache_dir;
+pub use inspect::{compact_cache_dir, gc_cache_dir, inspect_cache_dir, validate_cache_dir};
@@ -1,7 +1,9 @@
 use clap::{Parser, Subcommand};
 use rspack_error::Diagnostic;
 use rspack_paths::Utf8PathBuf;
-use rspack_tools::compare_cache_dir;
+use rspack_tools::{
+  compact_cache_dir, compare_cache_dir, gc_cache_dir, inspect_cache_dir, validate_cache_dir,
+};
 
 /// Toolkit for debugging and testing rspack internals
 #[derive(Parser, Debug)]
@@ -25,6 +27,55 @@ enum Commands {
     #[arg(value_name = "CACHE2")]
     cache2: String,
   },
+  /// Print versions, scopes and packs of a rspack cache directory as json
+  Inspect {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+
+    /// Validate the hash of every pack
+    #[arg(long)]
+    validate: bool,
+  },
+  /// Validate the hash of every pack in a rspack cache directory
+  Validate {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+  },
+  /// Remove expired versions and unused files in a rspack cache directory
+  Gc {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+  },
+  /// Rewrite the packs of a rspack cache directory to reduce fragmentation
+  Compact {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+
+    /// Only compact the specified version
+    #[arg(long, value_name = "VERSION")]
+    version: Option<String>,
+  },
+}
+
+fn exit_with_error(err: rspack_error::Error) -> ! {
+  eprintln!(
+    "{}",
+    Diagnostic::from(err)
+      .render_report(true)
+      .expect("render error failed")
+  );
+  std::process::exit(1);
+}
+
+fn print_json(value: &serde_json::Value) {
+  println!(
+    "{}",
+    serde_json::to_string_pretty(value).expect("should serialize report")
+  );
 }
 
 #[tokio::main]
@@ -42,16 +93,35 @@ async fn main() {
       let path2 = Utf8PathBuf::from(&cache2);
 
       if let Err(err) = compare_cache_dir(path1, path2).await {
-        eprintln!(
-          "{}",
-          Diagnostic::from(err)
-            .render_report(true)
-            .expect("render error failed")
-        );
-        std::process::exit(1);
+        exit_with_error(err);
       }
 
       println!("✓ Cache directories are identical");
     }
+    Commands::Inspect { cache, validate } => {
+      match inspect_cache_dir(Utf8PathBuf::from(&cache), validate).await {
+        Ok(report) => print_json(&report),
+        Err(err) => exit_with_error(err),
+      }
+    }
+    Commands::Validate { cache } => match validate_cache_dir(Utf8PathBuf::from(&cache)).await {
+      Ok((report, valid)) => {
+        print_json(&report);
+        if !valid {
+          std::process::exit(1);
+        }
+      }
+      Err(err) => exit_with_error(err),
+    },
+    Commands::Gc { cache } => match gc_cache_dir(Utf8PathBuf::from(&cache)).await {
+      Ok(report) => print_json(&report),
+      Err(err) => exit_with_error(err),
+    },
+    Commands::Compact { cache, version } => {
+      match compact_cache_dir(Utf8PathBuf::from(&cache), version).await {
+        Ok(report) => print_json(&report),
+        Err(err) => exit_with_error(err),
+      }
+    }
   }
 }
@@ -0,0 +1,158 @@
+//! A lightweight scanner of CSS sources, which finds the rules, blocks and selectors without
+//! parsing the stylesheet.
+
+use std::ops::Range;
+
+/// The at-rules whose blocks contain rules, e.g. `@media` and `@supports`.
+pub const GROUP_AT_RULES: &[&str] = &[
+  "media",
+  "supports",
+  "layer",
+  "container",
+  "document",
+  "-moz-document",
+  "starting-style",
+];
+
+/// Split the selector list between `start` and `end` into complex selectors, return the ranges
+/// of them with the surrounding whitespace trimmed.
+pub fn split_selectors(source: &str, start: usize, end: usize) -> Vec<Range<usize>> {
+  let bytes = source.as_bytes();
+  let mut selectors = vec![];
+  let mut depth = 0usize;
+  let mut selector_start = start;
+  let mut i = start;
+  let mut push = |selector_start: usize, selector_end: usize| {
+    let text = &source[selector_start..selector_end];
+    let trimmed_start = selector_start + (text.len() - text.trim_start().len());
+    let trimmed_end = selector_end - (text.len() - text.trim_end().len());
+    if trimmed_start < trimmed_end {
+      selectors.push(trimmed_start..trimmed_end);
+    }
+  };
+  while i < end {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        i = skip_comment(bytes, i).min(end);
+        continue;
+      }
+      b'"' | b'\'' => {
+        i = skip_string(bytes, i).min(end);
+        continue;
+      }
+      b'\\' => {
+        i += 2;
+        continue;
+      }
+      b'(' | b'[' => depth += 1,
+      b')' | b']' => depth = depth.saturating_sub(1),
+      b',' if depth == 0 => {
+        push(selector_start, i);
+        selector_start = i + 1;
+      }
+      _ => {}
+    }
+    i += 1;
+  }
+  push(selector_start, end);
+  selectors
+}
+
+/// Return the index after the comment starting at `start`.
+pub fn skip_comment(bytes: &[u8], start: usize) -> usize {
+  let mut i = start + 2;
+  while i + 1 < bytes.len() {
+    if bytes[i] == b'*' && bytes[i + 1] == b'/' {
+      return i + 2;
+    }
+    i += 1;
+  }
+  bytes.len()
+}
+
+/// Return the index after the string starting at `start`.
+pub fn skip_string(bytes: &[u8], start: usize) -> usize {
+  let quote = bytes[start];
+  let mut i = start + 1;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'\\' => i += 1,
+      b'\n' => return i,
+      c if c == quote => return i + 1,
+      _ => {}
+    }
+    i += 1;
+  }
+  bytes.len()
+}
+
+/// Return the index after the block starting at `start`, which is a `{`.
+pub fn skip_block(bytes: &[u8], start: usize) -> usize {
+  let mut depth = 0usize;
+  let mut i = start;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        i = skip_comment(bytes, i);
+        continue;
+      }
+      b'"' | b'\'' => {
+        i = skip_string(bytes, i);
+        continue;
+      }
+      b'\\' => i += 1,
+      b'{' => depth += 1,
+      b'}' => {
+        depth -= 1;
+        if depth == 0 {
+          return i + 1;
+        }
+      }
+      _ => {}
+    }
+    i += 1;
+  }
+  bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_split_selectors() {
+    let source = r#" .a , .b:is(.c, .d) /* , */, [data-x="a,b"],.e\,f "#;
+    let selectors = split_selectors(source, 0, source.len())
+      .into_iter()
+      .map(|range| &source[range])
+      .collect::<Vec<_>>();
+    assert_eq!(
+      selectors,
+      vec![".a", ".b:is(.c, .d) /* , */", r#"[data-x="a,b"]"#, r".e\,f"]
+    );
+    assert!(split_selectors("  ", 0, 2).is_empty());
+    assert_eq!(split_selectors(".a, ,", 0, 5), vec![0..2]);
+  }
+
+  #[test]
+  fn test_skip_comment() {
+    assert_eq!(skip_comment(b"/* a */b", 0), 7);
+    assert_eq!(skip_comment(b"/* a", 0), 4);
+  }
+
+  #[test]
+  fn test_skip_string() {
+    assert_eq!(skip_string(br#""a\"b"c"#, 0), 6);
+    assert_eq!(skip_string(b"'a\"b'c", 0), 5);
+    // an unescaped newline ends a bad string
+    assert_eq!(skip_string(b"'a\nb'", 0), 2);
+    assert_eq!(skip_string(b"'a", 0), 2);
+  }
+
+  #[test]
+  fn test_skip_block() {
+    assert_eq!(skip_block(b"{ a { b } }c", 0), 11);
+    assert_eq!(skip_block(br#"{ content: "}"; /* } */ }c"#, 0), 25);
+    assert_eq!(skip_block(b"{ a { b }", 0), 9);
+  }
+}
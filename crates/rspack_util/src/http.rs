@@ -0,0 +1,238 @@
+use std::time::Duration;
+
+use tokio::{
+  io::{AsyncReadExt, AsyncWriteExt},
+  net::TcpStream,
+  time::timeout,
+};
+
+/// Plain http client
+///
+/// A minimal HTTP/1.1 client which sends each request on a new connection.
+/// Only `http://` urls are supported, it's meant for the servers in a trusted
+/// network such as a local dev server or a cache server.
+#[derive(Debug, Clone)]
+pub struct PlainHttpClient {
+  headers: Vec<(String, String)>,
+  connect_timeout: Duration,
+  /// The timeout of sending the request and receiving the whole response.
+  response_timeout: Duration,
+}
+
+#[derive(Debug)]
+pub struct HttpResponse {
+  pub status: u16,
+  pub body: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub enum HttpError {
+  UnsupportedUrl(String),
+  Io(std::io::Error),
+  /// The stage which timed out, like `connecting`.
+  Timeout(&'static str),
+  MalformedResponse,
+}
+
+impl std::fmt::Display for HttpError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      HttpError::UnsupportedUrl(url) => write!(f, "unsupported url `{url}`"),
+      HttpError::Io(e) => write!(f, "{e}"),
+      HttpError::Timeout(stage) => write!(f, "timed out while {stage}"),
+      HttpError::MalformedResponse => write!(f, "got a malformed response"),
+    }
+  }
+}
+
+impl std::error::Error for HttpError {}
+
+#[derive(Debug)]
+struct HttpEndpoint {
+  /// The `host:port` to connect to.
+  authority: String,
+  host: String,
+  path: String,
+}
+
+impl HttpEndpoint {
+  fn parse(url: &str) -> Result<Self, HttpError> {
+    let Some(rest) = url.strip_prefix("http://") else {
+      return Err(HttpError::UnsupportedUrl(url.to_string()));
+    };
+    let rest = rest.split('#').next().unwrap_or_default();
+    let (host, path) = match rest.find('/') {
+      Some(index) => (&rest[..index], &rest[index..]),
+      None => (rest, "/"),
+    };
+    if host.is_empty() {
+      return Err(HttpError::UnsupportedUrl(url.to_string()));
+    }
+    let authority = if host.contains(':') {
+      host.to_string()
+    } else {
+      format!("{host}:80")
+    };
+    Ok(Self {
+      authority,
+      host: host.to_string(),
+      path: path.to_string(),
+    })
+  }
+}
+
+impl PlainHttpClient {
+  pub fn new(headers: Vec<(String, String)>) -> Self {
+    Self {
+      headers,
+      connect_timeout: Duration::from_secs(10),
+      response_timeout: Duration::from_secs(60),
+    }
+  }
+
+  pub fn with_timeouts(mut self, connect_timeout: Duration, response_timeout: Duration) -> Self {
+    self.connect_timeout = connect_timeout;
+    self.response_timeout = response_timeout;
+    self
+  }
+
+  pub async fn get(&self, url: &str) -> Result<HttpResponse, HttpError> {
+    self.request("GET", url, &[]).await
+  }
+
+  /// Send the request, the body of a `HEAD` response is always empty.
+  pub async fn request(
+    &self,
+    method: &str,
+    url: &str,
+    body: &[u8],
+  ) -> Result<HttpResponse, HttpError> {
+    let endpoint = HttpEndpoint::parse(url)?;
+    let mut stream = timeout(
+      self.connect_timeout,
+      TcpStream::connect(&endpoint.authority),
+    )
+    .await
+    .map_err(|_| HttpError::Timeout("connecting"))?
+    .map_err(HttpError::Io)?;
+    let mut head = format!(
+      "{method} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\nContent-Length: {}\r\n",
+      endpoint.path,
+      endpoint.host,
+      body.len()
+    );
+    for (name, value) in &self.headers {
+      head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str("\r\n");
+    let exchange = async {
+      stream.write_all(head.as_bytes()).await?;
+      stream.write_all(body).await?;
+      stream.flush().await?;
+      let mut buf = vec![];
+      stream.read_to_end(&mut buf).await?;
+      Ok::<_, std::io::Error>(buf)
+    };
+    let buf = timeout(self.response_timeout, exchange)
+      .await
+      .map_err(|_| HttpError::Timeout("waiting for the response"))?
+      .map_err(HttpError::Io)?;
+    parse_response(&buf, method == "HEAD").ok_or(HttpError::MalformedResponse)
+  }
+}
+
+fn parse_response(buf: &[u8], head_only: bool) -> Option<HttpResponse> {
+  let head_end = buf.windows(4).position(|w| w == b"\r\n\r\n")?;
+  let head = std::str::from_utf8(&buf[..head_end]).ok()?;
+  let mut lines = head.split("\r\n");
+  let status = lines.next()?.split(' ').nth(1)?.parse::<u16>().ok()?;
+
+  let mut content_length = None;
+  let mut chunked = false;
+  for line in lines {
+    let Some((name, value)) = line.split_once(':') else {
+      continue;
+    };
+    let value = value.trim();
+    if name.eq_ignore_ascii_case("content-length") {
+      content_length = value.parse::<usize>().ok();
+    } else if name.eq_ignore_ascii_case("transfer-encoding") {
+      chunked = value.eq_ignore_ascii_case("chunked");
+    }
+  }
+
+  let rest = &buf[head_end + 4..];
+  let body = if head_only {
+    vec![]
+  } else if chunked {
+    decode_chunked(rest)?
+  } else if let Some(length) = content_length {
+    rest.get(..length)?.to_vec()
+  } else {
+    rest.to_vec()
+  };
+  Some(HttpResponse { status, body })
+}
+
+fn decode_chunked(mut buf: &[u8]) -> Option<Vec<u8>> {
+  let mut body = vec![];
+  loop {
+    let line_end = buf.windows(2).position(|w| w == b"\r\n")?;
+    let size_line = std::str::from_utf8(&buf[..line_end]).ok()?;
+    let size = usize::from_str_radix(size_line.split(';').next()?.trim(), 16).ok()?;
+    buf = &buf[line_end + 2..];
+    if size == 0 {
+      return Some(body);
+    }
+    body.extend_from_slice(buf.get(..size)?);
+    buf = buf.get(size + 2..)?;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{HttpEndpoint, decode_chunked, parse_response};
+
+  #[test]
+  fn should_parse_endpoint() {
+    let endpoint =
+      HttpEndpoint::parse("http://127.0.0.1:8080/cache/objects/abc").expect("should parse url");
+    assert_eq!(endpoint.authority, "127.0.0.1:8080");
+    assert_eq!(endpoint.host, "127.0.0.1:8080");
+    assert_eq!(endpoint.path, "/cache/objects/abc");
+
+    let endpoint = HttpEndpoint::parse("http://cache.local").expect("should parse url");
+    assert_eq!(endpoint.authority, "cache.local:80");
+    assert_eq!(endpoint.path, "/");
+
+    let endpoint =
+      HttpEndpoint::parse("http://cache.local/manifest.json#hash").expect("should parse url");
+    assert_eq!(endpoint.path, "/manifest.json");
+
+    assert!(HttpEndpoint::parse("https://cache.local").is_err());
+    assert!(HttpEndpoint::parse("http:///objects").is_err());
+  }
+
+  #[test]
+  fn should_parse_response() {
+    let res = parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 3\r\n\r\nabcdef", false)
+      .expect("should parse response");
+    assert_eq!(res.status, 200);
+    assert_eq!(res.body, b"abc");
+
+    let res = parse_response(
+      b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n2\r\nde\r\n0\r\n\r\n",
+      false,
+    )
+    .expect("should parse response");
+    assert_eq!(res.body, b"abcde");
+
+    let res = parse_response(b"HTTP/1.1 404 Not Found\r\nContent-Length: 9\r\n\r\n", true)
+      .expect("should parse response");
+    assert_eq!(res.status, 404);
+    assert!(res.body.is_empty());
+
+    assert!(parse_response(b"HTTP/1.1 200 OK\r\n", false).is_none());
+    assert!(decode_chunked(b"3\r\nab").is_none());
+  }
+}
@@ -4,11 +4,13 @@ pub mod asset_condition;
 pub mod atom;
 pub mod base64;
 pub mod comparators;
+pub mod css_scanner;
 #[cfg(feature = "debug_tool")]
 pub mod debug_tool;
 pub mod env;
 pub mod ext;
 pub mod fx_hash;
+pub mod http;
 pub mod identifier;
 pub mod itoa;
 pub mod location;
@@ -32,6 +32,11 @@ impl DiskWatcher {
     let inner = RecommendedWatcher::new(
       move |result: notify::Result<Event>| match result {
         Ok(event) => {
+          if event.need_rescan() {
+            trigger.on_overflow();
+            return;
+          }
+
           let paths = &event.paths;
 
           if paths.is_empty() {
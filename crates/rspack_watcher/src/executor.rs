@@ -1,16 +1,26 @@
-use std::sync::{
-  Arc,
-  atomic::{AtomicBool, Ordering},
+use std::{
+  path::PathBuf,
+  sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+  },
+  time::SystemTime,
 };
 
+use rspack_error::error;
 use rspack_util::fx_hash::FxHashSet as HashSet;
-use tokio::sync::{
-  Mutex,
-  mpsc::{self, UnboundedReceiver, UnboundedSender},
+use tokio::{
+  sync::{
+    Mutex,
+    mpsc::{self, UnboundedReceiver, UnboundedSender},
+  },
+  time::{Duration, Instant},
 };
 
 use super::{EventAggregateHandler, EventHandler, FsEventKind};
-use crate::EventBatch;
+use crate::{
+  EventBatch, EventMessage, FsWatcherOptions, journal::JournalWriter, paths::PathManager, scanner,
+};
 
 type ThreadSafetyReceiver<T> = ThreadSafety<UnboundedReceiver<T>>;
 type ThreadSafety<T> = Arc<Mutex<T>>;
@@ -19,11 +29,78 @@ type ThreadSafety<T> = Arc<Mutex<T>>;
 struct FilesData {
   changed: HashSet<String>,
   deleted: HashSet<String>,
+  /// When the last event of the batch arrived.
+  last_event_at: Option<Instant>,
+  /// Whether the events were dropped and the watched paths need a full rescan.
+  overflowed: bool,
 }
 
 impl FilesData {
   fn is_empty(&self) -> bool {
-    self.changed.is_empty() && self.deleted.is_empty()
+    self.changed.is_empty() && self.deleted.is_empty() && !self.overflowed
+  }
+
+  fn len(&self) -> usize {
+    self.changed.len() + self.deleted.len()
+  }
+
+  /// Drop the pending files, they will be found again by the rescan.
+  fn overflow(&mut self) {
+    self.changed.clear();
+    self.deleted.clear();
+    self.overflowed = true;
+  }
+}
+
+/// `CoalescingPolicy` decides how the events are coalesced into a batch,
+/// see [`FsWatcherOptions`] for the meaning of each field.
+#[derive(Debug, Clone, Copy)]
+struct CoalescingPolicy {
+  aggregate_timeout: Duration,
+  quiet_period: Option<Duration>,
+  max_batch_latency: Option<Duration>,
+  replay_paused_events: bool,
+  overflow_threshold: Option<usize>,
+}
+
+impl CoalescingPolicy {
+  fn new(options: &FsWatcherOptions) -> Self {
+    let millis = |ms: u32| Duration::from_millis(u64::from(ms));
+    Self {
+      aggregate_timeout: millis(
+        options
+          .aggregate_timeout
+          .unwrap_or(DEFAULT_AGGREGATE_TIMEOUT),
+      ),
+      quiet_period: options.quiet_period.map(millis),
+      max_batch_latency: options.max_batch_latency.map(millis),
+      replay_paused_events: options.replay_paused_events,
+      overflow_threshold: options.overflow_threshold,
+    }
+  }
+
+  /// Wait until the batch is ready to be handled.
+  async fn wait_for_batch(&self, files: &ThreadSafety<FilesData>) {
+    let Some(quiet_period) = self.quiet_period else {
+      tokio::time::sleep(self.aggregate_timeout).await;
+      return;
+    };
+
+    let started_at = Instant::now();
+    let deadline = self
+      .max_batch_latency
+      .map(|max_batch_latency| started_at + max_batch_latency);
+    loop {
+      let last_event_at = files.lock().await.last_event_at.unwrap_or(started_at);
+      let mut wake_at = last_event_at + quiet_period;
+      if let Some(deadline) = deadline {
+        wake_at = wake_at.min(deadline);
+      }
+      if wake_at <= Instant::now() {
+        return;
+      }
+      tokio::time::sleep_until(wake_at).await;
+    }
   }
 }
 
@@ -32,9 +109,14 @@ impl FilesData {
 /// a configurable aggregate timeout. It receives events from a channel, tracks changed and
 /// deleted files, and coordinates the event handling logic.
 pub struct Executor {
-  aggregate_timeout: u32,
-  rx: ThreadSafetyReceiver<EventBatch>,
+  policy: CoalescingPolicy,
+  rx: ThreadSafetyReceiver<EventMessage>,
   files_data: ThreadSafety<FilesData>,
+  path_manager: Arc<PathManager>,
+  /// The time since which the changes are found by a rescan.
+  rescan_since: ThreadSafety<SystemTime>,
+  /// The file to record the received messages to.
+  journal: Option<PathBuf>,
   exec_aggregate_tx: UnboundedSender<ExecAggregateEvent>,
   exec_aggregate_rx: ThreadSafetyReceiver<ExecAggregateEvent>,
   exec_tx: UnboundedSender<ExecEvent>,
@@ -51,12 +133,15 @@ const DEFAULT_AGGREGATE_TIMEOUT: u32 = 50; // Default timeout in milliseconds
 /// `ExecEvent` represents control events for the watcher executor loop.
 /// - `Execute`: Indicates that an event (change or delete) has occurred and the handler should be triggered.
 /// - `Close`: Indicates that the event receiver has been closed and the executor should stop.
+/// - `Error`: Indicates that an error should be reported to the handler.
 #[derive(Debug)]
 enum ExecAggregateEvent {
   /// Trigger the execution of the event handler (e.g., after a file change or delete).
   Execute,
   /// Signal to close the executor loop (e.g., when the receiver is closed).
   Close,
+  /// Report an error which doesn't stop the watcher, e.g. failing to write the journal.
+  Error(rspack_error::Error),
 }
 
 enum ExecEvent {
@@ -65,8 +150,12 @@ enum ExecEvent {
 }
 
 impl Executor {
-  /// Create a new `WatcherExecutor` with the given receiver and optional aggregate timeout.
-  pub fn new(rx: UnboundedReceiver<EventBatch>, aggregate_timeout: Option<u32>) -> Self {
+  /// Create a new `WatcherExecutor` with the given receiver and the coalescing options.
+  pub fn new(
+    rx: UnboundedReceiver<EventMessage>,
+    options: &FsWatcherOptions,
+    path_manager: Arc<PathManager>,
+  ) -> Self {
     let (exec_aggregate_tx, exec_aggregate_rx) = mpsc::unbounded_channel::<ExecAggregateEvent>();
     let (exec_tx, exec_rx) = mpsc::unbounded_channel::<ExecEvent>();
 
@@ -76,13 +165,16 @@ impl Executor {
       paused: Arc::new(AtomicBool::new(false)),
       rx: Arc::new(Mutex::new(rx)),
       files_data: Default::default(),
+      path_manager,
+      rescan_since: Arc::new(Mutex::new(SystemTime::now())),
+      journal: options.journal.clone(),
       exec_aggregate_tx,
       exec_aggregate_rx: Arc::new(Mutex::new(exec_aggregate_rx)),
       exec_rx: Arc::new(Mutex::new(exec_rx)),
       exec_tx,
       execute_aggregate_handle: None,
       execute_handle: None,
-      aggregate_timeout: aggregate_timeout.unwrap_or(DEFAULT_AGGREGATE_TIMEOUT),
+      policy: CoalescingPolicy::new(options),
     }
   }
 
@@ -119,9 +211,20 @@ impl Executor {
     self.abort().await;
   }
 
+  /// Wait for the executor to handle the remaining events after all the senders are dropped.
+  pub async fn wait_for_finish(&mut self) {
+    if let Some(execute_aggregate_handle) = self.execute_aggregate_handle.take() {
+      let _ = execute_aggregate_handle.await;
+    }
+    if let Some(execute_handle) = self.execute_handle.take() {
+      let _ = execute_handle.await;
+    }
+  }
+
   /// Execute the watcher executor loop.
   pub async fn wait_for_execute(
     &mut self,
+    start_time: SystemTime,
     event_aggregate_handler: Box<dyn EventAggregateHandler + Send>,
     event_handler: Box<dyn EventHandler + Send>,
   ) {
@@ -133,29 +236,58 @@ impl Executor {
       let exec_tx = self.exec_tx.clone();
       let paused = Arc::clone(&self.paused);
       let aggregate_running = Arc::clone(&self.aggregate_running);
+      let overflow_threshold = self.policy.overflow_threshold;
+      let journal = self.journal.clone().map(|path| {
+        let error_tx = self.exec_aggregate_tx.clone();
+        JournalWriter::spawn(path, move |e| {
+          let _ = error_tx.send(ExecAggregateEvent::Error(e));
+        })
+      });
 
       let future = async move {
-        while let Some(events) = rx.lock().await.recv().await {
-          for event in &events {
-            let path = event.path.to_string_lossy().to_string();
-            match event.kind {
-              FsEventKind::Change => {
-                files_data.lock().await.changed.insert(path);
-              }
-              FsEventKind::Remove => {
-                files_data.lock().await.deleted.insert(path);
+        while let Some(message) = rx.lock().await.recv().await {
+          if let Some(journal) = &journal {
+            journal.record(&message);
+          }
+
+          let events = {
+            let mut files_data = files_data.lock().await;
+            files_data.last_event_at = Some(Instant::now());
+            match message {
+              EventMessage::Batch(events) => {
+                // the pending files are dropped after an overflow, the rescan will find them
+                if !files_data.overflowed {
+                  for event in &events {
+                    let path = event.path.to_string_lossy().to_string();
+                    match event.kind {
+                      FsEventKind::Change | FsEventKind::Create => {
+                        files_data.changed.insert(path);
+                      }
+                      FsEventKind::Remove => {
+                        files_data.deleted.insert(path);
+                      }
+                    }
+                  }
+                  if overflow_threshold.is_some_and(|threshold| files_data.len() > threshold) {
+                    files_data.overflow();
+                  }
+                }
+                Some(events)
               }
-              FsEventKind::Create => {
-                files_data.lock().await.changed.insert(path);
+              EventMessage::Overflow => {
+                files_data.overflow();
+                None
               }
             }
-          }
+          };
 
           if !paused.load(Ordering::Relaxed) && !aggregate_running.load(Ordering::Relaxed) {
             let _ = exec_aggregate_tx.send(ExecAggregateEvent::Execute);
           }
 
-          let _ = exec_tx.send(ExecEvent::Execute(events));
+          if let Some(events) = events {
+            let _ = exec_tx.send(ExecEvent::Execute(events));
+          }
         }
 
         let _ = exec_aggregate_tx.send(ExecAggregateEvent::Close);
@@ -167,10 +299,16 @@ impl Executor {
     }
 
     self.paused.store(false, Ordering::Relaxed);
+    *self.rescan_since.lock().await = start_time;
     // abort the previous handlers if they exist
     self.abort().await;
 
     self.run_execute_handler(event_aggregate_handler, event_handler);
+
+    // the events received while paused are replayed as one merged batch
+    if self.policy.replay_paused_events && !self.files_data.lock().await.is_empty() {
+      let _ = self.exec_aggregate_tx.send(ExecAggregateEvent::Execute);
+    }
   }
 
   fn run_execute_handler(
@@ -182,7 +320,9 @@ impl Executor {
       event_aggregate_handler,
       Arc::clone(&self.exec_aggregate_rx),
       Arc::clone(&self.files_data),
-      self.aggregate_timeout as u64,
+      self.policy,
+      Arc::clone(&self.path_manager),
+      Arc::clone(&self.rescan_since),
       Arc::clone(&self.aggregate_running),
     ));
 
@@ -231,7 +371,9 @@ fn create_execute_aggregate_task(
   event_handler: Box<dyn EventAggregateHandler + Send>,
   exec_aggregate_rx: ThreadSafetyReceiver<ExecAggregateEvent>,
   files: ThreadSafety<FilesData>,
-  aggregate_timeout: u64,
+  policy: CoalescingPolicy,
+  path_manager: Arc<PathManager>,
+  rescan_since: ThreadSafety<SystemTime>,
   running: Arc<AtomicBool>,
 ) -> tokio::task::JoinHandle<()> {
   let future = async move {
@@ -246,27 +388,218 @@ fn create_execute_aggregate_task(
         }
       };
 
-      if let ExecAggregateEvent::Execute = aggregate_rx {
-        running.store(true, Ordering::Relaxed);
-        // Wait for the aggregate timeout before executing the handler
-        tokio::time::sleep(tokio::time::Duration::from_millis(aggregate_timeout)).await;
-
-        // Get the files to process
-        let files = {
-          let mut files = files.lock().await;
-          if files.is_empty() {
-            running.store(false, Ordering::Relaxed);
-            continue;
+      let aggregate_rx = match aggregate_rx {
+        ExecAggregateEvent::Error(e) => {
+          event_handler.on_error(e);
+          continue;
+        }
+        event => event,
+      };
+
+      // the remaining files are handled right away before closing
+      let closed = matches!(aggregate_rx, ExecAggregateEvent::Close);
+      running.store(true, Ordering::Relaxed);
+      if !closed {
+        // Wait for the batch to be coalesced before executing the handler
+        policy.wait_for_batch(&files).await;
+      }
+
+      // Get the files to process
+      let mut files = {
+        let mut files = files.lock().await;
+        if files.is_empty() {
+          running.store(false, Ordering::Relaxed);
+          if closed {
+            return;
+          }
+          continue;
+        }
+        std::mem::take(&mut *files)
+      };
+
+      let handled_at = SystemTime::now();
+      if files.overflowed {
+        event_handler.on_overflow();
+        let since = *rescan_since.lock().await;
+        let path_manager = Arc::clone(&path_manager);
+        // the rescan reads the metadata of all the watched paths
+        match tokio::task::spawn_blocking(move || scanner::rescan(&path_manager, since)).await {
+          Ok(events) => {
+            for event in events {
+              let path = event.path.to_string_lossy().to_string();
+              match event.kind {
+                FsEventKind::Change | FsEventKind::Create => files.changed.insert(path),
+                FsEventKind::Remove => files.deleted.insert(path),
+              };
+            }
           }
-          std::mem::take(&mut *files)
-        };
+          Err(e) => event_handler.on_error(error!("{e}, failed to rescan the watched paths")),
+        }
+      }
 
-        // Call the event handler with the changed and deleted files
-        event_handler.on_event_handle(files.changed, files.deleted);
-        running.store(false, Ordering::Relaxed);
+      // Call the event handler with the changed and deleted files
+      event_handler.on_event_handle(files.changed, files.deleted);
+      *rescan_since.lock().await = handled_at;
+      running.store(false, Ordering::Relaxed);
+      if closed {
+        return;
       }
     }
   };
 
   tokio::spawn(future)
 }
+
+#[cfg(test)]
+mod tests {
+  use std::{
+    path::Path,
+    sync::{Mutex as StdMutex, atomic::AtomicUsize},
+  };
+
+  use rspack_paths::ArcPath;
+
+  use super::*;
+  use crate::FsEvent;
+
+  #[derive(Default, Clone)]
+  struct Collector {
+    batches: Arc<StdMutex<Vec<(HashSet<String>, HashSet<String>)>>>,
+    overflows: Arc<AtomicUsize>,
+  }
+
+  impl EventAggregateHandler for Collector {
+    fn on_event_handle(&self, changed_files: HashSet<String>, deleted_files: HashSet<String>) {
+      self
+        .batches
+        .lock()
+        .expect("should lock batches")
+        .push((changed_files, deleted_files));
+    }
+
+    fn on_overflow(&self) {
+      self.overflows.fetch_add(1, Ordering::SeqCst);
+    }
+  }
+
+  struct NoopHandler;
+
+  impl EventHandler for NoopHandler {}
+
+  fn change(path: &str) -> FsEvent {
+    FsEvent {
+      path: ArcPath::from(Path::new(path)),
+      kind: FsEventKind::Change,
+    }
+  }
+
+  async fn start(
+    options: FsWatcherOptions,
+  ) -> (Executor, Collector, UnboundedSender<EventMessage>) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let mut executor = Executor::new(rx, &options, Arc::new(PathManager::default()));
+    let collector = Collector::default();
+    executor
+      .wait_for_execute(
+        SystemTime::now(),
+        Box::new(collector.clone()),
+        Box::new(NoopHandler),
+      )
+      .await;
+    (executor, collector, tx)
+  }
+
+  /// Wait on the paused clock, it only auto-advances after the woken tasks
+  /// have run, so the tests don't depend on the real timing.
+  async fn elapse(millis: u64) {
+    tokio::time::sleep(Duration::from_millis(millis)).await;
+  }
+
+  fn batches(collector: &Collector) -> Vec<(HashSet<String>, HashSet<String>)> {
+    collector
+      .batches
+      .lock()
+      .expect("should lock batches")
+      .clone()
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_quiet_period_coalesces_events() {
+    let (mut executor, collector, tx) = start(FsWatcherOptions {
+      quiet_period: Some(100),
+      ..Default::default()
+    })
+    .await;
+
+    for i in 0..5 {
+      tx.send(EventMessage::Batch(vec![change(&format!("/file_{i}"))]))
+        .unwrap();
+      elapse(20).await;
+    }
+    // the quiet period restarts on every event
+    elapse(70).await;
+    assert!(batches(&collector).is_empty());
+    elapse(20).await;
+
+    let batches = batches(&collector);
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].0.len(), 5);
+    executor.close().await;
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_max_batch_latency() {
+    let (mut executor, collector, tx) = start(FsWatcherOptions {
+      quiet_period: Some(100),
+      max_batch_latency: Some(150),
+      ..Default::default()
+    })
+    .await;
+
+    for i in 0..10 {
+      tx.send(EventMessage::Batch(vec![change(&format!("/file_{i}"))]))
+        .unwrap();
+      elapse(40).await;
+    }
+    elapse(300).await;
+
+    // the events keep coming within the quiet period, so the batches are
+    // handled at 150ms, 310ms and 460ms
+    let batches = batches(&collector);
+    assert_eq!(
+      batches
+        .iter()
+        .map(|batch| batch.0.len())
+        .collect::<Vec<_>>(),
+      vec![4, 4, 2]
+    );
+    executor.close().await;
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_overflow_threshold() {
+    let (mut executor, collector, tx) = start(FsWatcherOptions {
+      aggregate_timeout: Some(50),
+      overflow_threshold: Some(2),
+      ..Default::default()
+    })
+    .await;
+
+    tx.send(EventMessage::Batch(vec![
+      change("/file_0"),
+      change("/file_1"),
+      change("/file_2"),
+    ]))
+    .unwrap();
+    elapse(40).await;
+    assert!(batches(&collector).is_empty());
+    elapse(20).await;
+
+    // the pending files are dropped, and the rescan finds nothing in an empty path manager
+    let batches = batches(&collector);
+    assert_eq!(collector.overflows.load(Ordering::SeqCst), 1);
+    assert_eq!(batches.len(), 1);
+    assert!(batches[0].0.is_empty());
+    executor.close().await;
+  }
+}
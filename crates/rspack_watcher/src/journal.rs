@@ -0,0 +1,399 @@
+use std::{
+  fs::File,
+  io::{BufRead, BufReader, BufWriter, Write},
+  path::{Path, PathBuf},
+  sync::Arc,
+  time::SystemTime,
+};
+
+use rspack_error::{Result, ToStringResultToRspackResultExt, error};
+use rspack_paths::ArcPath;
+use serde::{Deserialize, Serialize};
+use tokio::{
+  sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+  task::spawn_blocking,
+  time::{Duration, Instant},
+};
+
+use crate::{
+  EventAggregateHandler, EventHandler, EventMessage, FsEvent, FsEventKind, FsWatcherOptions,
+  executor::Executor, paths::PathManager,
+};
+
+/// `JournalEvent` is a file system event in the journal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEvent {
+  pub path: String,
+  pub kind: FsEventKind,
+}
+
+/// `JournalEntry` is a line of the journal, it's either a batch of events or an overflow.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntry {
+  /// The milliseconds since the recording started.
+  pub time: u64,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub events: Vec<JournalEvent>,
+  #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+  pub overflow: bool,
+}
+
+impl JournalEntry {
+  fn new(time: u64, message: &EventMessage) -> Self {
+    match message {
+      EventMessage::Batch(events) => Self {
+        time,
+        events: events
+          .iter()
+          .map(|event| JournalEvent {
+            path: event.path.to_string_lossy().to_string(),
+            kind: event.kind,
+          })
+          .collect(),
+        overflow: false,
+      },
+      EventMessage::Overflow => Self {
+        time,
+        events: vec![],
+        overflow: true,
+      },
+    }
+  }
+
+  fn into_message(self) -> EventMessage {
+    if self.overflow {
+      return EventMessage::Overflow;
+    }
+    EventMessage::Batch(
+      self
+        .events
+        .into_iter()
+        .map(|event| FsEvent {
+          path: ArcPath::from(Path::new(&event.path)),
+          kind: event.kind,
+        })
+        .collect(),
+    )
+  }
+}
+
+/// `JournalWriter` appends the messages received by the executor to the journal file,
+/// one JSON entry per line.
+///
+/// The file is written in a background task, so recording never blocks the executor.
+/// The first IO error is passed to `on_error` and stops the recording.
+pub(crate) struct JournalWriter {
+  started_at: Instant,
+  tx: UnboundedSender<JournalEntry>,
+}
+
+impl JournalWriter {
+  pub fn spawn(path: PathBuf, on_error: impl FnOnce(rspack_error::Error) + Send + 'static) -> Self {
+    let (tx, mut rx) = mpsc::unbounded_channel::<JournalEntry>();
+    tokio::spawn(async move {
+      if let Err(e) = write_journal(path, &mut rx).await {
+        on_error(e);
+      }
+    });
+    Self {
+      started_at: Instant::now(),
+      tx,
+    }
+  }
+
+  pub fn record(&self, message: &EventMessage) {
+    let time = self.started_at.elapsed().as_millis() as u64;
+    // the writer is stopped after an error, which is reported already
+    let _ = self.tx.send(JournalEntry::new(time, message));
+  }
+}
+
+async fn write_journal(path: PathBuf, rx: &mut UnboundedReceiver<JournalEntry>) -> Result<()> {
+  let mut writer = spawn_blocking(move || create_journal(&path))
+    .await
+    .to_rspack_result()??;
+  while let Some(entry) = rx.recv().await {
+    writer = spawn_blocking(move || {
+      write_journal_entry(&mut writer, &entry)?;
+      Ok::<_, rspack_error::Error>(writer)
+    })
+    .await
+    .to_rspack_result()??;
+  }
+  Ok(())
+}
+
+fn create_journal(path: &Path) -> Result<BufWriter<File>> {
+  if let Some(dir) = path.parent() {
+    std::fs::create_dir_all(dir)
+      .map_err(|e| error!("Failed to create watcher journal {}: {e}", path.display()))?;
+  }
+  let file = File::create(path)
+    .map_err(|e| error!("Failed to create watcher journal {}: {e}", path.display()))?;
+  Ok(BufWriter::new(file))
+}
+
+fn write_journal_entry(writer: &mut BufWriter<File>, entry: &JournalEntry) -> Result<()> {
+  serde_json::to_writer(&mut *writer, entry)
+    .map_err(|e| error!("Failed to write watcher journal: {e}"))?;
+  // flush every entry, so the journal is complete even if the process crashes
+  writer
+    .write_all(b"\n")
+    .and_then(|_| writer.flush())
+    .map_err(|e| error!("Failed to write watcher journal: {e}"))
+}
+
+/// Reads the entries of a journal recorded with [`FsWatcherOptions::journal`].
+pub fn read_journal(path: &Path) -> Result<Vec<JournalEntry>> {
+  let file = File::open(path)
+    .map_err(|e| error!("Failed to open watcher journal {}: {e}", path.display()))?;
+  let mut entries = vec![];
+  for (index, line) in BufReader::new(file).lines().enumerate() {
+    let line = line.to_rspack_result()?;
+    if line.trim().is_empty() {
+      continue;
+    }
+    let entry = serde_json::from_str(&line).map_err(|e| {
+      error!(
+        "Failed to parse watcher journal {} at line {}: {e}",
+        path.display(),
+        index + 1
+      )
+    })?;
+    entries.push(entry);
+  }
+  Ok(entries)
+}
+
+/// Feeds the entries of a journal through an [`Executor`] into the handlers, keeping the
+/// recorded intervals between them, so the events are coalesced the same way as recorded.
+///
+/// Nothing is watched or scanned on the disk, an overflow is replayed with an empty rescan.
+pub async fn replay_journal(
+  path: &Path,
+  options: FsWatcherOptions,
+  event_aggregate_handler: Box<dyn EventAggregateHandler + Send>,
+  event_handler: Box<dyn EventHandler + Send>,
+) -> Result<()> {
+  let entries = {
+    let path = path.to_path_buf();
+    spawn_blocking(move || read_journal(&path))
+      .await
+      .to_rspack_result()??
+  };
+  let options = FsWatcherOptions {
+    journal: None,
+    ..options
+  };
+
+  let (tx, rx) = mpsc::unbounded_channel();
+  let mut executor = Executor::new(rx, &options, Arc::new(PathManager::default()));
+  executor
+    .wait_for_execute(SystemTime::now(), event_aggregate_handler, event_handler)
+    .await;
+
+  let started_at = Instant::now();
+  for entry in entries {
+    tokio::time::sleep_until(started_at + Duration::from_millis(entry.time)).await;
+    let _ = tx.send(entry.into_message());
+  }
+  // closing the channel lets the executor handle the last batch and stop
+  drop(tx);
+  executor.wait_for_finish().await;
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Mutex;
+
+  use rspack_util::fx_hash::FxHashSet as HashSet;
+
+  use super::*;
+
+  type Batches = Arc<Mutex<Vec<(HashSet<String>, HashSet<String>)>>>;
+
+  #[derive(Default, Clone)]
+  struct Collector {
+    batches: Batches,
+    errors: Arc<Mutex<Vec<String>>>,
+  }
+
+  impl EventAggregateHandler for Collector {
+    fn on_event_handle(&self, changed_files: HashSet<String>, deleted_files: HashSet<String>) {
+      self
+        .batches
+        .lock()
+        .expect("should lock batches")
+        .push((changed_files, deleted_files));
+    }
+
+    fn on_error(&self, error: rspack_error::Error) {
+      self
+        .errors
+        .lock()
+        .expect("should lock errors")
+        .push(error.to_string());
+    }
+  }
+
+  struct NoopHandler;
+
+  impl EventHandler for NoopHandler {}
+
+  fn batch(events: &[(&str, FsEventKind)]) -> EventMessage {
+    EventMessage::Batch(
+      events
+        .iter()
+        .map(|(path, kind)| FsEvent {
+          path: ArcPath::from(Path::new(path)),
+          kind: *kind,
+        })
+        .collect(),
+    )
+  }
+
+  /// Wait until the writer task has written the entries or reported the error, and stopped.
+  async fn finish(writer: JournalWriter) {
+    let tx = writer.tx.clone();
+    drop(writer);
+    tx.closed().await;
+  }
+
+  #[tokio::test]
+  async fn test_record_and_read_journal() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("journal/journal.jsonl");
+
+    let errors = Arc::new(Mutex::new(vec![]));
+    let writer = JournalWriter::spawn(path.clone(), {
+      let errors = errors.clone();
+      move |e| errors.lock().unwrap().push(e.to_string())
+    });
+    writer.record(&batch(&[
+      ("/a.js", FsEventKind::Change),
+      ("/b.js", FsEventKind::Remove),
+    ]));
+    writer.record(&EventMessage::Overflow);
+    finish(writer).await;
+
+    assert!(errors.lock().unwrap().is_empty());
+    let entries = read_journal(&path).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(
+      entries[0].events,
+      vec![
+        JournalEvent {
+          path: "/a.js".to_string(),
+          kind: FsEventKind::Change,
+        },
+        JournalEvent {
+          path: "/b.js".to_string(),
+          kind: FsEventKind::Remove,
+        },
+      ]
+    );
+    assert!(!entries[0].overflow);
+    assert!(entries[1].events.is_empty());
+    assert!(entries[1].overflow);
+    assert!(entries[0].time <= entries[1].time);
+  }
+
+  #[tokio::test]
+  async fn test_journal_error() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    // a directory can't be created as the journal file
+    let path = temp_dir.path().to_path_buf();
+
+    let errors = Arc::new(Mutex::new(vec![]));
+    let writer = JournalWriter::spawn(path.clone(), {
+      let errors = errors.clone();
+      move |e| errors.lock().unwrap().push(e.to_string())
+    });
+    writer.record(&batch(&[("/a.js", FsEventKind::Change)]));
+    finish(writer).await;
+
+    let errors = errors.lock().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("Failed to create watcher journal"));
+  }
+
+  #[tokio::test]
+  async fn test_journal_error_reported_by_watcher() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().to_path_buf();
+
+    let (tx, rx) = mpsc::unbounded_channel();
+    let mut executor = Executor::new(
+      rx,
+      &FsWatcherOptions {
+        journal: Some(path),
+        ..Default::default()
+      },
+      Arc::new(PathManager::default()),
+    );
+    let collector = Collector::default();
+    executor
+      .wait_for_execute(
+        SystemTime::now(),
+        Box::new(collector.clone()),
+        Box::new(NoopHandler),
+      )
+      .await;
+    // the journal is created in the background
+    tokio::time::timeout(Duration::from_secs(10), async {
+      while collector.errors.lock().unwrap().is_empty() {
+        tokio::time::sleep(Duration::from_millis(1)).await;
+      }
+    })
+    .await
+    .expect("should report the journal error");
+
+    // the watcher keeps working after the error
+    tx.send(batch(&[("/a.js", FsEventKind::Change)])).unwrap();
+    drop(tx);
+    executor.wait_for_finish().await;
+    let errors = collector.errors.lock().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("Failed to create watcher journal"));
+    assert_eq!(collector.batches.lock().unwrap().len(), 1);
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_replay_journal() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("journal.jsonl");
+    std::fs::write(
+      &path,
+      [
+        r#"{"time":0,"events":[{"path":"/a.js","kind":"change"}]}"#,
+        r#"{"time":10,"events":[{"path":"/b.js","kind":"create"}]}"#,
+        r#"{"time":300,"events":[{"path":"/a.js","kind":"remove"}]}"#,
+      ]
+      .join("\n"),
+    )
+    .unwrap();
+
+    // the recorded intervals elapse on the paused clock
+    let collector = Collector::default();
+    replay_journal(
+      &path,
+      FsWatcherOptions {
+        aggregate_timeout: Some(100),
+        ..Default::default()
+      },
+      Box::new(collector.clone()),
+      Box::new(NoopHandler),
+    )
+    .await
+    .unwrap();
+
+    let batches = collector.batches.lock().unwrap();
+    assert_eq!(batches.len(), 2);
+    assert_eq!(batches[0].0.len(), 2);
+    assert!(batches[0].1.is_empty());
+    assert!(batches[1].0.is_empty());
+    assert!(batches[1].1.contains("/a.js"));
+  }
+}
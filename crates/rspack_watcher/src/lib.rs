@@ -2,21 +2,24 @@ mod analyzer;
 mod disk_watcher;
 mod executor;
 mod ignored;
+mod journal;
 mod paths;
 mod scanner;
 mod trigger;
 
-use std::{sync::Arc, time::SystemTime};
+use std::{path::PathBuf, sync::Arc, time::SystemTime};
 
 use analyzer::{Analyzer, RecommendedAnalyzer};
 use disk_watcher::DiskWatcher;
 use executor::Executor;
 pub use ignored::FsWatcherIgnored;
+pub use journal::{JournalEntry, JournalEvent, read_journal, replay_journal};
 use paths::PathManager;
 use rspack_error::Result;
 use rspack_paths::ArcPath;
 use rspack_util::fx_hash::FxHashSet as HashSet;
 use scanner::Scanner;
+use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 use trigger::Trigger;
 
@@ -26,7 +29,8 @@ pub(crate) struct WatchPattern {
   mode: notify::RecursiveMode,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum FsEventKind {
   Change,
   Remove,
@@ -41,6 +45,16 @@ pub(crate) struct FsEvent {
 
 pub(crate) type EventBatch = Vec<FsEvent>;
 
+/// `EventMessage` is what the trigger and the scanner send to the executor.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum EventMessage {
+  /// A batch of file system events.
+  Batch(EventBatch),
+  /// Some events were lost, e.g. the event queue of the OS overflowed,
+  /// so all the watched paths need to be rescanned.
+  Overflow,
+}
+
 /// `EventAggregateHandler` is a trait for handling aggregated file system events.
 /// It provides methods to handle changes and deletions of files, as well as errors.
 /// Implementors of this trait can define custom behavior for these events.
@@ -51,6 +65,12 @@ pub trait EventAggregateHandler {
   /// Handle a batch of file system events.
   fn on_event_handle(&self, _changed_files: HashSet<String>, _deleted_files: HashSet<String>);
 
+  /// Handle an overflow, which means some events were lost and the next batch
+  /// comes from a full rescan of the watched paths.
+  fn on_overflow(&self) {
+    // Default implementation does nothing.
+  }
+
   /// Handle an error that occurs during file system watching.
   fn on_error(&self, _error: rspack_error::Error) {
     // Default implementation does nothing.
@@ -82,6 +102,26 @@ pub struct FsWatcherOptions {
 
   /// The timeout in milliseconds to aggregate events.
   pub aggregate_timeout: Option<u32>,
+
+  /// Wait until no event arrives for this long in milliseconds before handling a batch,
+  /// the wait restarts on every event. `aggregate_timeout` is used as a fixed delay
+  /// after the first event when it's `None`.
+  pub quiet_period: Option<u32>,
+
+  /// The longest time in milliseconds a batch can be delayed by `quiet_period`.
+  pub max_batch_latency: Option<u32>,
+
+  /// Replay the events received while the watcher is paused, e.g. during a rebuild,
+  /// as one merged batch when it resumes.
+  pub replay_paused_events: bool,
+
+  /// The number of pending files above which the pending events are dropped, and all
+  /// the watched paths are rescanned instead when the batch is handled.
+  pub overflow_threshold: Option<usize>,
+
+  /// Record every event batch received by the watcher, with the time it arrived,
+  /// to this file. The journal can be fed back with [`replay_journal`].
+  pub journal: Option<PathBuf>,
 }
 
 pub struct FsWatcher {
@@ -105,7 +145,7 @@ impl FsWatcher {
       options.poll_interval,
       trigger.clone(),
     );
-    let executor = Executor::new(rx, options.aggregate_timeout);
+    let executor = Executor::new(rx, &options, Arc::clone(&path_manager));
     let scanner = Scanner::new(tx, Arc::clone(&path_manager));
 
     Self {
@@ -144,7 +184,7 @@ impl FsWatcher {
 
     self
       .executor
-      .wait_for_execute(event_aggregate_handler, event_handler)
+      .wait_for_execute(start_time, event_aggregate_handler, event_handler)
       .await;
   }
 
@@ -4,17 +4,17 @@ use rspack_paths::{ArcPath, ArcPathDashSet};
 use tokio::sync::mpsc::UnboundedSender;
 
 use super::{FsEvent, FsEventKind, PathManager};
-use crate::EventBatch;
+use crate::{EventBatch, EventMessage};
 
 // Scanner will scann the path whether it is exist or not in disk on initialization
 pub struct Scanner {
   path_manager: Arc<PathManager>,
-  tx: Option<UnboundedSender<EventBatch>>,
+  tx: Option<UnboundedSender<EventMessage>>,
 }
 
 impl Scanner {
   /// Creates a new `Scanner` that will send events to the provided sender when paths are scanned.
-  pub fn new(tx: UnboundedSender<EventBatch>, path_manager: Arc<PathManager>) -> Self {
+  pub fn new(tx: UnboundedSender<EventMessage>, path_manager: Arc<PathManager>) -> Self {
     Self {
       path_manager,
       tx: Some(tx),
@@ -61,12 +61,36 @@ impl Scanner {
   }
 }
 
-fn scan_path_missing(
-  paths: &[ArcPath],
-  missing: &ArcPathDashSet,
-  tx: &UnboundedSender<EventBatch>,
-) -> bool {
-  let remove_event = paths
+/// Scans all the watched paths rather than the added ones, it's used after an overflow
+/// when the watcher can't tell which paths changed since `since`.
+pub fn rescan(path_manager: &PathManager, since: SystemTime) -> EventBatch {
+  let accessor = path_manager.access();
+  let paths = accessor
+    .files()
+    .0
+    .iter()
+    .chain(accessor.directories().0.iter())
+    .map(|path| path.deref().clone())
+    .collect::<Vec<_>>();
+  let missing = accessor.missing().0;
+
+  let mut events = collect_path_missing(&paths, missing);
+  events.extend(collect_path_changed(&paths, &since));
+  // the missing paths which exist now are created during the lost events
+  events.extend(
+    missing
+      .iter()
+      .filter(|path| path.exists())
+      .map(|path| FsEvent {
+        path: path.deref().clone(),
+        kind: FsEventKind::Create,
+      }),
+  );
+  events
+}
+
+fn collect_path_missing(paths: &[ArcPath], missing: &ArcPathDashSet) -> EventBatch {
+  paths
     .iter()
     .filter(|path| !path.exists() && !missing.contains(*path))
     .cloned()
@@ -74,19 +98,11 @@ fn scan_path_missing(
       path,
       kind: FsEventKind::Remove,
     })
-    .collect::<Vec<_>>();
-  if remove_event.is_empty() {
-    return true;
-  }
-  tx.send(remove_event).is_ok()
+    .collect()
 }
 
-fn scan_path_changed(
-  paths: &[ArcPath],
-  start_time: &SystemTime,
-  tx: &UnboundedSender<EventBatch>,
-) -> bool {
-  let changed_event = paths
+fn collect_path_changed(paths: &[ArcPath], start_time: &SystemTime) -> EventBatch {
+  paths
     .iter()
     .filter(|path| check_path_metadata(path, start_time))
     .cloned()
@@ -94,12 +110,31 @@ fn scan_path_changed(
       path,
       kind: FsEventKind::Change,
     })
-    .collect::<Vec<_>>();
+    .collect()
+}
 
+fn scan_path_missing(
+  paths: &[ArcPath],
+  missing: &ArcPathDashSet,
+  tx: &UnboundedSender<EventMessage>,
+) -> bool {
+  let remove_event = collect_path_missing(paths, missing);
+  if remove_event.is_empty() {
+    return true;
+  }
+  tx.send(EventMessage::Batch(remove_event)).is_ok()
+}
+
+fn scan_path_changed(
+  paths: &[ArcPath],
+  start_time: &SystemTime,
+  tx: &UnboundedSender<EventMessage>,
+) -> bool {
+  let changed_event = collect_path_changed(paths, start_time);
   if changed_event.is_empty() {
     return true;
   }
-  tx.send(changed_event).is_ok()
+  tx.send(EventMessage::Batch(changed_event)).is_ok()
 }
 
 fn check_path_metadata(filepath: &ArcPath, start_time: &SystemTime) -> bool {
@@ -159,13 +194,60 @@ mod tests {
     println!("Collected events: {:?}", collected_events);
     assert_eq!(collected_events.len(), 2);
 
-    assert!(collected_events.contains(&vec![FsEvent {
-      path: ArcPath::from(current_dir.join("___test_file.txt")),
-      kind: FsEventKind::Remove
-    }]));
-    assert!(collected_events.contains(&vec![FsEvent {
-      path: ArcPath::from(current_dir.join("___test_dir/a/b/c")),
+    assert!(
+      collected_events.contains(&EventMessage::Batch(vec![FsEvent {
+        path: ArcPath::from(current_dir.join("___test_file.txt")),
+        kind: FsEventKind::Remove
+      }]))
+    );
+    assert!(
+      collected_events.contains(&EventMessage::Batch(vec![FsEvent {
+        path: ArcPath::from(current_dir.join("___test_dir/a/b/c")),
+        kind: FsEventKind::Remove,
+      }]))
+    );
+  }
+
+  #[test]
+  fn test_rescan() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let since = SystemTime::now() - std::time::Duration::from_secs(10);
+
+    let changed_file = temp_dir.path().join("changed.txt");
+    let created_file = temp_dir.path().join("created.txt");
+    let removed_file = temp_dir.path().join("removed.txt");
+    std::fs::write(&changed_file, "changed").expect("Failed to write file");
+    std::fs::write(&created_file, "created").expect("Failed to write file");
+
+    let path_manager = PathManager::default();
+    path_manager
+      .update(
+        (
+          vec![changed_file.clone().into(), removed_file.clone().into()].into_iter(),
+          vec![].into_iter(),
+        ),
+        (vec![].into_iter(), vec![].into_iter()),
+        (
+          vec![created_file.clone().into()].into_iter(),
+          vec![].into_iter(),
+        ),
+      )
+      .unwrap();
+
+    let events = rescan(&path_manager, since);
+
+    assert_eq!(events.len(), 3);
+    assert!(events.contains(&FsEvent {
+      path: ArcPath::from(changed_file),
+      kind: FsEventKind::Change,
+    }));
+    assert!(events.contains(&FsEvent {
+      path: ArcPath::from(removed_file),
       kind: FsEventKind::Remove,
-    }]));
+    }));
+    assert!(events.contains(&FsEvent {
+      path: ArcPath::from(created_file),
+      kind: FsEventKind::Create,
+    }));
   }
 }
@@ -4,7 +4,7 @@ use rspack_paths::{ArcPath, ArcPathDashSet};
 use tokio::sync::mpsc::UnboundedSender;
 
 use super::{FsEvent, FsEventKind};
-use crate::{EventBatch, paths::PathManager};
+use crate::{EventMessage, paths::PathManager};
 /// `DependencyFinder` provides references to sets of files, directories, and missing paths,
 /// allowing efficient lookup and dependency resolution for a given path.
 ///
@@ -90,12 +90,12 @@ pub struct Trigger {
   /// Shared reference to the path register, which tracks watched files/directories/missing.
   path_manager: Arc<PathManager>,
   /// Sender for communicating file system events to the watcher executor.
-  tx: UnboundedSender<EventBatch>,
+  tx: UnboundedSender<EventMessage>,
 }
 
 impl Trigger {
   /// Create a new `Trigger` with the given path register and event sender.
-  pub fn new(path_manager: Arc<PathManager>, tx: UnboundedSender<EventBatch>) -> Self {
+  pub fn new(path_manager: Arc<PathManager>, tx: UnboundedSender<EventMessage>) -> Self {
     Self { path_manager, tx }
   }
 
@@ -118,6 +118,11 @@ impl Trigger {
     self.trigger_events(associated_event);
   }
 
+  /// Called when some events were lost, the executor will rescan all the watched paths.
+  pub fn on_overflow(&self) {
+    let _ = self.tx.send(EventMessage::Overflow);
+  }
+
   /// Helper to construct a `DependencyFinder` for the current path register state.
   fn finder(&self) -> DependencyFinder<'_> {
     let accessor = self.path_manager.access();
@@ -138,12 +143,12 @@ impl Trigger {
   fn trigger_events(&self, events: Vec<(ArcPath, FsEventKind)>) -> bool {
     self
       .tx
-      .send(
+      .send(EventMessage::Batch(
         events
           .into_iter()
           .map(|(path, kind)| FsEvent { path, kind })
           .collect(),
-      )
+      ))
       .is_ok()
   }
 }
@@ -184,6 +184,7 @@ export interface KnownNormalizedStatsOptions {
 	errors: boolean;
 	errorsCount: boolean;
 	hash: boolean;
+	incremental: boolean;
 	modules: boolean;
 	warnings: boolean;
 	warningsCount: boolean;
@@ -1,5 +1,6 @@
 import binding from "@rspack/binding";
 import type Watchpack from "watchpack";
+import type { WatchOptions } from "./config";
 import type {
 	FileSystemInfoEntry,
 	InputFileSystem,
@@ -172,10 +173,21 @@ export default class NativeWatchFileSystem implements WatchFileSystem {
 			return this.#inner;
 		}
 
+		// the options only supported by the native watcher are not typed by watchpack
+		const {
+			quietPeriod,
+			maxBatchLatency,
+			replayPausedEvents,
+			overflowThreshold
+		} = options as WatchOptions;
 		const nativeWatcherOptions: binding.NativeWatcherOptions = {
 			followSymlinks: options.followSymlinks,
 			aggregateTimeout: options.aggregateTimeout,
 			pollInterval: typeof options.poll === "boolean" ? 0 : options.poll,
+			quietPeriod,
+			maxBatchLatency,
+			replayPausedEvents,
+			overflowThreshold,
 			ignored: toJsWatcherIgnored(options.ignored)
 		};
 		const nativeWatcher = new binding.NativeWatcher(nativeWatcherOptions);
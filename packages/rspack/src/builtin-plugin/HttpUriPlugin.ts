@@ -1,4 +1,5 @@
 import type { IncomingMessage } from "node:http";
+import type { Socket } from "node:net";
 import path from "node:path";
 import {
 	type BuiltinPlugin,
@@ -10,6 +11,24 @@ import { memoize } from "../util/memoize";
 import { createBuiltinPlugin, RspackBuiltinPlugin } from "./base";
 export type HttpUriPluginOptionsAllowedUris = (string | RegExp)[];
 
+export type HttpUriPluginOptionsRetry = {
+	/**
+	 * The max number of retries
+	 * @default 0
+	 */
+	retries?: number;
+	/**
+	 * The delay in milliseconds before the first retry, doubled for each of the next retries
+	 * @default 500
+	 */
+	delay?: number;
+	/**
+	 * The upper bound of the delay in milliseconds
+	 * @default 10000
+	 */
+	maxDelay?: number;
+};
+
 export type HttpUriPluginOptions = {
 	/**
 	 * A list of allowed URIs
@@ -27,14 +46,37 @@ export type HttpUriPluginOptions = {
 	 * Detect changes to remote resources and upgrade them automatically
 	 */
 	upgrade?: boolean;
-	// /**
-	//  * Specify the proxy server to use for fetching remote resources
-	//  */
-	// proxy?: string;
-	// /**
-	//  * Freeze the remote resources and lockfile. Any modification to the lockfile or resource contents will result in an error
-	//  */
-	// frozen?: boolean;
+	/**
+	 * Specify the proxy server to use for fetching remote resources.
+	 * Defaults to the `HTTP_PROXY` environment variable for `http:` urls,
+	 * and the `HTTPS_PROXY` environment variable for `https:` urls
+	 */
+	proxy?: string;
+	/**
+	 * Hosts to fetch without the proxy, separated by commas.
+	 * Defaults to the `NO_PROXY` environment variable
+	 */
+	noProxy?: string;
+	/**
+	 * Extra request headers of each origin, e.g. the credentials of a private registry
+	 */
+	headers?: Record<string, Record<string, string>>;
+	/**
+	 * Retry the requests that fail with a network error, a `429` or a `5xx` status
+	 */
+	retry?: HttpUriPluginOptionsRetry;
+	/**
+	 * Override the trusted CA certificates of the default http client
+	 */
+	ca?: string | Buffer | (string | Buffer)[];
+	/**
+	 * Freeze the remote resources and lockfile. Any modification to the lockfile or resource contents will result in an error
+	 */
+	frozen?: boolean;
+	/**
+	 * Serve the remote resources only from the cache. Any resource that requires the network will result in an error
+	 */
+	offline?: boolean;
 	/**
 	 * Custom http client
 	 */
@@ -44,13 +86,85 @@ export type HttpUriPluginOptions = {
 const getHttp = memoize(() => require("node:http"));
 const getHttps = memoize(() => require("node:https"));
 
-function compatibleFetch(
+function getProxyHeaders(proxyURL: URL): Record<string, string> {
+	if (!proxyURL.username) {
+		return {};
+	}
+	const credentials = `${decodeURIComponent(proxyURL.username)}:${decodeURIComponent(proxyURL.password)}`;
+	return {
+		"proxy-authorization": `Basic ${Buffer.from(credentials).toString("base64")}`
+	};
+}
+
+/**
+ * Open a tunnel to the target through the proxy with a CONNECT request
+ */
+function connectTunnel(proxyURL: URL, targetURL: URL) {
+	const send: typeof import("node:http") =
+		proxyURL.protocol === "https:" ? getHttps() : getHttp();
+	const authority = `${targetURL.hostname}:${targetURL.port || 443}`;
+	return new Promise<Socket>((resolve, reject) => {
+		send
+			.request(proxyURL, {
+				method: "CONNECT",
+				path: authority,
+				headers: { host: authority, ...getProxyHeaders(proxyURL) }
+			})
+			.on("connect", (res, socket) => {
+				if (res.statusCode === 200) {
+					resolve(socket);
+				} else {
+					socket.destroy();
+					reject(
+						new Error(
+							`Proxy ${proxyURL.host} responded ${res.statusCode} to CONNECT ${authority}`
+						)
+					);
+				}
+			})
+			.on("error", reject)
+			.end();
+	});
+}
+
+async function compatibleFetch(
 	url: string,
-	options: { headers: Record<string, string> }
+	options: {
+		headers: Record<string, string>;
+		proxy?: string;
+		ca?: HttpUriPluginOptions["ca"];
+	}
 ) {
 	const parsedURL = new URL(url);
-	const send: typeof import("node:http") =
+	let send: typeof import("node:http") =
 		parsedURL.protocol === "https:" ? getHttps() : getHttp();
+	let target: URL = parsedURL;
+	const requestOptions: import("node:https").RequestOptions = {
+		headers: options.headers,
+		ca: options.ca
+	};
+	if (options.proxy) {
+		const proxyURL = new URL(options.proxy);
+		if (parsedURL.protocol === "https:") {
+			const socket = await connectTunnel(proxyURL, parsedURL);
+			requestOptions.createConnection = () =>
+				require("node:tls").connect({
+					socket,
+					servername: parsedURL.hostname,
+					ca: options.ca
+				});
+		} else {
+			// plain http requests are sent to the proxy with the absolute url
+			send = proxyURL.protocol === "https:" ? getHttps() : getHttp();
+			target = proxyURL;
+			requestOptions.path = url;
+			requestOptions.headers = {
+				...options.headers,
+				host: parsedURL.host,
+				...getProxyHeaders(proxyURL)
+			};
+		}
+	}
 	const {
 		createBrotliDecompress,
 		createGunzip,
@@ -60,7 +174,7 @@ function compatibleFetch(
 	return new Promise<{ res: IncomingMessage; body: Buffer }>(
 		(resolve, reject) => {
 			send
-				.get(url, options, res => {
+				.get(target, requestOptions, res => {
 					// align with https://github.com/webpack/webpack/blob/dec18718be5dfba28f067fb3827dd620a1f33667/lib/schemes/HttpUriPlugin.js#L807
 					const contentEncoding = res.headers["content-encoding"];
 					/** @type {Readable} */
@@ -95,12 +209,14 @@ function compatibleFetch(
 
 const defaultHttpClientForNode = async (
 	url: string,
-	headers: Record<string, string>
+	headers: Record<string, string>,
+	proxy?: string,
+	ca?: HttpUriPluginOptions["ca"]
 ) => {
 	// Return a promise that resolves to the response
 	// setting redirect: "manual" to prevent automatic redirection which will break the redirect logic in rust plugin
 	// webpack use require('http').get while rspack use fetch which treats redirect differently
-	const { res, body } = await compatibleFetch(url, { headers });
+	const { res, body } = await compatibleFetch(url, { headers, proxy, ca });
 	const responseHeaders: Record<string, string> = {};
 	for (const [key, value] of Object.entries(res.headers)) {
 		if (Array.isArray(value)) {
@@ -169,15 +285,26 @@ export class HttpUriPlugin extends RspackBuiltinPlugin {
 
 		const defaultHttpClient = IS_BROWSER
 			? defaultHttpClientForBrowser
-			: defaultHttpClientForNode;
+			: (url: string, headers: Record<string, string>, proxy?: string) =>
+					defaultHttpClientForNode(url, headers, proxy, options.ca);
+		const env: Record<string, string | undefined> = IS_BROWSER
+			? {}
+			: process.env;
+		const httpProxy = options.proxy ?? env.HTTP_PROXY ?? env.http_proxy;
+		const httpsProxy = options.proxy ?? env.HTTPS_PROXY ?? env.https_proxy;
 
 		const raw: RawHttpUriPluginOptions = {
 			allowedUris: options.allowedUris,
 			lockfileLocation,
 			cacheLocation,
 			upgrade: options.upgrade ?? false,
-			// frozen: options.frozen,
-			// proxy: options.proxy,
+			httpProxy: httpProxy || undefined,
+			httpsProxy: httpsProxy || undefined,
+			noProxy: options.noProxy ?? env.NO_PROXY ?? env.no_proxy,
+			frozen: options.frozen ?? false,
+			offline: options.offline ?? false,
+			headers: options.headers,
+			retry: options.retry,
 			httpClient: options.httpClient ?? defaultHttpClient
 		};
 		return createBuiltinPlugin(this.name, raw);
@@ -18,6 +18,16 @@ export type LightningCssMinimizerRspackPluginOptions = {
 	include?: AssetConditions;
 	exclude?: AssetConditions;
 	removeUnusedLocalIdents?: boolean;
+	/**
+	 * Report the features lowered and the vendor prefixes added or removed for the targets,
+	 * and warn about the declarations that can't be represented for the targets.
+	 * The reports are emitted as warnings, or as a JSON asset if `filename` is set.
+	 */
+	report?:
+		| boolean
+		| {
+				filename?: string;
+		  };
 	minimizerOptions?: {
 		errorRecovery?: boolean;
 		targets?: string[] | string;
@@ -48,6 +58,12 @@ export const LightningCssMinimizerRspackPlugin = create(
 			include: options?.include,
 			exclude: options?.exclude,
 			removeUnusedLocalIdents: options?.removeUnusedLocalIdents ?? true,
+			report:
+				options?.report === true
+					? {}
+					: options?.report
+						? { filename: options.report.filename }
+						: undefined,
 			minimizerOptions: {
 				errorRecovery: options?.minimizerOptions?.errorRecovery ?? true,
 				unusedSymbols: options?.minimizerOptions?.unusedSymbols ?? [],
@@ -18,11 +18,28 @@ type ExtractCommentsObject = {
 };
 type ExtractCommentsOptions = ExtractCommentsCondition | ExtractCommentsObject;
 
+type ManglePropertiesOptions = {
+	/**
+	 * The properties whose names match the regex are mangled across all the assets.
+	 * The pattern follows the Rust regex syntax, only the `i`, `m` and `s` flags of a `RegExp` are supported.
+	 */
+	regex: RegExp | string;
+	/**
+	 * The properties never mangled.
+	 */
+	reserved?: string[];
+	/**
+	 * The file storing the mangled names relative to the context, so the names are stable across builds.
+	 */
+	nameCache?: string;
+};
+
 export type SwcJsMinimizerRspackPluginOptions = {
 	test?: AssetConditions;
 	exclude?: AssetConditions;
 	include?: AssetConditions;
 	extractComments?: ExtractCommentsOptions | undefined;
+	mangleProperties?: ManglePropertiesOptions;
 	minimizerOptions?: {
 		minify?: boolean;
 		ecma?: TerserEcmaVersion;
@@ -268,6 +285,28 @@ function getRawExtractCommentsOptions(
 	return undefined;
 }
 
+// the flags which don't change whether a name matches, the unicode mode is the default of Rust regex
+const IGNORED_REGEX_FLAGS = "gyud";
+// the flags supported by the inline flags of Rust regex
+const INLINE_REGEX_FLAGS = "ims";
+
+function getRawMangleRegex(regex: RegExp | string): string {
+	if (typeof regex === "string") {
+		return regex;
+	}
+	let inlineFlags = "";
+	for (const flag of regex.flags) {
+		if (INLINE_REGEX_FLAGS.includes(flag)) {
+			inlineFlags += flag;
+		} else if (!IGNORED_REGEX_FLAGS.includes(flag)) {
+			throw new Error(
+				`The flag "${flag}" of mangleProperties.regex ${regex} can't be converted to a Rust regex.`
+			);
+		}
+	}
+	return inlineFlags ? `(?${inlineFlags})${regex.source}` : regex.source;
+}
+
 export const SwcJsMinimizerRspackPlugin = create(
 	BuiltinPluginName.SwcJsMinimizerRspackPlugin,
 	(
@@ -299,6 +338,11 @@ export const SwcJsMinimizerRspackPlugin = create(
 			include: options?.include,
 			exclude: options?.exclude,
 			extractComments: getRawExtractCommentsOptions(options?.extractComments),
+			mangleProperties: options?.mangleProperties && {
+				regex: getRawMangleRegex(options.mangleProperties.regex),
+				reserved: options.mangleProperties.reserved,
+				nameCache: options.mangleProperties.nameCache
+			},
 			minimizerOptions: {
 				compress,
 				mangle,
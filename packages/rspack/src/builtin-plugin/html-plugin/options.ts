@@ -31,6 +31,14 @@ export type HtmlRspackPluginOptions = {
 	 */
 	templateContent?: string | TemplateRenderFunction;
 
+	/**
+	 * A glob or a directory of templates, each template generates an HTML file, and the `template`, `filename` and `chunks` options are ignored.
+	 *
+	 * A page named by its template path relative to the directory without the extension includes the entry with the same name, and is emitted as `[name].html`.
+	 * Use `entry`, `chunks` and `filename` in the front matter of the template to change them.
+	 */
+	pages?: string;
+
 	/**
 	 * Allows to overwrite the parameters used in the template.
 	 *
@@ -98,6 +106,13 @@ export type HtmlRspackPluginOptions = {
 	 */
 	hash?: boolean;
 
+	/**
+	 * Inline the CSS rules of the initial stylesheets which match the template markup in a `<style>` tag,
+	 * and load the full stylesheets asynchronously with `<link rel="preload">`, with a `<noscript>` fallback.
+	 * @default false
+	 */
+	criticalCss?: boolean;
+
 	/**
 	 * Any other options will be passed by hooks.
 	 */
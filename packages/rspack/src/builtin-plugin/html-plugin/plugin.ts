@@ -201,6 +201,7 @@ const HtmlRspackPluginImpl = create(
 		return {
 			filename: filenames ? Array.from(filenames) : undefined,
 			template: c.template,
+			pages: c.pages,
 			hash: c.hash,
 			title: c.title,
 			favicon: c.favicon,
@@ -211,6 +212,7 @@ const HtmlRspackPluginImpl = create(
 			sri: c.sri,
 			minify: c.minify,
 			meta,
+			criticalCss: c.criticalCss,
 			scriptLoading,
 			inject,
 			base,
@@ -65,6 +65,7 @@ export * from "./OccurrenceChunkIdsPlugin";
 export * from "./ProgressPlugin";
 export * from "./ProvidePlugin";
 export * from "./RealContentHashPlugin";
+export * from "./RecordIdsPlugin";
 export * from "./RemoveDuplicateModulesPlugin";
 export * from "./RemoveEmptyChunksPlugin";
 export * from "./RsdoctorPlugin";
@@ -774,7 +774,10 @@ function getRawCssAutoOrModuleGeneratorOptions(
 		localIdentName: options.localIdentName,
 		exportsConvention: options.exportsConvention,
 		exportsOnly: options.exportsOnly,
-		esModule: options.esModule
+		esModule: options.esModule,
+		typeDeclaration:
+			options.typeDeclaration === false ? "false" : options.typeDeclaration,
+		removeUnusedRules: options.removeUnusedRules
 	};
 }
 
@@ -98,6 +98,8 @@ export const applyRspackOptionsDefaults = (
 	});
 	// IGNORE(bail): bail is default to false in webpack, but it's set in `Compilation`
 	D(options, "bail", false);
+	D(options, "recordsInputPath", false);
+	D(options, "recordsOutputPath", false);
 
 	// IGNORE(cache): cache is default to { type: "memory" } in webpack when the mode is development,
 	// but Rspack currently does not support this option
@@ -237,7 +239,8 @@ const applyExperimentsDefaults = (
 	D(experiments, "buildHttp", undefined);
 	if (experiments.buildHttp && typeof experiments.buildHttp === "object") {
 		D(experiments.buildHttp, "upgrade", false);
-		// D(experiments.buildHttp, "frozen", false);
+		D(experiments.buildHttp, "frozen", false);
+		D(experiments.buildHttp, "offline", false);
 	}
 
 	// IGNORE(experiments.incremental): Rspack specific configuration for incremental
@@ -431,6 +434,8 @@ const applyModuleDefaults = (
 				: "[id]-[local]";
 		D(module.generator["css/auto"], "localIdentName", localIdentName);
 		D(module.generator["css/auto"], "esModule", true);
+		D(module.generator["css/auto"], "typeDeclaration", false);
+		D(module.generator["css/auto"], "removeUnusedRules", false);
 
 		F(module.generator, "css/module", () => ({}));
 		assertNotNill(module.generator["css/module"]);
@@ -442,6 +447,8 @@ const applyModuleDefaults = (
 		D(module.generator["css/module"], "exportsConvention", "as-is");
 		D(module.generator["css/module"], "localIdentName", localIdentName);
 		D(module.generator["css/module"], "esModule", true);
+		D(module.generator["css/module"], "typeDeclaration", false);
+		D(module.generator["css/module"], "removeUnusedRules", false);
 	}
 
 	// IGNORE(module.defaultRules): Rspack does not support `rule.assert`
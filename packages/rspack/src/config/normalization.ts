@@ -73,6 +73,7 @@ import type {
 	Plugins,
 	Profile,
 	PublicPath,
+	RecordsPath,
 	Resolve,
 	RspackFutureOptions,
 	RspackOptions,
@@ -393,19 +394,40 @@ export const getNormalizedRspackOptions = (
 							deps.map(d => path.resolve(config.context || process.cwd(), d))
 						),
 						version: cache.version || "",
+						invalidationReport: cache.invalidationReport || false,
 						snapshot: {
 							immutablePaths: nestedArray(snapshot.immutablePaths, p => [...p]),
 							unmanagedPaths: nestedArray(snapshot.unmanagedPaths, p => [...p]),
 							managedPaths: optionalNestedArray(snapshot.managedPaths, p => [
 								...p
-							]) || [/[\\/]node_modules[\\/][^.]/]
+							]) || [/[\\/]node_modules[\\/][^.]/],
+							contentHash: snapshot.contentHash
 						},
 						storage: {
-							type: "filesystem",
-							directory: path.resolve(
-								config.context || process.cwd(),
-								cache.storage?.directory || "node_modules/.cache/rspack"
-							)
+							bucketSize: cache.storage?.bucketSize,
+							packSize: cache.storage?.packSize,
+							expire: cache.storage?.expire,
+							freshGeneration: cache.storage?.freshGeneration,
+							releaseGeneration: cache.storage?.releaseGeneration,
+							adaptive: cache.storage?.adaptive,
+							...(cache.storage?.type === "remote"
+								? {
+										type: "remote" as const,
+										directory: path.resolve(
+											config.context || process.cwd(),
+											cache.storage.directory || "node_modules/.cache/rspack"
+										),
+										url: cache.storage.url,
+										headers: cache.storage.headers || {},
+										readOnly: cache.storage.readOnly || false
+									}
+								: {
+										type: "filesystem" as const,
+										directory: path.resolve(
+											config.context || process.cwd(),
+											cache.storage?.directory || "node_modules/.cache/rspack"
+										)
+									})
 						}
 					};
 				}),
@@ -427,6 +449,8 @@ export const getNormalizedRspackOptions = (
 		profile: config.profile,
 		amd: config.amd,
 		bail: config.bail,
+		recordsInputPath: config.recordsInputPath ?? config.recordsPath,
+		recordsOutputPath: config.recordsOutputPath ?? config.recordsPath,
 		lazyCompilation: optionalNestedConfig(config.lazyCompilation, options =>
 			options === true ? {} : options
 		)
@@ -668,14 +692,32 @@ export type ExperimentCacheNormalized =
 			type: "persistent";
 			buildDependencies: string[];
 			version: string;
+			invalidationReport: boolean;
 			snapshot: {
 				immutablePaths: (string | RegExp)[];
 				unmanagedPaths: (string | RegExp)[];
 				managedPaths: (string | RegExp)[];
+				contentHash?: boolean;
 			};
-			storage: {
-				type: "filesystem";
-				directory: string;
+			storage: (
+				| {
+						type: "filesystem";
+						directory: string;
+				  }
+				| {
+						type: "remote";
+						directory: string;
+						url: string;
+						headers: Record<string, string>;
+						readOnly: boolean;
+				  }
+			) & {
+				bucketSize?: number;
+				packSize?: number;
+				expire?: number;
+				freshGeneration?: number;
+				releaseGeneration?: number;
+				adaptive?: boolean;
 			};
 	  };
 
@@ -762,4 +804,6 @@ export interface RspackOptionsNormalized {
 	profile?: Profile;
 	amd?: Amd;
 	bail?: Bail;
+	recordsInputPath?: RecordsPath;
+	recordsOutputPath?: RecordsPath;
 }
@@ -1332,6 +1332,16 @@ export type CssGeneratorLocalIdentName = string;
 
 export type CssGeneratorEsModule = boolean;
 
+/**
+ * Generate TypeScript declarations for the exports of CSS modules.
+ * - `"write"`: write the declaration next to the source, as `[resource].d.ts`
+ * - `"asset"`: emit the declaration as an asset
+ * - `"check"`: report an error when the declaration next to the source is missing or outdated
+ */
+export type CssGeneratorTypeDeclaration = false | "write" | "asset" | "check";
+
+export type CssGeneratorRemoveUnusedRules = boolean;
+
 /** Generator options for css modules. */
 export type CssGeneratorOptions = {
 	/**
@@ -1363,6 +1373,18 @@ export type CssAutoGeneratorOptions = {
 
 	/** This configuration is available for improved ESM-CJS interoperability purposes. */
 	esModule?: CssGeneratorEsModule;
+
+	/**
+	 * Generate TypeScript declarations for the exports of CSS modules.
+	 * @default false
+	 */
+	typeDeclaration?: CssGeneratorTypeDeclaration;
+
+	/**
+	 * Remove the rules whose local classes and ids are not used by any module, based on the used exports.
+	 * @default false
+	 */
+	removeUnusedRules?: CssGeneratorRemoveUnusedRules;
 };
 
 /** Generator options for css/module modules. */
@@ -2012,6 +2034,11 @@ export type StatsOptions = {
 	 * @default false
 	 */
 	optimizationBailout?: boolean;
+	/**
+	 * Tells stats whether to add the recomputed and reused counts of each incremental pass.
+	 * @default false
+	 */
+	incremental?: boolean;
 	/**
 	 * Enables or disables grouping of modules by type.
 	 */
@@ -2586,14 +2613,80 @@ export type ExperimentCacheOptions =
 			type: "persistent";
 			buildDependencies?: string[];
 			version?: string;
+			/**
+			 * Log why the cache is invalidated in a warm start, including the changed
+			 * build dependencies and files with the modules they invalidate.
+			 * @default false
+			 */
+			invalidationReport?: boolean;
 			snapshot?: {
 				immutablePaths?: (string | RegExp)[];
 				unmanagedPaths?: (string | RegExp)[];
 				managedPaths?: (string | RegExp)[];
+				/**
+				 * Compare files by the xxhash64 digest of their content, the modified time is
+				 * only used as a fast pre-check. Enable it to keep the cache valid after
+				 * `git checkout` or restoring the cache directory on another machine.
+				 * @default false
+				 */
+				contentHash?: boolean;
 			};
-			storage?: {
-				type: "filesystem";
-				directory?: string;
+			storage?: (
+				| {
+						type: "filesystem";
+						directory?: string;
+				  }
+				| {
+						type: "remote";
+						/**
+						 * Local copy of the remote cache.
+						 */
+						directory?: string;
+						/**
+						 * Base url of the content-addressed object store, only `http://` is supported.
+						 */
+						url: string;
+						/**
+						 * Extra request headers, e.g. authorization tokens.
+						 */
+						headers?: Record<string, string>;
+						/**
+						 * Only download packs from the remote store, never upload.
+						 * @default false
+						 */
+						readOnly?: boolean;
+				  }
+			) & {
+				/**
+				 * Count of buckets in each cache scope.
+				 * @default 20
+				 */
+				bucketSize?: number;
+				/**
+				 * Max size of a pack file in bytes.
+				 * @default 512000
+				 */
+				packSize?: number;
+				/**
+				 * Expiration of the cache in milliseconds.
+				 * @default 604800000
+				 */
+				expire?: number;
+				/**
+				 * Packs written within this count of builds are merged or split during saving.
+				 * @default 1
+				 */
+				freshGeneration?: number;
+				/**
+				 * Pack contents unused for this count of builds are released from memory.
+				 * @default 2
+				 */
+				releaseGeneration?: number;
+				/**
+				 * Pick bucket size and pack size of each scope from its item count.
+				 * @default false
+				 */
+				adaptive?: boolean;
 			};
 	  };
 
@@ -2913,6 +3006,31 @@ export type WatchOptions = {
 	 * Stop watching when stdin stream has ended.
 	 */
 	stdin?: boolean;
+
+	/**
+	 * Wait until no file changes for this long in milliseconds before rebuilding.
+	 * Only supported by `experiments.nativeWatcher`.
+	 */
+	quietPeriod?: number;
+
+	/**
+	 * The longest time in milliseconds a rebuild can be delayed by `quietPeriod`.
+	 * Only supported by `experiments.nativeWatcher`.
+	 */
+	maxBatchLatency?: number;
+
+	/**
+	 * Rebuild right after the current build if files changed during it.
+	 * Only supported by `experiments.nativeWatcher`.
+	 * @default false
+	 */
+	replayPausedEvents?: boolean;
+
+	/**
+	 * Rescan all the watched files instead of tracking the changes when more files than this are changed.
+	 * Only supported by `experiments.nativeWatcher`.
+	 */
+	overflowThreshold?: number;
 };
 //#endregion
 
@@ -2956,6 +3074,13 @@ export type IgnoreWarnings = (
 export type Profile = boolean;
 //#endregion
 
+//#region Records
+/**
+ * The absolute path of a JSON file to store the module and chunk ids between builds, or `false` to disable it.
+ * */
+export type RecordsPath = string | false;
+//#endregion
+
 //#region amd
 /**
  * Set the value of `require.amd` and `define.amd`. Or disable AMD support.
@@ -3135,6 +3260,21 @@ export type RspackOptions = {
 	 * Whether to fail on the first error.
 	 */
 	bail?: Bail;
+	/**
+	 * The file to read the module and chunk ids from and write them back to,
+	 * used as the default of `recordsInputPath` and `recordsOutputPath`.
+	 */
+	recordsPath?: RecordsPath;
+	/**
+	 * The file to read the module and chunk ids from, so the same modules and chunks keep their ids.
+	 * @default false
+	 */
+	recordsInputPath?: RecordsPath;
+	/**
+	 * The file to write the module and chunk ids to after emitting the assets.
+	 * @default false
+	 */
+	recordsOutputPath?: RecordsPath;
 	/**
 	 * Performance optimization options.
 	 */
@@ -28,6 +28,11 @@ export type RemotesObject = {
 export type RemotesConfig = {
 	external: RemotesItem | RemotesItems;
 	shareScope?: string;
+	/**
+	 * The manifest of the remote, a file relative to the context or a `http://` url.
+	 * The exposed modules and shared versions used by the consumer are checked against it.
+	 */
+	manifest?: string;
 };
 
 export class ContainerReferencePlugin extends RspackBuiltinPlugin {
@@ -42,13 +47,15 @@ export class ContainerReferencePlugin extends RspackBuiltinPlugin {
 				options.remotes,
 				item => ({
 					external: Array.isArray(item) ? item : [item],
-					shareScope: options.shareScope || "default"
+					shareScope: options.shareScope || "default",
+					manifest: undefined
 				}),
 				item => ({
 					external: Array.isArray(item.external)
 						? item.external
 						: [item.external],
-					shareScope: item.shareScope || options.shareScope || "default"
+					shareScope: item.shareScope || options.shareScope || "default",
+					manifest: item.manifest
 				})
 			),
 			enhanced: options.enhanced ?? false
@@ -58,6 +58,7 @@ import {
 	NoEmitOnErrorsPlugin,
 	OccurrenceChunkIdsPlugin,
 	RealContentHashPlugin,
+	RecordIdsPlugin,
 	RemoveEmptyChunksPlugin,
 	RuntimeChunkPlugin,
 	RuntimePlugin,
@@ -356,6 +357,12 @@ export class RspackOptionsApply {
 					throw new Error(`chunkIds: ${chunkIds} is not implemented`);
 			}
 		}
+		if (options.recordsInputPath || options.recordsOutputPath) {
+			new RecordIdsPlugin({
+				inputPath: options.recordsInputPath || undefined,
+				outputPath: options.recordsOutputPath || undefined
+			}).apply(compiler);
+		}
 		if (options.optimization.nodeEnv) {
 			new DefinePlugin({
 				"process.env.NODE_ENV": JSON.stringify(options.optimization.nodeEnv)
@@ -865,6 +865,10 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			const statsCompilation = context.getStatsCompilation(compilation);
 			object.hash = statsCompilation.hash;
 		},
+		incremental: (object, compilation, context: KnownStatsFactoryContext) => {
+			const statsCompilation = context.getStatsCompilation(compilation);
+			object.incremental = statsCompilation.incremental;
+		},
 		version: object => {
 			object.version = WEBPACK_VERSION;
 			object.rspackVersion = RSPACK_VERSION;
@@ -47,6 +47,7 @@ const NAMED_PRESETS: Record<string, StatsOptions> = {
 		logging: "verbose",
 		orphanModules: true,
 		runtimeModules: true,
+		incremental: true,
 		// exclude: false,
 		excludeModules: false,
 		errorsSpace: Number.POSITIVE_INFINITY,
@@ -229,6 +230,7 @@ const DEFAULTS: StatsDefault = {
 	optimizationBailout: OFF_FOR_TO_STRING,
 	children: OFF_FOR_TO_STRING,
 	source: NORMAL_OFF,
+	incremental: NORMAL_OFF,
 	moduleTrace: NORMAL_ON,
 	errors: NORMAL_ON,
 	errorsCount: NORMAL_ON,
@@ -287,6 +287,7 @@ export type KnownStatsCompilation = {
 	filteredModules?: number;
 	children?: StatsCompilation[];
 	logging?: Record<string, StatsLogging>;
+	incremental?: StatsIncrementalPass[];
 
 	// TODO: not aligned with webpack
 	// env?: any;
@@ -298,6 +299,14 @@ export type StatsCompilation = KnownStatsCompilation & Record<string, any>;
 
 export type StatsLogging = KnownStatsLogging & Record<string, any>;
 
+export type StatsIncrementalPass = {
+	pass: string;
+	incremental: boolean;
+	recomputed: number;
+	reused: number;
+	total: number;
+};
+
 export type KnownStatsLogging = {
 	entries: StatsLoggingEntry[];
 	filteredEntries: number;
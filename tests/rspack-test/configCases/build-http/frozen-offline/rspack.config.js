@@ -0,0 +1,25 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "development",
+	module: {
+		rules: [
+			{
+				test: /\.png$/,
+				type: "asset/resource"
+			}
+		]
+	},
+	experiments: {
+		buildHttp: {
+			allowedUris: ["https://"],
+			lockfileLocation: path.resolve(__dirname, "./lock-files/lock.json"),
+			cacheLocation: path.resolve(__dirname, "./lock-files/test"),
+			// served from the committed lockfile and cache, without the network
+			frozen: true,
+			offline: true
+		},
+		css: false
+	}
+};
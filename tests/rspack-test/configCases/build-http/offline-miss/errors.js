@@ -0,0 +1,4 @@
+module.exports = [
+	/https:\/\/example\.com\/first\.js is not in the cache .*offline mode/,
+	/https:\/\/example\.com\/second\.js is not in the cache .*offline mode/
+];
@@ -0,0 +1,5 @@
+// the whole file is not gonna run, as the compilation should fail
+import "https://example.com/first.js";
+import "https://example.com/second.js";
+
+throw new Error("should not reach here");
@@ -0,0 +1,15 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	experiments: {
+		buildHttp: {
+			allowedUris: ["https://"],
+			lockfileLocation: path.resolve(__dirname, "./lock-files/lock.json"),
+			cacheLocation: path.resolve(__dirname, "./lock-files/cache"),
+			offline: true
+		},
+		css: false
+	}
+};
@@ -0,0 +1,15 @@
+import registry from "http://registry.rspack.test/module.js";
+import flaky from "http://registry.rspack.test/flaky.js";
+import direct from "http://localhost:9992/direct.js";
+
+it("should fetch through the proxy with the headers of the origin", () => {
+	expect(registry).toBe("registry");
+});
+
+it("should retry the failed requests", () => {
+	expect(flaky).toBe("flaky");
+});
+
+it("should fetch the hosts in noProxy directly", () => {
+	expect(direct).toBe("direct");
+});
@@ -0,0 +1,25 @@
+const ServerPlugin = require("./server");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	plugins: [new ServerPlugin(9992)],
+	experiments: {
+		buildHttp: {
+			allowedUris: ["http://registry.rspack.test/", "http://localhost:9992/"],
+			cacheLocation: false,
+			proxy: "http://localhost:9992",
+			noProxy: "localhost",
+			headers: {
+				"http://registry.rspack.test": {
+					Authorization: "Bearer secret"
+				}
+			},
+			retry: {
+				retries: 2,
+				delay: 10
+			}
+		},
+		css: false
+	}
+};
@@ -0,0 +1,102 @@
+const http = require("http");
+
+/**
+ * A server acting as both the proxy of `registry.rspack.test` and a normal server
+ * @returns {import("http").Server} server instance
+ */
+function createServer() {
+	let flakyRequests = 0;
+	const server = http.createServer((req, res) => {
+		const send = (status, body) => {
+			res.writeHead(status, {
+				"Content-Type": "text/javascript",
+				"Cache-Control": "no-cache"
+			});
+			res.end(body);
+		};
+
+		// proxied requests come with the absolute url
+		if (req.url.startsWith("http://")) {
+			const url = new URL(req.url);
+			if (url.host !== "registry.rspack.test") {
+				send(400, "localhost should bypass the proxy");
+				return;
+			}
+			if (req.headers.authorization !== "Bearer secret") {
+				send(401, "Unauthorized");
+				return;
+			}
+			if (url.pathname === "/module.js") {
+				send(200, 'export default "registry";');
+				return;
+			}
+			if (url.pathname === "/flaky.js") {
+				if (flakyRequests++ === 0) {
+					send(503, "Service Unavailable");
+				} else {
+					send(200, 'export default "flaky";');
+				}
+				return;
+			}
+			send(404, "Not found");
+			return;
+		}
+
+		if (req.url === "/direct.js") {
+			if (req.headers.authorization) {
+				send(400, "the headers of registry.rspack.test should not be sent");
+				return;
+			}
+			send(200, 'export default "direct";');
+			return;
+		}
+
+		send(404, "Not found");
+	});
+	server.unref();
+	return server;
+}
+
+class ServerPlugin {
+	/**
+	 * @param {number} port
+	 */
+	constructor(port) {
+		this.port = port;
+		this.refs = 0;
+		this.server = undefined;
+	}
+
+	/**
+	 * @param {import("@rspack/core").Compiler} compiler
+	 */
+	apply(compiler) {
+		compiler.hooks.beforeRun.tapPromise("ServerPlugin", () => {
+			this.refs++;
+			if (!this.server) {
+				this.server = createServer();
+				return new Promise((resolve, reject) => {
+					this.server.listen(this.port, err => {
+						if (err) {
+							reject(err);
+						} else {
+							resolve();
+						}
+					});
+				});
+			}
+		});
+
+		compiler.hooks.done.tap("ServerPlugin", (stats, callback) => {
+			const s = this.server;
+			if (s && --this.refs === 0) {
+				this.server = undefined;
+				s.close(callback);
+			} else {
+				callback();
+			}
+		});
+	}
+}
+
+module.exports = ServerPlugin;
@@ -0,0 +1,38 @@
+import "./style.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should inline the critical css and load the stylesheet asynchronously", () => {
+	const html = fs.readFileSync(path.join(__dirname, "index.html"), "utf-8");
+	const style = html.match(/<style>([\s\S]*?)<\/style>/)[1];
+
+	expect(style).toContain("@font-face");
+	expect(style).toContain("body {");
+	expect(style).toContain(".hero {");
+	expect(style).toContain(".hero--dark h1[data-title] {");
+	expect(style).toContain("#top:hover {");
+	expect(style).toContain("@media (min-width: 800px)");
+	expect(style).not.toContain(".modal");
+	expect(style).not.toContain(".sidebar");
+	expect(style).not.toContain("@media print");
+
+	// the relative urls of the stylesheet are rebased to the document
+	const stylesheet = fs.readFileSync(
+		path.join(__dirname, "bundle0.css"),
+		"utf-8"
+	);
+	expect(stylesheet).toMatch(/url\(["']?open-sans\.woff2["']?\)/);
+	expect(stylesheet).toMatch(/url\(["']?hero\.png["']?\)/);
+	expect(style).toMatch(/url\(["']?\/assets\/open-sans\.woff2["']?\)/);
+	expect(style).toMatch(/url\(["']?\/assets\/hero\.png["']?\)/);
+	expect(style).not.toMatch(/url\(["']?(open-sans\.woff2|hero\.png)/);
+
+	expect(html).toContain(
+		`<link as="style" href="/assets/bundle0.css" onload="this.onload=null;this.rel='stylesheet'" rel="preload">`
+	);
+	// the stylesheet is still loaded without javascript
+	expect(html).toMatch(
+		/<noscript><link href="\/assets\/bundle0\.css" rel="stylesheet"\/?><\/noscript>/
+	);
+});
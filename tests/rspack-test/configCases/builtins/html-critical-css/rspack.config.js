@@ -0,0 +1,32 @@
+const { HtmlRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	node: false,
+	output: {
+		publicPath: "/assets/"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.(woff2|png)$/,
+				type: "asset/resource",
+				generator: {
+					filename: "[name][ext]",
+					// the urls are relative to the stylesheet, and rebased when inlined
+					publicPath: ""
+				}
+			}
+		]
+	},
+	plugins: [
+		new HtmlRspackPlugin({
+			template: "./template.html",
+			criticalCss: true
+		})
+	],
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,31 @@
+const fs = require("fs");
+const path = require("path");
+
+const readPage = filename =>
+	fs.readFileSync(path.join(__dirname, filename), "utf-8");
+
+it("should generate a page for each template with the entry of the same name", () => {
+	const main = readPage("main.html");
+	expect(main).toContain("<title>Main</title>");
+	expect(main).toContain('<script defer src="main.js"></script>');
+	expect(main).not.toContain("about.js");
+
+	const about = readPage("about.html");
+	expect(about).toContain("<title>About</title>");
+	expect(about).toContain('<script defer src="about.js"></script>');
+	expect(about).not.toContain("main.js");
+});
+
+it("should use the entry and filename in the front matter", () => {
+	const contact = readPage("about/contact.html");
+	expect(contact).not.toContain("entry: about");
+	expect(contact).toContain("<title>Contact</title>");
+	expect(contact).toMatch(/<script defer src="(\.\.\/)?about\.js"><\/script>/);
+});
+
+it("should include the chunks in the front matter", () => {
+	const post = readPage("blog/post.html");
+	expect(post).not.toContain("chunks:");
+	expect(post).toContain("main.js");
+	expect(post).toContain("about.js");
+});
@@ -0,0 +1,17 @@
+const { HtmlRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		about: "./about.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	plugins: [
+		new HtmlRspackPlugin({
+			pages: "pages"
+		})
+	]
+};
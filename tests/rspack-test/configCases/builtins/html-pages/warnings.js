@@ -0,0 +1,3 @@
+module.exports = [
+	[/The page template `pages\/blog\/post\.html` includes the entry `missing`, which does not exist/]
+];
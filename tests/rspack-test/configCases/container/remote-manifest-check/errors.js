@@ -0,0 +1,3 @@
+module.exports = [
+	[/The remote "remote" does not expose "\.\/missing", the exposed modules in its manifest are "\.\/module"/]
+];
@@ -0,0 +1,15 @@
+it("should load the exposed module of the remote", async () => {
+	const { value } = await import("remote/module");
+	expect(value).toBe("module");
+});
+
+it("should load the shared module", async () => {
+	const { version } = await import("lib");
+	expect(version).toBe("2.0.0");
+});
+
+it("should not load the module missing in the remote", () => {
+	if (Math.random() < 0) {
+		import("remote/missing");
+	}
+});
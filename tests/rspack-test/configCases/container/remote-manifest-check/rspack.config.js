@@ -0,0 +1,24 @@
+const { ModuleFederationPluginV1: ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "container",
+			library: { type: "commonjs-module" },
+			filename: "container.js",
+			exposes: ["./module"],
+			remotes: {
+				remote: {
+					external: "./container.js",
+					manifest: "./mf-manifest.json"
+				}
+			},
+			shared: {
+				lib: {
+					requiredVersion: "^2.0.0"
+				}
+			}
+		})
+	]
+};
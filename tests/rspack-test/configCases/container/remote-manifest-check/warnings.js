@@ -0,0 +1,3 @@
+module.exports = [
+	[/The shared module "lib" requires version "\^2\.0\.0", but the remote "remote" provides version "1\.0\.0"/]
+];
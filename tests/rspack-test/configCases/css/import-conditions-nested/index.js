@@ -0,0 +1,26 @@
+require("./a.css");
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should wrap the nested imports in the conditions of every level", async () => {
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "bundle0.css"),
+		"utf-8"
+	);
+
+	expect(css).toMatch(
+		/@supports \(display: grid\) \{\s*@layer base \{\s*@media\s+screen\s*\{\s*@layer reset \{\s*\.c \{/
+	);
+	expect(css).toMatch(
+		/@supports \(display: grid\) \{\s*@layer base \{\s*\.b \{/
+	);
+});
+
+it("should declare the layers up front in the import order", async () => {
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "bundle0.css"),
+		"utf-8"
+	);
+
+	expect(css.startsWith("@layer base, base.reset;\n")).toBe(true);
+});
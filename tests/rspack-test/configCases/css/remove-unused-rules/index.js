@@ -0,0 +1,25 @@
+import { used, composed } from "./style.module.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should remove the rules whose local classes are never used", async () => {
+	expect(used).toBe("used");
+	expect(composed).toBe("composed base");
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "bundle0.css"),
+		"utf-8"
+	);
+
+	expect(css).toContain(".used {\n\tcolor: red;");
+	expect(css).toContain(".used, .unused-in-list {");
+	expect(css).toContain("color: black;");
+	expect(css).toContain(".base {");
+	expect(css).toContain(".composed {");
+	expect(css).toContain("margin: 0;");
+
+	expect(css).not.toContain("color: blue;");
+	expect(css).not.toContain("color: yellow;");
+	expect(css).not.toContain("color: purple;");
+	expect(css).not.toContain("margin: 1px;");
+});
@@ -0,0 +1,22 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	node: false,
+	mode: "production",
+	module: {
+		generator: {
+			"css/auto": {
+				localIdentName: "[local]",
+				exportsOnly: false,
+				removeUnusedRules: true
+			}
+		}
+	},
+	optimization: {
+		minimize: false,
+		concatenateModules: false
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,45 @@
+import * as style from "./style.module.css";
+import defaultStyle from "./default.module.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should declare the named exports after the exports convention", async () => {
+	expect(style.iconLarge).toBeDefined();
+	const declaration = await fs.promises.readFile(
+		path.resolve(__dirname, "style.module.css.d.ts"),
+		"utf-8"
+	);
+
+	expect(declaration).toBe(
+		[
+			"// This file is generated by Rspack, do not edit it manually.",
+			"export declare const brandColor: string;",
+			"export declare const iconLarge: string;",
+			"export declare const primary: string;",
+			"declare const _icon_large: string;",
+			'export { _icon_large as "icon-large" };',
+			""
+		].join("\n")
+	);
+});
+
+it("should declare a default export object without named exports", async () => {
+	expect(defaultStyle.btnPrimary).toBeDefined();
+	const declaration = await fs.promises.readFile(
+		path.resolve(__dirname, "default.module.css.d.ts"),
+		"utf-8"
+	);
+
+	expect(declaration).toBe(
+		[
+			"// This file is generated by Rspack, do not edit it manually.",
+			"declare const styles: {",
+			'  readonly "btn-primary": string;',
+			'  readonly "btnPrimary": string;',
+			"};",
+			"export default styles;",
+			""
+		].join("\n")
+	);
+});
@@ -0,0 +1,28 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	node: false,
+	entry: {
+		main: "./index.js"
+	},
+	module: {
+		generator: {
+			"css/auto": {
+				exportsConvention: "camel-case",
+				typeDeclaration: "asset"
+			}
+		},
+		rules: [
+			{
+				test: /default\.module\.css$/,
+				type: "css/auto",
+				parser: {
+					namedExports: false
+				}
+			}
+		]
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,4 @@
+module.exports = [
+	/The type declaration .*style\.module\.css\.d\.ts is outdated/,
+	/The type declaration .*missing\.module\.css\.d\.ts is missing/
+];
@@ -0,0 +1,13 @@
+import * as style from "./style.module.css";
+import * as missing from "./missing.module.css";
+
+it("should not emit the type declarations in check mode", () => {
+	const fs = __non_webpack_require__("fs");
+	const path = __non_webpack_require__("path");
+
+	expect(style.secondary).toBeDefined();
+	expect(missing.title).toBeDefined();
+	expect(
+		fs.existsSync(path.resolve(__dirname, "missing.module.css.d.ts"))
+	).toBe(false);
+});
@@ -0,0 +1,33 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	node: false,
+	entry: {
+		main: "./index.js"
+	},
+	module: {
+		generator: {
+			"css/auto": {
+				typeDeclaration: "check"
+			}
+		}
+	},
+	plugins: [
+		compiler => {
+			compiler.hooks.done.tap("Test", ({ compilation }) => {
+				const fileDeps = Array.from(compilation.fileDependencies);
+				expect(fileDeps).toContain(
+					path.resolve(__dirname, "style.module.css.d.ts")
+				);
+				expect(fileDeps).toContain(
+					path.resolve(__dirname, "missing.module.css.d.ts")
+				);
+			});
+		}
+	],
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,2 @@
+// This file is generated by Rspack, do not edit it manually.
+export declare const primary: string;
@@ -0,0 +1,15 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should report the features transpiled for the targets", () => {
+	const report = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "css-report.json"), "utf-8")
+	);
+	expect(report["a.css"].transpiled).toContain("nesting");
+	expect(report["a.css"].unsupported).toEqual([]);
+});
+
+it("should lower the nested rules", () => {
+	const content = fs.readFileSync(path.resolve(__dirname, "a.css"), "utf-8");
+	expect(content).toMatch(".a .b{");
+});
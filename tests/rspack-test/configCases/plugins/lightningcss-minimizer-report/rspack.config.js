@@ -0,0 +1,31 @@
+const { rspack } = require("@rspack/core");
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	entry: {
+		a: "./a.js",
+		main: "./index.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	module: {
+		generator: {
+			"css/auto": {
+				exportsOnly: false
+			}
+		}
+	},
+	optimization: {
+		minimize: true,
+		minimizer: [
+			new rspack.LightningCssMinimizerRspackPlugin({
+				report: { filename: "css-report.json" },
+				minimizerOptions: {
+					targets: "chrome 80"
+				}
+			})
+		]
+	}
+};
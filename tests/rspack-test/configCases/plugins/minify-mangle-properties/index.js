@@ -0,0 +1,18 @@
+import { createStore } from "./store";
+
+it("should mangle the properties consistently across chunks", async () => {
+	const { readStore } = await import("./reader");
+	expect(readStore(createStore())).toBe(43);
+
+	const fs = __non_webpack_require__("fs");
+	const path = __non_webpack_require__("path");
+	const contents = fs
+		.readdirSync(__dirname)
+		.filter(file => file.endsWith(".js"))
+		.map(file => fs.readFileSync(path.join(__dirname, file), "utf-8"));
+	expect(contents.length).toBeGreaterThan(1);
+	for (const content of contents) {
+		expect(content).not.toMatch(/_internal[V]alue/);
+	}
+	expect(contents.some(content => /_internal[K]eep/.test(content))).toBe(true);
+});
@@ -0,0 +1,3 @@
+export function readStore(store) {
+	return store._internalValue + store._internalKeep;
+}
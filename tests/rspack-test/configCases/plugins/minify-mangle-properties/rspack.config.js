@@ -0,0 +1,17 @@
+const { rspack } = require("@rspack/core");
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	optimization: {
+		minimize: true
+	},
+	plugins: [
+		new rspack.SwcJsMinimizerRspackPlugin({
+			mangleProperties: {
+				regex: /^_internal/,
+				reserved: ["_internalKeep"]
+			}
+		})
+	]
+};
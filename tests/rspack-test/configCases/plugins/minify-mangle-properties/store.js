@@ -0,0 +1,3 @@
+export function createStore() {
+	return { _internalValue: 42, _internalKeep: 1 };
+}
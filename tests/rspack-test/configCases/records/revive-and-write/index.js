@@ -0,0 +1,25 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should revive the recorded ids", async () => {
+	expect(require("./a")).toBe(100);
+	const lazy = await import(/* webpackChunkName: "lazy" */ "./lazy");
+	expect(lazy.default).toBe("lazy");
+	expect(__STATS__.chunks.find(chunk => chunk.names.includes("lazy")).id).toBe(300);
+});
+
+it("should write the records without the removed modules and chunks", () => {
+	const records = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "records.json"), "utf-8")
+	);
+	expect(records.modules.byIdentifier["./a.js"]).toBe(100);
+	expect(records.modules.byIdentifier).not.toHaveProperty(["./removed.js"]);
+	expect(records.modules.usedIds).toContain(100);
+	expect(records.modules.usedIds).not.toContain(200);
+	expect(records.chunks.byName.lazy).toBe(300);
+	expect(records.chunks.byName).not.toHaveProperty("removed");
+	expect(records.chunks.bySource).not.toHaveProperty(["0 ./index.js ./removed"]);
+	expect(records.chunks.usedIds).toContain(300);
+	expect(records.chunks.usedIds).not.toContain(400);
+	expect(records.chunks.usedIds).not.toContain(500);
+});
@@ -0,0 +1,11 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = (_, { testPath }) => ({
+	recordsInputPath: path.resolve(__dirname, "records.json"),
+	recordsOutputPath: path.resolve(testPath, "records.json"),
+	optimization: {
+		moduleIds: "natural",
+		chunkIds: "natural"
+	}
+});
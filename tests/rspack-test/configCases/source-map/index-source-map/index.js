@@ -0,0 +1,52 @@
+import { a } from "./a";
+import { b } from "./b";
+
+const fs = require("fs");
+const path = require("path");
+
+const positionFor = (content, text) => {
+	const lines = content.split(/\r?\n/);
+	for (let i = 0; i < lines.length; i++) {
+		const column = lines[i].indexOf(text);
+		if (column >= 0) return { line: i + 1, column };
+	}
+	return null;
+};
+
+it("should emit an index source map with a section for each module", () => {
+	expect([a, b]).toEqual(["Hello from a", "Hello from b"]);
+	const map = JSON.parse(fs.readFileSync(__filename + ".map", "utf-8"));
+	expect(map.version).toBe(3);
+	expect(map.mappings).toBeUndefined();
+	expect(map.sections.length).toBeGreaterThan(1);
+	const sources = map.sections.flatMap(section => section.map.sources);
+	expect(sources).toContain("webpack:///./a.js");
+	expect(sources).toContain("webpack:///./b.js");
+	for (const section of map.sections) {
+		expect(new Set(section.map.sources).size).toBeLessThanOrEqual(1);
+	}
+	for (let i = 1; i < map.sections.length; i++) {
+		const prev = map.sections[i - 1].offset;
+		const { offset } = map.sections[i];
+		expect(
+			offset.line > prev.line ||
+				(offset.line === prev.line && offset.column > prev.column)
+		).toBe(true);
+	}
+});
+
+it("should map to the original content through the sections", async () => {
+	const sourceMap = require("source-map");
+	const map = JSON.parse(fs.readFileSync(__filename + ".map", "utf-8"));
+	const generated = fs.readFileSync(__filename, "utf-8");
+	const consumer = await new sourceMap.SourceMapConsumer(map);
+	for (const name of ["a", "b"]) {
+		const STUB = `Hello from ${name}`;
+		const original = fs.readFileSync(path.resolve(CONTEXT, `${name}.js`), "utf-8");
+		const { source, line } = consumer.originalPositionFor(
+			positionFor(generated, STUB)
+		);
+		expect(source).toBe(`webpack:///./${name}.js`);
+		expect(line).toBe(positionFor(original, STUB).line);
+	}
+});
@@ -0,0 +1,17 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	devtool: false,
+	externals: ["source-map"],
+	externalsType: "commonjs",
+	plugins: [
+		new rspack.SourceMapDevToolPlugin({
+			filename: "[file].map",
+			sections: "module"
+		}),
+		new rspack.DefinePlugin({
+			CONTEXT: JSON.stringify(__dirname)
+		})
+	]
+};
@@ -0,0 +1,13 @@
+import noMap from "./no-map";
+import noContent from "./no-content";
+import outOfRange from "./out-of-range";
+
+it("should still emit the source map", () => {
+	expect([noMap, noContent, outOfRange]).toEqual([
+		"no-map",
+		"no-content",
+		"out-of-range"
+	]);
+	const fs = require("fs");
+	expect(fs.existsSync(__filename + ".map")).toBe(true);
+});
@@ -0,0 +1,14 @@
+module.exports = function (content) {
+	const { mode } = this.getOptions();
+	if (mode === "no-map") {
+		return content;
+	}
+	this.callback(null, content, {
+		version: 3,
+		sources: [this.resourcePath],
+		sourcesContent: mode === "no-content" ? undefined : [content],
+		names: [],
+		// "AAiDA" maps the first column to the line 50 of a one line source
+		mappings: mode === "out-of-range" ? "AAiDA" : "AAAA"
+	});
+};
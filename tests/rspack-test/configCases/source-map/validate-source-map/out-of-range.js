@@ -0,0 +1 @@
+export default "out-of-range";
@@ -0,0 +1,31 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	devtool: false,
+	plugins: [
+		compiler => {
+			new compiler.webpack.SourceMapDevToolPlugin({
+				filename: "[file].map",
+				validate: true
+			}).apply(compiler);
+		}
+	],
+	module: {
+		rules: [
+			{
+				test: /no-map\.js$/,
+				loader: "./loader.js",
+				options: { mode: "no-map" }
+			},
+			{
+				test: /no-content\.js$/,
+				loader: "./loader.js",
+				options: { mode: "no-content" }
+			},
+			{
+				test: /out-of-range\.js$/,
+				loader: "./loader.js",
+				options: { mode: "out-of-range" }
+			}
+		]
+	}
+};
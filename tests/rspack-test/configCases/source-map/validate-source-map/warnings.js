@@ -0,0 +1,5 @@
+module.exports = [
+	[/Invalid source map of "bundle0\.js": \d+ segments map into the code generated by the loaders instead of "\.\/no-map\.js" \(loaders: \.\/loader\.js/],
+	[/Invalid source map of "bundle0\.js": The source "\.\/no-content\.js" \(loaders: \.\/loader\.js.*\) has no "sourcesContent"/],
+	[/Invalid source map of "bundle0\.js": \d+ of \d+ segments map past the end of "\.\/out-of-range\.js" \(loaders: \.\/loader\.js.*\), e\.g\. \d+:\d+ maps to 50:0, but the source has 2 lines/]
+];
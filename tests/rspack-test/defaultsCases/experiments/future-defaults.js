@@ -84,12 +84,16 @@ module.exports = {
 		+         "exportsConvention": "as-is",
 		+         "exportsOnly": false,
 		+         "localIdentName": "[id]-[local]",
+		+         "removeUnusedRules": false,
+		+         "typeDeclaration": false,
 		+       },
 		+       "css/module": Object {
 		+         "esModule": true,
 		+         "exportsConvention": "as-is",
 		+         "exportsOnly": false,
 		+         "localIdentName": "[id]-[local]",
+		+         "removeUnusedRules": false,
+		+         "typeDeclaration": false,
 		+       },
 		@@ ... @@
 		+       "css": Object {